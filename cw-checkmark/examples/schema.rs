@@ -1,10 +1,11 @@
 use cosmwasm_schema::write_api;
-use cw_checkmark::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use cw_checkmark::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, SudoMsg};
 
 fn main() {
     write_api! {
         instantiate: InstantiateMsg,
         execute: ExecuteMsg,
         query: QueryMsg,
+        sudo: SudoMsg,
     }
 }
@@ -1,22 +1,62 @@
 use cosmwasm_std::{
-    to_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdError, StdResult,
+    coins, from_binary, to_binary, Addr, BankMsg, Binary, BlockInfo, CanonicalAddr, Coin,
+    CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response, StdError,
+    StdResult, Storage, Uint128, WasmMsg,
 };
 
 use crate::error::ContractError;
+use crate::evm;
 use crate::msg::{
-    AssignerResponse, CheckmarkBannedResponse, CountResponse, ExecuteMsg, GetAddressResponse,
-    GetCheckmarkResponse, InstantiateMsg, QueryMsg,
+    AddressBannedResponse, AssignerResponse, BannedAddressEntry, BannedCheckmarkEntry,
+    CheckmarkBannedResponse, CheckmarkIssuedResponse, CountResponse, ExecuteMsg,
+    FeeBalancesResponse, GetAddressResponse, GetCheckmarkResponse, GetDelegationResponse,
+    GuardiansResponse, HasRoleResponse, InstantiateMsg, IssuedCheckmark,
+    ListAllowedContractsResponse, ListBannedAddressesResponse, ListBannedCheckmarksResponse,
+    ListIssuedByResponse, ListOrganizationsResponse, ListPendingAssignmentsResponse,
+    ListPendingRecoveriesResponse, ListReportsResponse, ListRoleMembersResponse,
+    ListScheduledRevocationsResponse, ListTimelockedActionsResponse,
+    ListVerificationRequestsResponse, MixingEpochResponse, OfferResponse, OptedOutResponse,
+    OrganizationEntry, PausedResponse, PendingAssignmentEntry, PendingRecovery,
+    PendingRecoveryResponse, QueryMsg, ReassignCooldownResponse, ReceiveMsg, ReportEntry,
+    ReportPoolResponse, ReportResponse, ResolveVoterResponse, ScheduledRevocationEntry,
+    ScheduledRevocationResponse, SecondaryAddressesResponse, SudoMsg, TimelockedActionEntry,
+    TimelockedActionResponse, UpdateConfigMsg, VerificationRequestEntry,
+    VerificationRequestResponse,
 };
+use crate::pubkey;
 use crate::state::{
-    ADDRESSES_TO_CHECKMARKS, ASSIGNER, BANNED_CHECKMARKS, CHECKMARKS_TO_ADDRESSES, CHECKMARK_COUNT,
+    denom_key, Appeal, Ban, BanReason, Config, Fee, FeeSplit, GuardianChange, Guardians, Issuance,
+    LastLoss, MixBatch, MixOp, Offer, PendingAssignment, Recovery, RecoveryProposal, Report,
+    ReportStatus, Role, ScheduledRevocation, Suspension, TimelockedAction, TimelockedMsg,
+    VerificationRequest, VerificationStatus, ADDRESSES_TO_CHECKMARKS, ADDRESSES_TO_EVM_ADDRESSES,
+    ADDRESSES_TO_PUBKEYS, ALLOWED_CONTRACTS, ASSIGNER, BANNED_ADDRESSES, BANNED_CHECKMARKS,
+    CANONICAL_ADDRESSES_TO_ADDRESSES, CHECKMARKS_TO_ADDRESSES, CHECKMARK_COUNT, CONFIG, DELEGATES,
+    DELEGATIONS, EVM_ADDRESSES_TO_ADDRESSES, FEE_BALANCES, GUARDIANS, ISSUANCES,
+    ISSUANCES_BY_ASSIGNER, ISSUED_CHECKMARK_IDS, LAST_LOSSES, MIX_BATCH, MIX_QUEUE,
+    MIX_QUEUED_ADDRESSES, MIX_QUEUED_CHECKMARKS, MIX_QUEUE_BY_ASSIGNER, MIX_QUEUE_END,
+    MIX_QUEUE_LEN, NEXT_EPOCH, OFFERED_CHECKMARKS, OFFERS, OFFERS_BY_ASSIGNER, OPTED_OUT,
+    ORGANIZATIONS, ORGANIZATION_COUNT, PAUSED, PENDING_ASSIGNMENTS,
+    PENDING_ASSIGNMENTS_BY_ASSIGNER, PENDING_DELETIONS, PENDING_GUARDIANS,
+    PENDING_SECONDARY_ADDRESSES, PRIMARY_SECONDARY_ADDRESSES, RECOVERIES, REPORTS,
+    REPORTS_BY_STATUS, REPORTS_BY_TARGET, REPORT_COUNT, REPORT_POOL, ROLES, SCHEDULED_REVOCATIONS,
+    SECONDARY_ADDRESSES, SUSPENSIONS, TIMELOCKED_ACTIONS, TIMELOCKED_ACTION_COUNT,
+    VERIFICATION_REQUESTS,
 };
 use cosmwasm_std::entry_point;
 use cw2::set_contract_version;
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg, Denom};
+use cw_storage_plus::Bound;
+use cw_utils::{Duration, Expiration, PaymentError};
+use sha2::{Digest, Sha256};
 
 // Version info for migration
 const CONTRACT_NAME: &str = "crates.io:cw-checkmark";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+// Pagination for list queries
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -45,69 +85,603 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    // Ensure governance has not paused the contract.
+    if PAUSED.may_load(deps.storage)?.unwrap_or_default() {
+        return Err(ContractError::Paused);
+    }
+
     match msg {
         ExecuteMsg::Assign {
             checkmark_id,
             address,
-        } => execute_assign(deps, info, checkmark_id, address),
-        ExecuteMsg::Delete {} => execute_delete(deps, info),
-        ExecuteMsg::RevokeCheckmark { checkmark_id } => {
-            execute_revoke_checkmark(deps, info, checkmark_id)
+        } => {
+            let payment = native_payment(&info)?;
+            execute_assign(deps, env, info, checkmark_id, address, payment)
+        }
+        ExecuteMsg::Receive(msg) => execute_receive(deps, env, info, msg),
+        ExecuteMsg::RequestVerification { session_commitment } => {
+            let payment = native_payment(&info)?;
+            execute_request_verification(deps, env, info, session_commitment, payment)
+        }
+        ExecuteMsg::RejectVerification {
+            address,
+            reason_code,
+            refund,
+        } => execute_reject_verification(deps, info, address, reason_code, refund),
+        ExecuteMsg::CancelVerification {} => execute_cancel_verification(deps, info),
+        ExecuteMsg::WithdrawFees { denom } => execute_withdraw_fees(deps, info, denom),
+        ExecuteMsg::ApproveAssign {
+            checkmark_id,
+            address,
+        } => execute_approve_assign(deps, env, info, checkmark_id, address),
+        ExecuteMsg::PrunePendingAssignments { limit } => {
+            execute_prune_pending_assignments(deps, env, limit)
+        }
+        ExecuteMsg::Delete {} => execute_delete(deps, env, info),
+        ExecuteMsg::AssignOrganization {
+            checkmark_id,
+            address,
+        } => {
+            let payment = native_payment(&info)?;
+            execute_assign_organization(deps, env, info, checkmark_id, address, payment)
+        }
+        ExecuteMsg::ReassignCheckmark {
+            checkmark_id,
+            address,
+        } => execute_reassign_checkmark(deps, env, info, checkmark_id, address),
+        ExecuteMsg::RotateCheckmark {
+            address,
+            new_checkmark_id,
+            reissue,
+        } => execute_rotate_checkmark(deps, env, info, address, new_checkmark_id, reissue),
+        ExecuteMsg::ProcessEpoch { limit } => execute_process_epoch(deps, env, limit),
+        ExecuteMsg::AcceptCheckmark {} => execute_accept_checkmark(deps, env, info),
+        ExecuteMsg::RejectCheckmark {} => execute_reject_checkmark(deps, info),
+        ExecuteMsg::OptOut {} => execute_opt_out(deps, info),
+        ExecuteMsg::OptIn {} => execute_opt_in(deps, info),
+        ExecuteMsg::CancelDelete {} => execute_cancel_delete(deps, info),
+        ExecuteMsg::FinalizeDelete { address } => execute_finalize_delete(deps, env, address),
+        ExecuteMsg::RevokeCheckmark {
+            checkmark_id,
+            reason,
+        } => execute_timelockable(
+            deps,
+            env,
+            info,
+            TimelockedMsg::RevokeCheckmark {
+                checkmark_id,
+                reason,
+            },
+        ),
+        ExecuteMsg::RevokeAddress { address, reason } => execute_timelockable(
+            deps,
+            env,
+            info,
+            TimelockedMsg::RevokeAddress { address, reason },
+        ),
+        ExecuteMsg::Appeal { evidence_hash } => execute_appeal(deps, env, info, evidence_hash),
+        ExecuteMsg::ResolveAppeal {
+            checkmark_id,
+            uphold,
+        } => execute_resolve_appeal(deps, info, checkmark_id, uphold),
+        ExecuteMsg::Report {
+            address,
+            reason,
+            evidence_hash,
+        } => execute_report(deps, info, address, reason, evidence_hash),
+        ExecuteMsg::ResolveReport { id, status } => {
+            execute_resolve_report(deps, env, info, id, status)
+        }
+        ExecuteMsg::FundReportPool {} => execute_fund_report_pool(deps, info),
+        ExecuteMsg::RevokeIssuedBy {
+            assigner,
+            from_height,
+            to_height,
+            limit,
+        } => execute_timelockable(
+            deps,
+            env,
+            info,
+            TimelockedMsg::RevokeIssuedBy {
+                assigner,
+                from_height,
+                to_height,
+                limit,
+            },
+        ),
+        ExecuteMsg::FinalizeRevocation { checkmark_id } => {
+            execute_finalize_revocation(deps, env, checkmark_id)
+        }
+        ExecuteMsg::Suspend {
+            address,
+            checkmark_id,
+            reason,
+            until,
+        } => execute_suspend(deps, info, address, checkmark_id, reason, until),
+        ExecuteMsg::Unsuspend {
+            address,
+            checkmark_id,
+        } => execute_unsuspend(deps, info, address, checkmark_id),
+        ExecuteMsg::UpdateCheckmarkBan {
+            ban_ids,
+            unban_ids,
+            reason,
+            note,
+            expiration,
+        } => execute_timelockable(
+            deps,
+            env,
+            info,
+            TimelockedMsg::UpdateCheckmarkBan {
+                ban_ids,
+                unban_ids,
+                reason,
+                note,
+                expiration,
+            },
+        ),
+        ExecuteMsg::UpdateAddressBan {
+            ban_addresses,
+            unban_addresses,
+            reason,
+            note,
+            expiration,
+        } => execute_timelockable(
+            deps,
+            env,
+            info,
+            TimelockedMsg::UpdateAddressBan {
+                ban_addresses,
+                unban_addresses,
+                reason,
+                note,
+                expiration,
+            },
+        ),
+        ExecuteMsg::UpdateAssigner { assigner } => {
+            execute_timelockable(deps, env, info, TimelockedMsg::UpdateAssigner { assigner })
         }
-        ExecuteMsg::RevokeAddress { address } => execute_revoke_address(deps, info, address),
-        ExecuteMsg::UpdateCheckmarkBan { ban_ids, unban_ids } => {
-            execute_update_checkmark_ban(deps, info, ban_ids, unban_ids)
+        ExecuteMsg::ExecuteTimelocked { id } => execute_execute_timelocked(deps, env, id),
+        ExecuteMsg::CancelTimelocked { id } => execute_cancel_timelocked(deps, info, id),
+        ExecuteMsg::LinkEvmAddress {
+            eth_address,
+            signature,
+        } => execute_link_evm_address(deps, env, info, eth_address, signature),
+        ExecuteMsg::RegisterPubkey { pubkey } => execute_register_pubkey(deps, info, pubkey),
+        ExecuteMsg::AddSecondaryAddress { address } => {
+            execute_add_secondary_address(deps, info, address)
+        }
+        ExecuteMsg::AcceptSecondaryAddress { primary } => {
+            execute_accept_secondary_address(deps, env, info, primary)
+        }
+        ExecuteMsg::RemoveSecondaryAddress { address } => {
+            execute_remove_secondary_address(deps, info, address)
+        }
+        ExecuteMsg::Delegate { to } => execute_delegate(deps, info, to),
+        ExecuteMsg::Undelegate {} => execute_undelegate(deps, info),
+        ExecuteMsg::SetGuardians {
+            guardians,
+            threshold,
+        } => execute_set_guardians(deps, env, info, guardians, threshold),
+        ExecuteMsg::ApproveRecovery {
+            holder,
+            new_address,
+        } => execute_approve_recovery(deps, env, info, holder, new_address),
+        ExecuteMsg::CancelRecovery { holder } => execute_cancel_recovery(deps, info, holder),
+        ExecuteMsg::ExecuteRecovery { holder } => execute_execute_recovery(deps, env, holder),
+        ExecuteMsg::UpdateConfig(update) => {
+            execute_timelockable(deps, env, info, TimelockedMsg::UpdateConfig(update))
+        }
+        ExecuteMsg::UpdateAllowedContracts { add, remove } => execute_timelockable(
+            deps,
+            env,
+            info,
+            TimelockedMsg::UpdateAllowedContracts { add, remove },
+        ),
+        ExecuteMsg::GrantRole { role, address } => {
+            execute_timelockable(deps, env, info, TimelockedMsg::GrantRole { role, address })
+        }
+        ExecuteMsg::RevokeRole { role, address } => {
+            execute_timelockable(deps, env, info, TimelockedMsg::RevokeRole { role, address })
+        }
+        ExecuteMsg::UpdateOwnership(cw_ownable::Action::AcceptOwnership) => {
+            execute_update_owner(deps, env, info, cw_ownable::Action::AcceptOwnership)
+        }
+        ExecuteMsg::UpdateOwnership(action) => {
+            execute_timelockable(deps, env, info, TimelockedMsg::UpdateOwnership(action))
         }
-        ExecuteMsg::UpdateAssigner { assigner } => execute_update_assigner(deps, info, assigner),
-        ExecuteMsg::UpdateOwnership(action) => execute_update_owner(deps, env, info, action),
     }
 }
 
 fn execute_assign(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     checkmark_id: String,
     address: String,
+    payment: Option<Fee>,
 ) -> Result<Response, ContractError> {
     let addr = deps.api.addr_validate(&address)?;
 
-    // Ensure the sender is the owner or the assigner.
+    // Ensure the sender is the owner or an assigner.
     let ownership_asserted = cw_ownable::assert_owner(deps.storage, &info.sender).is_ok();
-    let assigner = ASSIGNER.load(deps.storage)?;
-    if info.sender != assigner && !ownership_asserted {
+    if !ownership_asserted && !is_assigner(deps.storage, &info.sender)? {
         return Err(ContractError::Unauthorized);
     }
 
-    // Ensure checkmark_id is not banned.
-    let banned = BANNED_CHECKMARKS.has(deps.storage, checkmark_id.clone());
-    if banned {
-        return Err(ContractError::CheckmarkBanned);
+    // Ensure assigners approve instead if multiple approvals are required.
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    if !ownership_asserted && config.assign_threshold > 1 {
+        return Err(ContractError::ApprovalRequired);
     }
 
-    // Ensure checkmark_id is not already assigned.
-    let existing_address = CHECKMARKS_TO_ADDRESSES.may_load(deps.storage, checkmark_id.clone())?;
-    if existing_address.is_some() {
-        return Err(ContractError::AlreadyAssigned);
+    ensure_assignable(deps.storage, &env.block, &checkmark_id, &addr)?;
+    ensure_not_contract(deps.as_ref(), &addr)?;
+    let expired_refund = withdraw_expired_offer(deps.storage, &env.block, &addr)?;
+    let fee = hold_fee(deps.storage, &addr, &payment)?;
+
+    let issuance = Issuance {
+        assigner: info.sender,
+        height: env.block.height,
+    };
+
+    // Queue the assignment until the end of the epoch in mixing mode, holding
+    // the fee until it is applied.
+    if config.mixing_epoch.is_some() {
+        queue_mix_op(
+            deps.storage,
+            MixOp::Assign {
+                checkmark_id: checkmark_id.clone(),
+                address: addr,
+                issuance,
+                payment,
+            },
+        )?;
+
+        return Ok(Response::default()
+            .add_attribute("method", "queue_assign")
+            .add_attribute("checkmark_id", checkmark_id)
+            .add_attribute("address", address)
+            .add_attributes(fee.map(|fee| ("fee", fee.to_string())))
+            .add_messages(expired_refund));
     }
 
-    // Ensure address does not already have a checkmark.
-    let existing_checkmark = ADDRESSES_TO_CHECKMARKS.may_load(deps.storage, addr.clone())?;
-    if existing_checkmark.is_some() {
+    // Assign the checkmark, or offer it if consent is required.
+    let (offered, refund) = assign_or_offer(
+        deps.storage,
+        &env.block,
+        &checkmark_id,
+        &addr,
+        issuance,
+        payment,
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("method", if offered { "offer" } else { "assign" })
+        .add_attribute("checkmark_id", checkmark_id)
+        .add_attribute("address", address)
+        .add_attributes(fee.map(|fee| ("fee", fee.to_string())))
+        .add_messages(expired_refund)
+        .add_messages(refund))
+}
+
+fn execute_receive(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    wrapper: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    // The sender is the token contract, paying on behalf of the cw20 sender.
+    let payment = Some(Fee {
+        denom: Denom::Cw20(info.sender),
+        amount: wrapper.amount,
+    });
+    let info = MessageInfo {
+        sender: deps.api.addr_validate(&wrapper.sender)?,
+        funds: vec![],
+    };
+
+    match from_binary(&wrapper.msg)? {
+        ReceiveMsg::Assign {
+            checkmark_id,
+            address,
+        } => execute_assign(deps, env, info, checkmark_id, address, payment),
+        ReceiveMsg::RequestVerification { session_commitment } => {
+            execute_request_verification(deps, env, info, session_commitment, payment)
+        }
+        ReceiveMsg::AssignOrganization {
+            checkmark_id,
+            address,
+        } => execute_assign_organization(deps, env, info, checkmark_id, address, payment),
+    }
+}
+
+fn execute_request_verification(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    session_commitment: String,
+    payment: Option<Fee>,
+) -> Result<Response, ContractError> {
+    let session_commitment = validate_session_commitment(&session_commitment)?;
+
+    if active_address_ban(deps.storage, &env.block, &info.sender)?.is_some() {
+        return Err(ContractError::AddressBanned);
+    }
+    if ADDRESSES_TO_CHECKMARKS.has(deps.storage, info.sender.clone()) {
         return Err(ContractError::AlreadyHasCheckmark);
     }
+    if pending_verification_request(deps.storage, &info.sender)?.is_some()
+        || assigning_verification_request(deps.storage, &info.sender)?.is_some()
+    {
+        return Err(ContractError::AlreadyRequested);
+    }
 
-    // Assign the checkmark.
-    CHECKMARKS_TO_ADDRESSES.save(deps.storage, checkmark_id.clone(), &addr)?;
-    ADDRESSES_TO_CHECKMARKS.save(deps.storage, addr, &checkmark_id)?;
-    CHECKMARK_COUNT.update(deps.storage, |count| Ok::<u64, StdError>(count + 1))?;
+    // Ensure one of the fees was paid into escrow, if any are configured.
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    let fee = match payment {
+        None if config.fees.is_empty() => None,
+        Some(_) if config.fees.is_empty() => return Err(PaymentError::NonPayable {}.into()),
+        Some(payment) if config.fees.contains(&payment) => Some(payment),
+        _ => return Err(incorrect_fee(&config)),
+    };
+    VERIFICATION_REQUESTS.save(
+        deps.storage,
+        info.sender.clone(),
+        &VerificationRequest {
+            session_commitment: session_commitment.clone(),
+            fee: fee.clone(),
+            status: VerificationStatus::Pending,
+        },
+    )?;
 
     Ok(Response::default()
-        .add_attribute("method", "assign")
-        .add_attribute("checkmark_id", checkmark_id)
-        .add_attribute("address", address))
+        .add_attribute("method", "request_verification")
+        .add_attribute("address", info.sender)
+        .add_attribute("session_commitment", session_commitment)
+        .add_attributes(fee.map(|fee| ("fee", fee.to_string()))))
+}
+
+fn execute_reject_verification(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    reason_code: u32,
+    refund: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    // Ensure the sender is the owner or an assigner.
+    if cw_ownable::assert_owner(deps.storage, &info.sender).is_err()
+        && !is_assigner(deps.storage, &info.sender)?
+    {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let addr = deps.api.addr_validate(&address)?;
+    let mut request = pending_verification_request(deps.storage, &addr)?
+        .ok_or(ContractError::NoVerificationRequest)?;
+
+    let mut response = Response::default()
+        .add_attribute("method", "reject_verification")
+        .add_attribute("address", addr.clone())
+        .add_attribute("reason_code", reason_code.to_string());
+
+    // Refund the requested amount, and collect the rest.
+    let refunded = match &request.fee {
+        Some(fee) => {
+            let refunded = refund.unwrap_or(fee.amount);
+            if refunded > fee.amount {
+                return Err(ContractError::InvalidRefund);
+            }
+
+            if !refunded.is_zero() {
+                response = response.add_message(fee_transfer(&fee.denom, &addr, refunded)?);
+            }
+            credit_fee(
+                deps.storage,
+                &Fee {
+                    denom: fee.denom.clone(),
+                    amount: fee.amount - refunded,
+                },
+            )?;
+
+            refunded
+        }
+        None => Uint128::zero(),
+    };
+
+    request.status = VerificationStatus::Rejected {
+        reason_code,
+        refunded,
+    };
+    VERIFICATION_REQUESTS.save(deps.storage, addr, &request)?;
+
+    Ok(response.add_attribute("refunded", refunded.to_string()))
+}
+
+fn execute_cancel_verification(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let mut request = pending_verification_request(deps.storage, &info.sender)?
+        .ok_or(ContractError::NoVerificationRequest)?;
+
+    let mut response = Response::default()
+        .add_attribute("method", "cancel_verification")
+        .add_attribute("address", info.sender.clone());
+
+    // Refund the whole fee.
+    if let Some(fee) = request.fee.as_ref().filter(|fee| !fee.amount.is_zero()) {
+        response = response
+            .add_message(fee_transfer(&fee.denom, &info.sender, fee.amount)?)
+            .add_attribute("refunded", fee.amount.to_string());
+    }
+
+    request.status = VerificationStatus::Cancelled;
+    VERIFICATION_REQUESTS.save(deps.storage, info.sender, &request)?;
+
+    Ok(response)
+}
+
+fn execute_withdraw_fees(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: Denom,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    let split = config.fee_split.ok_or(ContractError::NoFeeSplit)?;
+
+    let balance = FEE_BALANCES
+        .may_load(deps.storage, denom_key(&denom))?
+        .ok_or(ContractError::NoFeeBalance)?;
+    FEE_BALANCES.remove(deps.storage, denom_key(&denom));
+
+    // The community pool receives the remainder after rounding down the
+    // other shares.
+    let provider_amount = balance.amount * split.provider_share;
+    let operator_amount = balance.amount * split.operator_share;
+    let community_pool_amount = balance.amount - provider_amount - operator_amount;
+
+    let mut response = Response::default()
+        .add_attribute("method", "withdraw_fees")
+        .add_attribute("fees", balance.to_string());
+    for (recipient, amount) in [
+        (split.provider, provider_amount),
+        (split.operator, operator_amount),
+        (split.community_pool, community_pool_amount),
+    ] {
+        if !amount.is_zero() {
+            response = response.add_message(fee_transfer(&denom, &recipient, amount)?);
+        }
+    }
+
+    Ok(response)
+}
+
+fn execute_approve_assign(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    checkmark_id: String,
+    address: String,
+) -> Result<Response, ContractError> {
+    let addr = deps.api.addr_validate(&address)?;
+
+    // Ensure the sender is an assigner.
+    if !is_assigner(deps.storage, &info.sender)? {
+        return Err(ContractError::Unauthorized);
+    }
+
+    ensure_assignable(deps.storage, &env.block, &checkmark_id, &addr)?;
+    ensure_not_contract(deps.as_ref(), &addr)?;
+
+    // Start a new proposal if none is pending or the pending one expired.
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    let mut pending = PENDING_ASSIGNMENTS
+        .may_load(deps.storage, checkmark_id.clone())?
+        .filter(|pending| !pending.expiration.is_expired(&env.block))
+        .unwrap_or(PendingAssignment {
+            address: addr.clone(),
+            approvals: vec![],
+            expiration: config.assign_approval_timeout.after(&env.block),
+        });
+
+    // Ensure approval matches the pending proposal.
+    if pending.address != addr {
+        return Err(ContractError::AssignmentMismatch);
+    }
+    if pending.approvals.contains(&info.sender) {
+        return Err(ContractError::AlreadyApproved);
+    }
+
+    pending.approvals.push(info.sender.clone());
+
+    let response = Response::default()
+        .add_attribute("method", "approve_assign")
+        .add_attribute("checkmark_id", checkmark_id.clone())
+        .add_attribute("address", address)
+        .add_attribute("approvals", pending.approvals.len().to_string());
+
+    // Assign, or offer if consent is required, once enough assigners approve.
+    // Fees must have been paid by the recipient requesting verification.
+    if pending.approvals.len() as u32 >= config.assign_threshold {
+        remove_pending_assignment(deps.storage, &checkmark_id)?;
+        let expired_refund = withdraw_expired_offer(deps.storage, &env.block, &addr)?;
+        let fee = hold_fee(deps.storage, &addr, &None)?;
+        let response = response
+            .add_attributes(fee.map(|fee| ("fee", fee.to_string())))
+            .add_messages(expired_refund);
+        let issuance = Issuance {
+            assigner: info.sender,
+            height: env.block.height,
+        };
+
+        // Queue the assignment until the end of the epoch in mixing mode.
+        if config.mixing_epoch.is_some() {
+            queue_mix_op(
+                deps.storage,
+                MixOp::Assign {
+                    checkmark_id,
+                    address: addr,
+                    issuance,
+                    payment: None,
+                },
+            )?;
+
+            return Ok(response
+                .add_attribute("assigned", "false")
+                .add_attribute("queued", "true"));
+        }
+
+        let (offered, refund) = assign_or_offer(
+            deps.storage,
+            &env.block,
+            &checkmark_id,
+            &addr,
+            issuance,
+            None,
+        )?;
+
+        return Ok(response
+            .add_attribute("assigned", (!offered).to_string())
+            .add_attribute("offered", offered.to_string())
+            .add_messages(refund));
+    }
+
+    save_pending_assignment(deps.storage, &checkmark_id, &pending)?;
+
+    Ok(response.add_attribute("assigned", "false"))
+}
+
+fn execute_prune_pending_assignments(
+    deps: DepsMut,
+    env: Env,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let expired = PENDING_ASSIGNMENTS
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|item| {
+            item.as_ref().map_or(true, |(_, pending)| {
+                pending.expiration.is_expired(&env.block)
+            })
+        })
+        .take(limit)
+        .map(|item| item.map(|(checkmark_id, _)| checkmark_id))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for checkmark_id in &expired {
+        remove_pending_assignment(deps.storage, checkmark_id)?;
+    }
+
+    Ok(Response::default()
+        .add_attribute("method", "prune_pending_assignments")
+        .add_attribute("pruned", expired.len().to_string()))
 }
 
-fn execute_delete(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+fn execute_delete(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
     // Ensure address has a checkmark.
     let existing_checkmark = ADDRESSES_TO_CHECKMARKS.may_load(deps.storage, info.sender.clone())?;
     if existing_checkmark.is_none() {
@@ -117,10 +691,30 @@ fn execute_delete(deps: DepsMut, info: MessageInfo) -> Result<Response, Contract
     let checkmark_id = existing_checkmark.unwrap();
     let addr = info.sender;
 
+    // Schedule the deletion if a delay is configured.
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    if let Some(delay) = config.deletion_delay {
+        if PENDING_DELETIONS.has(deps.storage, checkmark_id.clone()) {
+            return Err(ContractError::DeletionAlreadyScheduled);
+        }
+
+        let finalize_after = delay.after(&env.block);
+        PENDING_DELETIONS.save(deps.storage, checkmark_id.clone(), &finalize_after)?;
+
+        return Ok(Response::default()
+            .add_attribute("method", "schedule_delete")
+            .add_attribute("checkmark_id", checkmark_id)
+            .add_attribute("address", addr)
+            .add_attribute("finalize_after", finalize_after.to_string()));
+    }
+
+    // Queue the deletion until the end of the epoch in mixing mode.
+    if config.mixing_epoch.is_some() {
+        return queue_delete(deps, checkmark_id, addr);
+    }
+
     // Remove the checkmark.
-    CHECKMARKS_TO_ADDRESSES.remove(deps.storage, checkmark_id.clone());
-    ADDRESSES_TO_CHECKMARKS.remove(deps.storage, addr.clone());
-    CHECKMARK_COUNT.update(deps.storage, |count| Ok::<u64, StdError>(count - 1))?;
+    lose_checkmark(deps.storage, &env.block, &checkmark_id, &addr)?;
 
     Ok(Response::default()
         .add_attribute("method", "delete")
@@ -128,143 +722,3672 @@ fn execute_delete(deps: DepsMut, info: MessageInfo) -> Result<Response, Contract
         .add_attribute("address", addr))
 }
 
-fn execute_revoke_checkmark(
+fn execute_assign_organization(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     checkmark_id: String,
+    address: String,
+    payment: Option<Fee>,
 ) -> Result<Response, ContractError> {
-    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+    assert_can_assign_directly(deps.storage, &info.sender)?;
 
-    // Ensure checkmark exists.
-    let existing_address = CHECKMARKS_TO_ADDRESSES.may_load(deps.storage, checkmark_id.clone())?;
-    if existing_address.is_none() {
-        return Err(ContractError::NoCheckmark);
+    let addr = deps.api.addr_validate(&address)?;
+    ensure_assignable(deps.storage, &env.block, &checkmark_id, &addr)?;
+
+    // Only contracts have contract info.
+    if deps.querier.query_wasm_contract_info(&addr).is_err() {
+        return Err(ContractError::NotContract);
     }
 
-    let addr = existing_address.unwrap();
+    let expired_refund = withdraw_expired_offer(deps.storage, &env.block, &addr)?;
+    let fee = hold_fee(deps.storage, &addr, &payment)?;
 
-    // Remove the checkmark.
-    CHECKMARKS_TO_ADDRESSES.remove(deps.storage, checkmark_id.clone());
-    ADDRESSES_TO_CHECKMARKS.remove(deps.storage, addr.clone());
-    CHECKMARK_COUNT.update(deps.storage, |count| Ok::<u64, StdError>(count - 1))?;
+    // Organizations are assigned directly, even in consent and mixing modes.
+    assign_checkmark(
+        deps.storage,
+        &checkmark_id,
+        &addr,
+        &Issuance {
+            assigner: info.sender,
+            height: env.block.height,
+        },
+    )?;
+    save_organization(deps.storage, &checkmark_id)?;
+    collect_fee(deps.storage, &addr, payment)?;
 
     Ok(Response::default()
-        .add_attribute("method", "revoke_checkmark")
+        .add_attribute("method", "assign_organization")
         .add_attribute("checkmark_id", checkmark_id)
-        .add_attribute("address", addr))
+        .add_attribute("address", address)
+        .add_attributes(fee.map(|fee| ("fee", fee.to_string())))
+        .add_messages(expired_refund))
 }
 
-fn execute_revoke_address(
+fn execute_reassign_checkmark(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
+    checkmark_id: String,
     address: String,
 ) -> Result<Response, ContractError> {
-    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+    assert_can_assign_directly(deps.storage, &info.sender)?;
 
     let addr = deps.api.addr_validate(&address)?;
+    let old_addr = CHECKMARKS_TO_ADDRESSES
+        .may_load(deps.storage, checkmark_id.clone())?
+        .ok_or(ContractError::NoCheckmark)?;
 
-    // Ensure checkmark exists.
-    let existing_checkmark = ADDRESSES_TO_CHECKMARKS.may_load(deps.storage, addr.clone())?;
-    if existing_checkmark.is_none() {
-        return Err(ContractError::NoCheckmark);
+    // Queued deletions refer to the old address.
+    if MIX_QUEUED_CHECKMARKS.has(deps.storage, checkmark_id.clone()) {
+        return Err(ContractError::AlreadyQueued);
+    }
+    if active_ban(deps.storage, &env.block, &checkmark_id)?.is_some() {
+        return Err(ContractError::CheckmarkBanned);
+    }
+    ensure_address_assignable(deps.storage, &env.block, &addr)?;
+    if !ORGANIZATIONS.has(deps.storage, checkmark_id.clone()) {
+        ensure_not_contract(deps.as_ref(), &addr)?;
+    } else if deps.querier.query_wasm_contract_info(&addr).is_err() {
+        return Err(ContractError::NotContract);
     }
 
-    let checkmark_id = existing_checkmark.unwrap();
+    // Reassignments are not queued, so they would bypass mixing mode.
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    if config.mixing_epoch.is_some() {
+        return Err(ContractError::MixingEnabled);
+    }
 
-    // Remove the checkmark.
-    CHECKMARKS_TO_ADDRESSES.remove(deps.storage, checkmark_id.clone());
-    ADDRESSES_TO_CHECKMARKS.remove(deps.storage, addr.clone());
-    CHECKMARK_COUNT.update(deps.storage, |count| Ok::<u64, StdError>(count - 1))?;
+    let issuance = Issuance {
+        assigner: info.sender,
+        height: env.block.height,
+    };
+    let response = Response::default()
+        .add_attribute("method", "reassign_checkmark")
+        .add_attribute("checkmark_id", checkmark_id.clone())
+        .add_attribute("old_address", old_addr.clone())
+        .add_attribute("address", addr.clone());
 
-    Ok(Response::default()
-        .add_attribute("method", "revoke_address")
-        .add_attribute("checkmark_id", checkmark_id)
-        .add_attribute("address", addr))
+    // Offer the checkmark if consent is required. The old address keeps it
+    // until the offer is accepted.
+    if config.require_consent {
+        if let Some(offered_to) = OFFERED_CHECKMARKS.may_load(deps.storage, checkmark_id.clone())? {
+            let offer = OFFERS.load(deps.storage, offered_to.clone())?;
+            if !offer.expiration.is_expired(&env.block) {
+                return Err(ContractError::CheckmarkOffered);
+            }
+            withdraw_offer(deps.storage, &offered_to, offer)?;
+        }
+        let expired_refund = withdraw_expired_offer(deps.storage, &env.block, &addr)?;
+
+        save_offer(
+            deps.storage,
+            &addr,
+            &Offer {
+                checkmark_id,
+                issuance,
+                expiration: config.offer_timeout.after(&env.block),
+                payment: None,
+                reassign_from: Some(old_addr),
+            },
+        )?;
+
+        return Ok(response
+            .add_attribute("offered", "true")
+            .add_messages(expired_refund));
+    }
+
+    // Move the checkmark, recording the sender as its issuer.
+    move_checkmark(deps.storage, &checkmark_id, &old_addr, &addr)?;
+    save_issuance(deps.storage, &checkmark_id, &issuance)?;
+
+    Ok(response.add_attribute("offered", "false"))
 }
 
-fn execute_update_checkmark_ban(
+/// Ensures the sender can assign checkmarks without approvals: the owner, or
+/// an assigner if a single approval suffices.
+fn assert_can_assign_directly(storage: &dyn Storage, sender: &Addr) -> Result<(), ContractError> {
+    if cw_ownable::assert_owner(storage, sender).is_ok() {
+        return Ok(());
+    }
+    if !is_assigner(storage, sender)? {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let config = CONFIG.may_load(storage)?.unwrap_or_default();
+    if config.assign_threshold > 1 {
+        return Err(ContractError::ApprovalRequired);
+    }
+
+    Ok(())
+}
+
+fn execute_rotate_checkmark(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    ban_ids: Option<Vec<String>>,
-    unban_ids: Option<Vec<String>>,
+    address: String,
+    new_checkmark_id: String,
+    reissue: Option<bool>,
 ) -> Result<Response, ContractError> {
-    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+    assert_can_assign_directly(deps.storage, &info.sender)?;
 
-    for ban_id in ban_ids.map_or_else(Vec::new, |v| v) {
-        // If banning, remove checkmark if exists.
-        let existing_address = CHECKMARKS_TO_ADDRESSES.may_load(deps.storage, ban_id.clone())?;
-        if let Some(addr) = existing_address {
-            // Remove the checkmark.
-            CHECKMARKS_TO_ADDRESSES.remove(deps.storage, ban_id.clone());
-            ADDRESSES_TO_CHECKMARKS.remove(deps.storage, addr);
-            CHECKMARK_COUNT.update(deps.storage, |count| Ok::<u64, StdError>(count - 1))?;
-        }
+    let addr = deps.api.addr_validate(&address)?;
+    let old_checkmark_id = ADDRESSES_TO_CHECKMARKS
+        .may_load(deps.storage, addr.clone())?
+        .ok_or(ContractError::NoCheckmark)?;
 
-        // Add to banned list.
-        BANNED_CHECKMARKS.save(deps.storage, ban_id.clone(), &Empty {})?;
+    // Queued deletions refer to the old checkmark ID.
+    if MIX_QUEUED_ADDRESSES.has(deps.storage, addr.clone()) {
+        return Err(ContractError::AlreadyQueued);
     }
+    ensure_checkmark_id_available(deps.storage, &env.block, &new_checkmark_id)?;
 
-    for unban_id in unban_ids.map_or_else(Vec::new, |v| v) {
-        // Remove from banned list.
-        BANNED_CHECKMARKS.remove(deps.storage, unban_id.clone());
+    rotate_checkmark(deps.storage, &old_checkmark_id, &new_checkmark_id, &addr)?;
+
+    // Record the sender as the issuer when correcting a mistaken assignment.
+    let reissue = reissue.unwrap_or(false);
+    if reissue {
+        save_issuance(
+            deps.storage,
+            &new_checkmark_id,
+            &Issuance {
+                assigner: info.sender,
+                height: env.block.height,
+            },
+        )?;
     }
 
-    Ok(Response::default().add_attribute("method", "update_checkmark_ban"))
+    Ok(Response::default()
+        .add_attribute("method", "rotate_checkmark")
+        .add_attribute("address", addr)
+        .add_attribute("old_checkmark_id", old_checkmark_id)
+        .add_attribute("new_checkmark_id", new_checkmark_id)
+        .add_attribute("reissued", reissue.to_string()))
 }
 
-fn execute_update_assigner(
+fn queue_delete(
     deps: DepsMut,
-    info: MessageInfo,
-    assigner: String,
+    checkmark_id: String,
+    addr: Addr,
 ) -> Result<Response, ContractError> {
-    cw_ownable::assert_owner(deps.storage, &info.sender)?;
-
-    let assigner = deps.api.addr_validate(&assigner)?;
+    if MIX_QUEUED_ADDRESSES.has(deps.storage, addr.clone()) {
+        return Err(ContractError::AlreadyQueued);
+    }
 
-    // Update the assigner.
-    ASSIGNER.save(deps.storage, &assigner)?;
+    queue_mix_op(
+        deps.storage,
+        MixOp::Delete {
+            checkmark_id: checkmark_id.clone(),
+            address: addr.clone(),
+        },
+    )?;
 
     Ok(Response::default()
-        .add_attribute("method", "update_assigner")
-        .add_attribute("assigner", assigner))
+        .add_attribute("method", "queue_delete")
+        .add_attribute("checkmark_id", checkmark_id)
+        .add_attribute("address", addr))
 }
 
-pub fn execute_update_owner(
+fn execute_process_epoch(
     deps: DepsMut,
     env: Env,
-    info: MessageInfo,
-    action: cw_ownable::Action,
+    limit: Option<u32>,
 ) -> Result<Response, ContractError> {
-    let ownership = cw_ownable::update_ownership(deps, &env.block, &info.sender, action)?;
-    Ok(Response::default().add_attributes(ownership.into_attributes()))
-}
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as u64;
 
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    match msg {
-        QueryMsg::GetCheckmark { address } => {
-            let addr = deps.api.addr_validate(&address)?;
+    // Continue the ended epoch, or end the current one, sealing its queue and
+    // starting the next epoch. Operations queued from now on wait for it.
+    let mut batch = match MIX_BATCH.may_load(deps.storage)? {
+        Some(batch) if batch.next < batch.end => batch,
+        batch => {
+            if let Some(next_epoch) = NEXT_EPOCH.may_load(deps.storage)? {
+                if !next_epoch.is_expired(&env.block) {
+                    return Err(ContractError::EpochNotEnded);
+                }
+            }
 
-            to_binary(&GetCheckmarkResponse {
-                checkmark_id: ADDRESSES_TO_CHECKMARKS
-                    .may_load(deps.storage, addr)
-                    .unwrap(),
-            })
+            let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+            match config.mixing_epoch {
+                Some(epoch) => NEXT_EPOCH.save(deps.storage, &epoch.after(&env.block))?,
+                None => NEXT_EPOCH.remove(deps.storage),
+            }
+
+            MixBatch {
+                next: batch.map_or(0, |batch| batch.end),
+                end: MIX_QUEUE_END.may_load(deps.storage)?.unwrap_or_default(),
+                seed: Binary::from(
+                    Sha256::new()
+                        .chain_update(env.block.chain_id.as_bytes())
+                        .chain_update(env.block.height.to_be_bytes())
+                        .chain_update(env.block.time.nanos().to_be_bytes())
+                        .finalize()
+                        .to_vec(),
+                ),
+            }
         }
-        QueryMsg::GetAddress { checkmark_id } => to_binary(&GetAddressResponse {
-            address: CHECKMARKS_TO_ADDRESSES
-                .may_load(deps.storage, checkmark_id)
-                .unwrap(),
-        }),
-        QueryMsg::Count {} => to_binary(&CountResponse {
-            count: CHECKMARK_COUNT.load(deps.storage)?,
-        }),
-        QueryMsg::CheckmarkBanned { checkmark_id } => to_binary(&CheckmarkBannedResponse {
-            banned: BANNED_CHECKMARKS.has(deps.storage, checkmark_id),
-        }),
-        QueryMsg::Assigner {} => to_binary(&AssignerResponse {
-            assigner: ASSIGNER.load(deps.storage)?,
-        }),
+    };
+
+    // Apply the operations in a shuffled order, one Fisher-Yates step at a
+    // time, skipping positions left empty by withdrawn operations and
+    // operations that are no longer valid, such as assignments to addresses
+    // banned since queueing.
+    let mut applied = 0;
+    let mut skipped = 0;
+    let mut refunds = vec![];
+    let stop = batch.end.min(batch.next + limit);
+    while batch.next < stop {
+        let position = batch.next + draw(&batch.seed, batch.next, batch.end - batch.next);
+        let op = MIX_QUEUE.may_load(deps.storage, position)?;
+        if let Some(op) = &op {
+            unqueue_mix_op(deps.storage, position, op)?;
+        }
+        if position != batch.next {
+            if let Some(swapped) = MIX_QUEUE.may_load(deps.storage, batch.next)? {
+                remove_mix_op(deps.storage, batch.next, &swapped);
+                requeue_mix_op(deps.storage, position, &swapped)?;
+            }
+        }
+        batch.next += 1;
+
+        let op = match op {
+            Some(op) => op,
+            None => continue,
+        };
+
+        match op {
+            MixOp::Assign {
+                checkmark_id,
+                address,
+                issuance,
+                payment,
+            } => {
+                // Release the fee held for assignments that are skipped.
+                if ensure_assignable(deps.storage, &env.block, &checkmark_id, &address).is_ok() {
+                    let (_, refund) = assign_or_offer(
+                        deps.storage,
+                        &env.block,
+                        &checkmark_id,
+                        &address,
+                        issuance,
+                        payment,
+                    )?;
+                    refunds.extend(refund);
+                    applied += 1;
+                } else {
+                    refunds.extend(release_fee(
+                        deps.storage,
+                        &address,
+                        payment,
+                        &issuance.assigner,
+                    )?);
+                    skipped += 1;
+                }
+            }
+            MixOp::Delete {
+                checkmark_id,
+                address,
+            } => {
+                let holder =
+                    CHECKMARKS_TO_ADDRESSES.may_load(deps.storage, checkmark_id.clone())?;
+                if holder.as_ref() == Some(&address) {
+                    lose_checkmark(deps.storage, &env.block, &checkmark_id, &address)?;
+                    applied += 1;
+                } else {
+                    skipped += 1;
+                }
+            }
+        }
+    }
+    MIX_BATCH.save(deps.storage, &batch)?;
+
+    Ok(Response::default()
+        .add_messages(refunds)
+        .add_attribute("method", "process_epoch")
+        .add_attribute("applied", applied.to_string())
+        .add_attribute("skipped", skipped.to_string())
+        .add_attribute("remaining", (batch.next < batch.end).to_string()))
+}
+
+fn execute_accept_checkmark(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let offer = OFFERS
+        .may_load(deps.storage, info.sender.clone())?
+        .ok_or(ContractError::NoOffer)?;
+    if offer.expiration.is_expired(&env.block) {
+        return Err(ContractError::OfferExpired);
+    }
+
+    // Ensure the checkmark can still be assigned, since bans and other
+    // changes may have happened since the offer.
+    remove_offer(deps.storage, &info.sender, &offer);
+    match &offer.reassign_from {
+        // Reassigned checkmarks move from the old address, if it still holds
+        // them.
+        Some(old_addr) => {
+            let holder =
+                CHECKMARKS_TO_ADDRESSES.may_load(deps.storage, offer.checkmark_id.clone())?;
+            if holder.as_ref() != Some(old_addr) {
+                return Err(ContractError::NoCheckmark);
+            }
+            if active_ban(deps.storage, &env.block, &offer.checkmark_id)?.is_some() {
+                return Err(ContractError::CheckmarkBanned);
+            }
+            ensure_address_assignable(deps.storage, &env.block, &info.sender)?;
+
+            move_checkmark(deps.storage, &offer.checkmark_id, old_addr, &info.sender)?;
+            save_issuance(deps.storage, &offer.checkmark_id, &offer.issuance)?;
+        }
+        None => {
+            ensure_assignable(deps.storage, &env.block, &offer.checkmark_id, &info.sender)?;
+            assign_checkmark(
+                deps.storage,
+                &offer.checkmark_id,
+                &info.sender,
+                &offer.issuance,
+            )?;
+        }
+    }
+    let fee = collect_fee(deps.storage, &info.sender, offer.payment)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "accept_checkmark")
+        .add_attribute("checkmark_id", offer.checkmark_id)
+        .add_attribute("address", info.sender)
+        .add_attributes(fee.map(|fee| ("fee", fee.to_string()))))
+}
+
+fn execute_reject_checkmark(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let offer = OFFERS
+        .may_load(deps.storage, info.sender.clone())?
+        .ok_or(ContractError::NoOffer)?;
+
+    let checkmark_id = offer.checkmark_id.clone();
+    let refund = withdraw_offer(deps.storage, &info.sender, offer)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "reject_checkmark")
+        .add_attribute("checkmark_id", checkmark_id)
+        .add_attribute("address", info.sender)
+        .add_messages(refund))
+}
+
+fn execute_opt_out(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    OPTED_OUT.save(deps.storage, info.sender.clone(), &Empty {})?;
+
+    // Reject any pending offer.
+    let refund = match OFFERS.may_load(deps.storage, info.sender.clone())? {
+        Some(offer) => withdraw_offer(deps.storage, &info.sender, offer)?,
+        None => None,
+    };
+
+    Ok(Response::default()
+        .add_attribute("method", "opt_out")
+        .add_attribute("address", info.sender)
+        .add_messages(refund))
+}
+
+fn execute_opt_in(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    OPTED_OUT.remove(deps.storage, info.sender.clone());
+
+    Ok(Response::default()
+        .add_attribute("method", "opt_in")
+        .add_attribute("address", info.sender))
+}
+
+fn execute_cancel_delete(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let checkmark_id = ADDRESSES_TO_CHECKMARKS
+        .may_load(deps.storage, info.sender.clone())?
+        .ok_or(ContractError::NoCheckmark)?;
+
+    if !PENDING_DELETIONS.has(deps.storage, checkmark_id.clone()) {
+        return Err(ContractError::NoScheduledDeletion);
+    }
+    // Finalized deletions queued in the mixing epoch cannot be cancelled.
+    if MIX_QUEUED_ADDRESSES.has(deps.storage, info.sender.clone()) {
+        return Err(ContractError::AlreadyQueued);
+    }
+
+    PENDING_DELETIONS.remove(deps.storage, checkmark_id.clone());
+
+    Ok(Response::default()
+        .add_attribute("method", "cancel_delete")
+        .add_attribute("checkmark_id", checkmark_id)
+        .add_attribute("address", info.sender))
+}
+
+fn execute_finalize_delete(
+    deps: DepsMut,
+    env: Env,
+    address: String,
+) -> Result<Response, ContractError> {
+    let addr = deps.api.addr_validate(&address)?;
+
+    let checkmark_id = ADDRESSES_TO_CHECKMARKS
+        .may_load(deps.storage, addr.clone())?
+        .ok_or(ContractError::NoCheckmark)?;
+
+    // Ensure the delay has passed.
+    let finalize_after = PENDING_DELETIONS
+        .may_load(deps.storage, checkmark_id.clone())?
+        .ok_or(ContractError::NoScheduledDeletion)?;
+    if !finalize_after.is_expired(&env.block) {
+        return Err(ContractError::DeletionNotReady);
+    }
+
+    // Queue the deletion until the end of the epoch in mixing mode.
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    if config.mixing_epoch.is_some() {
+        return queue_delete(deps, checkmark_id, addr);
+    }
+
+    // Remove the checkmark, along with the scheduled deletion.
+    lose_checkmark(deps.storage, &env.block, &checkmark_id, &addr)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "finalize_delete")
+        .add_attribute("checkmark_id", checkmark_id)
+        .add_attribute("address", addr))
+}
+
+fn execute_timelockable(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: TimelockedMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    let delay = match config.timelock_delay {
+        Some(delay) => delay,
+        None => return execute_timelocked_msg(deps, env, info, msg),
+    };
+
+    // Ensure the sender could perform the action now.
+    match &msg {
+        TimelockedMsg::RevokeCheckmark { .. } | TimelockedMsg::RevokeAddress { .. } => {
+            assert_owner_or_role(deps.storage, &info.sender, Role::Revoker)?
+        }
+        TimelockedMsg::UpdateCheckmarkBan { .. } | TimelockedMsg::UpdateAddressBan { .. } => {
+            assert_owner_or_role(deps.storage, &info.sender, Role::BanManager)?
+        }
+        TimelockedMsg::UpdateConfig(_) | TimelockedMsg::UpdateAllowedContracts { .. } => {
+            assert_owner_or_role(deps.storage, &info.sender, Role::ConfigAdmin)?
+        }
+        TimelockedMsg::UpdateAssigner { .. }
+        | TimelockedMsg::GrantRole { .. }
+        | TimelockedMsg::RevokeRole { .. }
+        | TimelockedMsg::UpdateOwnership(_)
+        | TimelockedMsg::RevokeIssuedBy { .. } => {
+            cw_ownable::assert_owner(deps.storage, &info.sender)?
+        }
+    }
+
+    // Queue the action.
+    let id = TIMELOCKED_ACTION_COUNT
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        + 1;
+    TIMELOCKED_ACTION_COUNT.save(deps.storage, &id)?;
+    let execute_after = delay.after(&env.block);
+    TIMELOCKED_ACTIONS.save(
+        deps.storage,
+        id,
+        &TimelockedAction {
+            msg,
+            proposer: info.sender.clone(),
+            execute_after,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("method", "queue_timelocked")
+        .add_attribute("id", id.to_string())
+        .add_attribute("proposer", info.sender)
+        .add_attribute("execute_after", execute_after.to_string()))
+}
+
+fn execute_timelocked_msg(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: TimelockedMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        TimelockedMsg::RevokeCheckmark {
+            checkmark_id,
+            reason,
+        } => execute_revoke_checkmark(deps, env, info, checkmark_id, reason),
+        TimelockedMsg::RevokeAddress { address, reason } => {
+            execute_revoke_address(deps, env, info, address, reason)
+        }
+        TimelockedMsg::UpdateCheckmarkBan {
+            ban_ids,
+            unban_ids,
+            reason,
+            note,
+            expiration,
+        } => execute_update_checkmark_ban(
+            deps,
+            env,
+            info,
+            ban_ids,
+            unban_ids,
+            Ban {
+                reason: reason.unwrap_or(BanReason::Other),
+                note,
+                banned_by: None,
+                expiration,
+            },
+        ),
+        TimelockedMsg::UpdateAddressBan {
+            ban_addresses,
+            unban_addresses,
+            reason,
+            note,
+            expiration,
+        } => execute_update_address_ban(
+            deps,
+            env,
+            info,
+            ban_addresses,
+            unban_addresses,
+            Ban {
+                reason: reason.unwrap_or(BanReason::Other),
+                note,
+                banned_by: None,
+                expiration,
+            },
+        ),
+        TimelockedMsg::UpdateAssigner { assigner } => execute_update_assigner(deps, info, assigner),
+        TimelockedMsg::UpdateConfig(update) => execute_update_config(deps, env, info, update),
+        TimelockedMsg::UpdateAllowedContracts { add, remove } => {
+            execute_update_allowed_contracts(deps, info, add, remove)
+        }
+        TimelockedMsg::GrantRole { role, address } => execute_grant_role(deps, info, role, address),
+        TimelockedMsg::RevokeRole { role, address } => {
+            execute_revoke_role(deps, info, role, address)
+        }
+        TimelockedMsg::UpdateOwnership(action) => execute_update_owner(deps, env, info, action),
+        TimelockedMsg::RevokeIssuedBy {
+            assigner,
+            from_height,
+            to_height,
+            limit,
+        } => execute_revoke_issued_by(deps, env, info, assigner, from_height, to_height, limit),
+    }
+}
+
+fn execute_execute_timelocked(deps: DepsMut, env: Env, id: u64) -> Result<Response, ContractError> {
+    let action = TIMELOCKED_ACTIONS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::NoTimelockedAction)?;
+
+    // Ensure the delay has passed.
+    if !action.execute_after.is_expired(&env.block) {
+        return Err(ContractError::TimelockNotExpired);
+    }
+
+    TIMELOCKED_ACTIONS.remove(deps.storage, id);
+
+    // Perform the action on behalf of the proposer, who must still be
+    // authorized.
+    let info = MessageInfo {
+        sender: action.proposer,
+        funds: vec![],
+    };
+    let response = execute_timelocked_msg(deps, env, info, action.msg)?;
+
+    Ok(response
+        .add_attribute("timelocked", "executed")
+        .add_attribute("id", id.to_string()))
+}
+
+fn execute_cancel_timelocked(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    assert_owner_or_role(deps.storage, &info.sender, Role::Guardian)?;
+
+    if !TIMELOCKED_ACTIONS.has(deps.storage, id) {
+        return Err(ContractError::NoTimelockedAction);
+    }
+
+    TIMELOCKED_ACTIONS.remove(deps.storage, id);
+
+    Ok(Response::default()
+        .add_attribute("method", "cancel_timelocked")
+        .add_attribute("id", id.to_string()))
+}
+
+fn execute_revoke_checkmark(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    checkmark_id: String,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    assert_owner_or_role(deps.storage, &info.sender, Role::Revoker)?;
+    revoke_or_schedule(deps, env, info.sender, checkmark_id, reason)
+}
+
+/// Revokes the checkmark, or schedules its revocation if a challenge period is
+/// configured.
+fn revoke_or_schedule(
+    deps: DepsMut,
+    env: Env,
+    proposer: Addr,
+    checkmark_id: String,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    match config.revocation_challenge_period {
+        Some(period) => schedule_revocation(deps, env, proposer, checkmark_id, reason, period),
+        None => revoke_checkmark(deps, env, checkmark_id),
+    }
+}
+
+fn revoke_checkmark(
+    deps: DepsMut,
+    env: Env,
+    checkmark_id: String,
+) -> Result<Response, ContractError> {
+    // Ensure checkmark exists.
+    let existing_address = CHECKMARKS_TO_ADDRESSES.may_load(deps.storage, checkmark_id.clone())?;
+    if existing_address.is_none() {
+        return Err(ContractError::NoCheckmark);
+    }
+
+    let addr = existing_address.unwrap();
+
+    // Remove the checkmark.
+    lose_checkmark(deps.storage, &env.block, &checkmark_id, &addr)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "revoke_checkmark")
+        .add_attribute("checkmark_id", checkmark_id)
+        .add_attribute("address", addr))
+}
+
+fn execute_revoke_address(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    assert_owner_or_role(deps.storage, &info.sender, Role::Revoker)?;
+
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    match config.revocation_challenge_period {
+        Some(period) => {
+            let addr = deps.api.addr_validate(&address)?;
+            let checkmark_id = ADDRESSES_TO_CHECKMARKS
+                .may_load(deps.storage, addr)?
+                .ok_or(ContractError::NoCheckmark)?;
+            schedule_revocation(deps, env, info.sender, checkmark_id, reason, period)
+        }
+        None => revoke_address(deps, env, address),
+    }
+}
+
+fn revoke_address(deps: DepsMut, env: Env, address: String) -> Result<Response, ContractError> {
+    let addr = deps.api.addr_validate(&address)?;
+
+    // Ensure checkmark exists.
+    let existing_checkmark = ADDRESSES_TO_CHECKMARKS.may_load(deps.storage, addr.clone())?;
+    if existing_checkmark.is_none() {
+        return Err(ContractError::NoCheckmark);
+    }
+
+    let checkmark_id = existing_checkmark.unwrap();
+
+    // Remove the checkmark.
+    lose_checkmark(deps.storage, &env.block, &checkmark_id, &addr)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "revoke_address")
+        .add_attribute("checkmark_id", checkmark_id)
+        .add_attribute("address", addr))
+}
+
+fn schedule_revocation(
+    deps: DepsMut,
+    env: Env,
+    proposer: Addr,
+    checkmark_id: String,
+    reason: Option<String>,
+    period: Duration,
+) -> Result<Response, ContractError> {
+    // Ensure checkmark exists.
+    let addr = CHECKMARKS_TO_ADDRESSES
+        .may_load(deps.storage, checkmark_id.clone())?
+        .ok_or(ContractError::NoCheckmark)?;
+
+    if SCHEDULED_REVOCATIONS.has(deps.storage, checkmark_id.clone()) {
+        return Err(ContractError::RevocationAlreadyScheduled);
+    }
+
+    let finalize_after = period.after(&env.block);
+    SCHEDULED_REVOCATIONS.save(
+        deps.storage,
+        checkmark_id.clone(),
+        &ScheduledRevocation {
+            reason,
+            proposer,
+            finalize_after,
+            appeal: None,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("method", "schedule_revocation")
+        .add_attribute("checkmark_id", checkmark_id)
+        .add_attribute("address", addr)
+        .add_attribute("finalize_after", finalize_after.to_string()))
+}
+
+fn execute_appeal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    evidence_hash: String,
+) -> Result<Response, ContractError> {
+    let checkmark_id = ADDRESSES_TO_CHECKMARKS
+        .may_load(deps.storage, info.sender.clone())?
+        .ok_or(ContractError::NoCheckmark)?;
+
+    let mut revocation = SCHEDULED_REVOCATIONS
+        .may_load(deps.storage, checkmark_id.clone())?
+        .ok_or(ContractError::NoScheduledRevocation)?;
+
+    // Ensure the challenge period has not ended.
+    if revocation.finalize_after.is_expired(&env.block) {
+        return Err(ContractError::ChallengePeriodEnded);
+    }
+    if revocation.appeal.is_some() {
+        return Err(ContractError::AlreadyAppealed);
+    }
+
+    let evidence_hash = validate_evidence_hash(&evidence_hash)?;
+
+    revocation.appeal = Some(Appeal {
+        evidence_hash: evidence_hash.clone(),
+        dismissed: false,
+    });
+    SCHEDULED_REVOCATIONS.save(deps.storage, checkmark_id.clone(), &revocation)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "appeal")
+        .add_attribute("checkmark_id", checkmark_id)
+        .add_attribute("evidence_hash", evidence_hash))
+}
+
+fn execute_resolve_appeal(
+    deps: DepsMut,
+    info: MessageInfo,
+    checkmark_id: String,
+    uphold: bool,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let mut revocation = SCHEDULED_REVOCATIONS
+        .may_load(deps.storage, checkmark_id.clone())?
+        .ok_or(ContractError::NoScheduledRevocation)?;
+
+    // Ensure an appeal is pending.
+    let appeal = match revocation.appeal.as_mut() {
+        Some(appeal) if !appeal.dismissed => appeal,
+        _ => return Err(ContractError::NoAppeal),
+    };
+
+    if uphold {
+        // Cancel the revocation.
+        SCHEDULED_REVOCATIONS.remove(deps.storage, checkmark_id.clone());
+    } else {
+        appeal.dismissed = true;
+        SCHEDULED_REVOCATIONS.save(deps.storage, checkmark_id.clone(), &revocation)?;
+    }
+
+    Ok(Response::default()
+        .add_attribute("method", "resolve_appeal")
+        .add_attribute("checkmark_id", checkmark_id)
+        .add_attribute("upheld", uphold.to_string()))
+}
+
+fn execute_report(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    reason: String,
+    evidence_hash: String,
+) -> Result<Response, ContractError> {
+    let target = deps.api.addr_validate(&address)?;
+    let evidence_hash = validate_evidence_hash(&evidence_hash)?;
+
+    // Ensure the target has a checkmark.
+    if !ADDRESSES_TO_CHECKMARKS.has(deps.storage, target.clone()) {
+        return Err(ContractError::NoCheckmark);
+    }
+
+    // Ensure the bond was sent, if any.
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    match &config.report_bond {
+        Some(bond) => {
+            if info.funds != [bond.clone()] {
+                return Err(ContractError::IncorrectBond {
+                    bond: bond.to_string(),
+                });
+            }
+        }
+        None => cw_utils::nonpayable(&info)?,
+    }
+
+    let id = REPORT_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+    REPORT_COUNT.save(deps.storage, &id)?;
+    REPORTS.save(
+        deps.storage,
+        id,
+        &Report {
+            reporter: info.sender.clone(),
+            target: target.clone(),
+            reason,
+            evidence_hash,
+            bond: config.report_bond,
+            status: ReportStatus::Open,
+        },
+    )?;
+    REPORTS_BY_STATUS.save(deps.storage, (ReportStatus::Open.as_str(), id), &Empty {})?;
+    REPORTS_BY_TARGET.save(deps.storage, (target.clone(), id), &Empty {})?;
+
+    Ok(Response::default()
+        .add_attribute("method", "report")
+        .add_attribute("id", id.to_string())
+        .add_attribute("reporter", info.sender)
+        .add_attribute("target", target))
+}
+
+fn execute_resolve_report(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+    status: ReportStatus,
+) -> Result<Response, ContractError> {
+    assert_owner_or_role(deps.storage, &info.sender, Role::Moderator)?;
+
+    let mut report = REPORTS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::NoReport)?;
+    if !matches!(report.status, ReportStatus::Open) {
+        return Err(ContractError::ReportResolved);
+    }
+
+    let mut response = Response::default()
+        .add_attribute("method", "resolve_report")
+        .add_attribute("id", id.to_string())
+        .add_attribute("status", status.as_str());
+
+    // Refund the bond unless slashed. Slashed bonds fund the report pool.
+    match (&status, &report.bond) {
+        (ReportStatus::Open, _) => return Err(ContractError::InvalidResolution),
+        (ReportStatus::Upheld | ReportStatus::Rejected, Some(bond)) => {
+            response = response.add_message(BankMsg::Send {
+                to_address: report.reporter.to_string(),
+                amount: vec![bond.clone()],
+            });
+        }
+        (ReportStatus::Slashed, Some(bond)) => {
+            REPORT_POOL.update(deps.storage, &bond.denom, |balance| -> StdResult<_> {
+                Ok(balance.unwrap_or_default() + bond.amount)
+            })?;
+        }
+        _ => {}
+    }
+
+    if matches!(status, ReportStatus::Upheld) {
+        // Reward the reporter from the report pool, as far as it allows.
+        let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+        if let Some(reward) = config.report_reward {
+            let balance = REPORT_POOL
+                .may_load(deps.storage, &reward.denom)?
+                .unwrap_or_default();
+            let reward = Coin {
+                amount: reward.amount.min(balance),
+                denom: reward.denom,
+            };
+            if !reward.amount.is_zero() {
+                if reward.amount == balance {
+                    REPORT_POOL.remove(deps.storage, &reward.denom);
+                } else {
+                    REPORT_POOL.save(deps.storage, &reward.denom, &(balance - reward.amount))?;
+                }
+                response = response
+                    .add_attribute("reward", reward.to_string())
+                    .add_message(BankMsg::Send {
+                        to_address: report.reporter.to_string(),
+                        amount: vec![reward],
+                    });
+            }
+        }
+
+        // Revoke the target, unless it no longer has a checkmark or its
+        // revocation is already scheduled.
+        let checkmark_id = ADDRESSES_TO_CHECKMARKS.may_load(deps.storage, report.target.clone())?;
+        if let Some(checkmark_id) = checkmark_id {
+            if !SCHEDULED_REVOCATIONS.has(deps.storage, checkmark_id.clone()) {
+                revoke_or_schedule(
+                    deps.branch(),
+                    env,
+                    info.sender,
+                    checkmark_id.clone(),
+                    Some(format!("report {id}")),
+                )?;
+                response = response.add_attribute("revoked_checkmark_id", checkmark_id);
+            }
+        }
+    }
+
+    REPORTS_BY_STATUS.remove(deps.storage, (report.status.as_str(), id));
+    REPORTS_BY_STATUS.save(deps.storage, (status.as_str(), id), &Empty {})?;
+    report.status = status;
+    REPORTS.save(deps.storage, id, &report)?;
+
+    Ok(response)
+}
+
+fn execute_fund_report_pool(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    if info.funds.is_empty() {
+        return Err(PaymentError::NoFunds {}.into());
+    }
+
+    for fund in &info.funds {
+        REPORT_POOL.update(deps.storage, &fund.denom, |balance| -> StdResult<_> {
+            Ok(balance.unwrap_or_default() + fund.amount)
+        })?;
+    }
+
+    Ok(Response::default()
+        .add_attribute("method", "fund_report_pool")
+        .add_attribute("sender", info.sender)
+        .add_attribute(
+            "funds",
+            info.funds
+                .iter()
+                .map(|fund| fund.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        ))
+}
+
+fn execute_revoke_issued_by(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    assigner: String,
+    from_height: u64,
+    to_height: u64,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let assigner = deps.api.addr_validate(&assigner)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    // Each step takes one extra to tell whether any remain after this chunk.
+    // Checkmarks already scheduled for revocation are skipped.
+    let mut issued = issued_by(deps.storage, &assigner, from_height, to_height, None)?
+        .filter(|item| {
+            item.as_ref().map_or(true, |(checkmark_id, _)| {
+                !SCHEDULED_REVOCATIONS.has(deps.storage, checkmark_id.clone())
+            })
+        })
+        .take(limit + 1)
+        .map(|item| item.map(|(checkmark_id, _)| checkmark_id))
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut remaining = issued.len() > limit;
+    issued.truncate(limit);
+    let mut budget = limit - issued.len();
+
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    for checkmark_id in &issued {
+        match config.revocation_challenge_period {
+            Some(period) => schedule_revocation(
+                deps.branch(),
+                env.clone(),
+                info.sender.clone(),
+                checkmark_id.clone(),
+                Some(format!("issued by {assigner}")),
+                period,
+            )?,
+            None => revoke_checkmark(deps.branch(), env.clone(), checkmark_id.clone())?,
+        };
+    }
+
+    // Withdraw offers the assigner made, which have not been accepted yet.
+    let mut offers = OFFERS_BY_ASSIGNER
+        .sub_prefix(assigner.clone())
+        .keys(
+            deps.storage,
+            Some(Bound::inclusive((from_height, Addr::unchecked("")))),
+            None,
+            Order::Ascending,
+        )
+        .take_while(|item| {
+            item.as_ref()
+                .map_or(true, |(height, _)| *height <= to_height)
+        })
+        .take(budget + 1)
+        .map(|item| {
+            let (_, addr) = item?;
+            let offer = OFFERS.load(deps.storage, addr.clone())?;
+            Ok((addr, offer))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    remaining |= offers.len() > budget;
+    offers.truncate(budget);
+    budget -= offers.len();
+
+    let mut refunds = vec![];
+    for (addr, offer) in offers.clone() {
+        refunds.extend(withdraw_offer(deps.storage, &addr, offer)?);
+    }
+
+    // Drop assignments the assigner queued for the end of the epoch.
+    let mut queued = MIX_QUEUE_BY_ASSIGNER
+        .sub_prefix(assigner.clone())
+        .keys(
+            deps.storage,
+            Some(Bound::inclusive((from_height, 0))),
+            None,
+            Order::Ascending,
+        )
+        .take_while(|item| {
+            item.as_ref()
+                .map_or(true, |(height, _)| *height <= to_height)
+        })
+        .take(budget + 1)
+        .map(|item| {
+            let (_, position) = item?;
+            let op = MIX_QUEUE.load(deps.storage, position)?;
+            Ok((position, op))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    remaining |= queued.len() > budget;
+    queued.truncate(budget);
+    budget -= queued.len();
+
+    for (position, op) in queued.clone() {
+        unqueue_mix_op(deps.storage, position, &op)?;
+        if let MixOp::Assign {
+            address,
+            issuance,
+            payment,
+            ..
+        } = op
+        {
+            refunds.extend(release_fee(
+                deps.storage,
+                &address,
+                payment,
+                &issuance.assigner,
+            )?);
+        }
+    }
+
+    // Withdraw the assigner's approvals of pending assignments, whatever
+    // their height, since approvals do not record one.
+    let mut approved = PENDING_ASSIGNMENTS_BY_ASSIGNER
+        .prefix(assigner.clone())
+        .keys(deps.storage, None, None, Order::Ascending)
+        .take(budget + 1)
+        .map(|item| {
+            let checkmark_id = item?;
+            let pending = PENDING_ASSIGNMENTS.load(deps.storage, checkmark_id.clone())?;
+            Ok((checkmark_id, pending))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    remaining |= approved.len() > budget;
+    approved.truncate(budget);
+
+    for (checkmark_id, mut pending) in approved.clone() {
+        pending.approvals.retain(|approval| *approval != assigner);
+        if pending.approvals.is_empty() {
+            remove_pending_assignment(deps.storage, &checkmark_id)?;
+        } else {
+            save_pending_assignment(deps.storage, &checkmark_id, &pending)?;
+        }
+    }
+
+    let (revoked, scheduled) = match config.revocation_challenge_period {
+        Some(_) => (0, issued.len()),
+        None => (issued.len(), 0),
+    };
+
+    Ok(Response::default()
+        .add_messages(refunds)
+        .add_attribute("method", "revoke_issued_by")
+        .add_attribute("assigner", assigner)
+        .add_attribute("revoked", revoked.to_string())
+        .add_attribute("scheduled", scheduled.to_string())
+        .add_attribute("offers_withdrawn", offers.len().to_string())
+        .add_attribute("queued_withdrawn", queued.len().to_string())
+        .add_attribute("approvals_withdrawn", approved.len().to_string())
+        .add_attribute("remaining", remaining.to_string()))
+}
+
+fn execute_finalize_revocation(
+    deps: DepsMut,
+    env: Env,
+    checkmark_id: String,
+) -> Result<Response, ContractError> {
+    let revocation = SCHEDULED_REVOCATIONS
+        .may_load(deps.storage, checkmark_id.clone())?
+        .ok_or(ContractError::NoScheduledRevocation)?;
+
+    // Ensure the challenge period has ended and no appeal is pending.
+    if !revocation.finalize_after.is_expired(&env.block) {
+        return Err(ContractError::ChallengePeriodNotEnded);
+    }
+    if revocation.appeal.is_some_and(|appeal| !appeal.dismissed) {
+        return Err(ContractError::AppealPending);
+    }
+
+    // Removing the checkmark also removes the scheduled revocation.
+    let response = revoke_checkmark(deps, env, checkmark_id)?;
+
+    Ok(response.add_attribute("finalized", "true"))
+}
+
+fn execute_suspend(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: Option<String>,
+    checkmark_id: Option<String>,
+    reason: String,
+    until: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    assert_owner_or_role(deps.storage, &info.sender, Role::Revoker)?;
+
+    let checkmark_id = target_checkmark(deps.as_ref(), address, checkmark_id)?;
+
+    SUSPENSIONS.save(
+        deps.storage,
+        checkmark_id.clone(),
+        &Suspension {
+            reason: reason.clone(),
+            suspended_by: info.sender,
+            until,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("method", "suspend")
+        .add_attribute("checkmark_id", checkmark_id)
+        .add_attribute("reason", reason))
+}
+
+fn execute_unsuspend(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: Option<String>,
+    checkmark_id: Option<String>,
+) -> Result<Response, ContractError> {
+    assert_owner_or_role(deps.storage, &info.sender, Role::Revoker)?;
+
+    let checkmark_id = target_checkmark(deps.as_ref(), address, checkmark_id)?;
+    if !SUSPENSIONS.has(deps.storage, checkmark_id.clone()) {
+        return Err(ContractError::NotSuspended);
+    }
+
+    SUSPENSIONS.remove(deps.storage, checkmark_id.clone());
+
+    Ok(Response::default()
+        .add_attribute("method", "unsuspend")
+        .add_attribute("checkmark_id", checkmark_id))
+}
+
+fn execute_update_checkmark_ban(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    ban_ids: Option<Vec<String>>,
+    unban_ids: Option<Vec<String>>,
+    ban: Ban,
+) -> Result<Response, ContractError> {
+    assert_owner_or_role(deps.storage, &info.sender, Role::BanManager)?;
+    update_checkmark_ban(
+        deps,
+        env,
+        ban_ids,
+        unban_ids,
+        Ban {
+            banned_by: Some(info.sender),
+            ..ban
+        },
+    )
+}
+
+fn update_checkmark_ban(
+    deps: DepsMut,
+    env: Env,
+    ban_ids: Option<Vec<String>>,
+    unban_ids: Option<Vec<String>>,
+    ban: Ban,
+) -> Result<Response, ContractError> {
+    let ban_ids = ban_ids.unwrap_or_default();
+    let unban_ids = unban_ids.unwrap_or_default();
+
+    for ban_id in &ban_ids {
+        // If banning, remove checkmark if exists.
+        let existing_address = CHECKMARKS_TO_ADDRESSES.may_load(deps.storage, ban_id.clone())?;
+        if let Some(addr) = existing_address {
+            // Remove the checkmark.
+            lose_checkmark(deps.storage, &env.block, ban_id, &addr)?;
+        }
+
+        // Add to banned list.
+        BANNED_CHECKMARKS.save(deps.storage, ban_id.clone(), &ban)?;
+    }
+
+    for unban_id in &unban_ids {
+        // Remove from banned list.
+        BANNED_CHECKMARKS.remove(deps.storage, unban_id.clone());
+    }
+
+    let mut response = Response::default().add_attribute("method", "update_checkmark_ban");
+    if !ban_ids.is_empty() {
+        response = response.add_attribute("ban_ids", ban_ids.join(","));
+    }
+    if !unban_ids.is_empty() {
+        response = response.add_attribute("unban_ids", unban_ids.join(","));
+    }
+
+    Ok(response)
+}
+
+fn execute_update_address_ban(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    ban_addresses: Option<Vec<String>>,
+    unban_addresses: Option<Vec<String>>,
+    ban: Ban,
+) -> Result<Response, ContractError> {
+    assert_owner_or_role(deps.storage, &info.sender, Role::BanManager)?;
+    update_address_ban(
+        deps,
+        env,
+        ban_addresses,
+        unban_addresses,
+        Ban {
+            banned_by: Some(info.sender),
+            ..ban
+        },
+    )
+}
+
+fn update_address_ban(
+    deps: DepsMut,
+    env: Env,
+    ban_addresses: Option<Vec<String>>,
+    unban_addresses: Option<Vec<String>>,
+    ban: Ban,
+) -> Result<Response, ContractError> {
+    let ban_addresses = ban_addresses.unwrap_or_default();
+    let unban_addresses = unban_addresses.unwrap_or_default();
+
+    for address in &ban_addresses {
+        let addr = deps.api.addr_validate(address)?;
+
+        // If banning, remove checkmark if exists.
+        let existing_checkmark = ADDRESSES_TO_CHECKMARKS.may_load(deps.storage, addr.clone())?;
+        if let Some(checkmark_id) = existing_checkmark {
+            // Remove the checkmark.
+            lose_checkmark(deps.storage, &env.block, &checkmark_id, &addr)?;
+        }
+
+        // Unlink the address if it is a secondary address, so it no longer
+        // resolves to the primary address's checkmark.
+        if let Some(primary) = SECONDARY_ADDRESSES.may_load(deps.storage, addr.clone())? {
+            SECONDARY_ADDRESSES.remove(deps.storage, addr.clone());
+            PRIMARY_SECONDARY_ADDRESSES.remove(deps.storage, (primary, addr.clone()));
+        }
+
+        // Add to banned list.
+        BANNED_ADDRESSES.save(deps.storage, addr, &ban)?;
+    }
+
+    for address in &unban_addresses {
+        let addr = deps.api.addr_validate(address)?;
+
+        // Remove from banned list.
+        BANNED_ADDRESSES.remove(deps.storage, addr);
+    }
+
+    let mut response = Response::default().add_attribute("method", "update_address_ban");
+    if !ban_addresses.is_empty() {
+        response = response.add_attribute("ban_addresses", ban_addresses.join(","));
+    }
+    if !unban_addresses.is_empty() {
+        response = response.add_attribute("unban_addresses", unban_addresses.join(","));
+    }
+
+    Ok(response)
+}
+
+fn execute_update_assigner(
+    deps: DepsMut,
+    info: MessageInfo,
+    assigner: String,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+    update_assigner(deps, assigner)
+}
+
+fn update_assigner(deps: DepsMut, assigner: String) -> Result<Response, ContractError> {
+    let assigner = deps.api.addr_validate(&assigner)?;
+
+    // Update the assigner.
+    ASSIGNER.save(deps.storage, &assigner)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "update_assigner")
+        .add_attribute("assigner", assigner))
+}
+
+fn execute_link_evm_address(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    eth_address: String,
+    signature: Binary,
+) -> Result<Response, ContractError> {
+    // Ensure address has a checkmark.
+    let existing_checkmark = ADDRESSES_TO_CHECKMARKS.may_load(deps.storage, info.sender.clone())?;
+    if existing_checkmark.is_none() {
+        return Err(ContractError::NoCheckmark);
+    }
+
+    let eth_address = evm::validate_address(&eth_address)?;
+
+    // Ensure the signature was made by the EVM address.
+    let message = evm::link_message(&env.contract.address, &info.sender);
+    let recovered_address = evm::recover_address(deps.api, &message, &signature)?;
+    if recovered_address != eth_address {
+        return Err(ContractError::InvalidSignature);
+    }
+
+    // Ensure EVM address is not linked to a different address.
+    let existing_address =
+        EVM_ADDRESSES_TO_ADDRESSES.may_load(deps.storage, eth_address.clone())?;
+    if existing_address.is_some_and(|addr| addr != info.sender) {
+        return Err(ContractError::EvmAddressAlreadyLinked);
+    }
+
+    // Replace the previously linked EVM address, if any.
+    if let Some(previous_eth_address) =
+        ADDRESSES_TO_EVM_ADDRESSES.may_load(deps.storage, info.sender.clone())?
+    {
+        EVM_ADDRESSES_TO_ADDRESSES.remove(deps.storage, previous_eth_address);
+    }
+
+    // Link the EVM address.
+    EVM_ADDRESSES_TO_ADDRESSES.save(deps.storage, eth_address.clone(), &info.sender)?;
+    ADDRESSES_TO_EVM_ADDRESSES.save(deps.storage, info.sender.clone(), &eth_address)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "link_evm_address")
+        .add_attribute("address", info.sender)
+        .add_attribute("eth_address", eth_address))
+}
+
+fn execute_register_pubkey(
+    deps: DepsMut,
+    info: MessageInfo,
+    pubkey: Binary,
+) -> Result<Response, ContractError> {
+    // Ensure address has a checkmark.
+    let existing_checkmark = ADDRESSES_TO_CHECKMARKS.may_load(deps.storage, info.sender.clone())?;
+    if existing_checkmark.is_none() {
+        return Err(ContractError::NoCheckmark);
+    }
+
+    // Ensure the public key derives the sender's address.
+    let canonical_addresses = pubkey::derive_canonical_addresses(&pubkey)?;
+    let sender_canonical = deps.api.addr_canonicalize(info.sender.as_str())?;
+    if !canonical_addresses
+        .iter()
+        .any(|canonical| canonical.as_slice() == sender_canonical.as_slice())
+    {
+        return Err(ContractError::PubkeyMismatch);
+    }
+
+    // Ensure no derived address resolves to a different address.
+    for canonical in &canonical_addresses {
+        let existing_address =
+            CANONICAL_ADDRESSES_TO_ADDRESSES.may_load(deps.storage, canonical)?;
+        if existing_address.is_some_and(|addr| addr != info.sender) {
+            return Err(ContractError::PubkeyAlreadyRegistered);
+        }
+    }
+
+    // Replace the previously registered public key, if any.
+    remove_pubkey(deps.storage, &info.sender)?;
+
+    // Register the public key.
+    for canonical in &canonical_addresses {
+        CANONICAL_ADDRESSES_TO_ADDRESSES.save(deps.storage, canonical, &info.sender)?;
+    }
+    ADDRESSES_TO_PUBKEYS.save(deps.storage, info.sender.clone(), &pubkey)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "register_pubkey")
+        .add_attribute("address", info.sender)
+        .add_attribute("pubkey", pubkey.to_base64()))
+}
+
+fn execute_add_secondary_address(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let addr = deps.api.addr_validate(&address)?;
+
+    // Ensure sender has a checkmark.
+    let existing_checkmark = ADDRESSES_TO_CHECKMARKS.may_load(deps.storage, info.sender.clone())?;
+    if existing_checkmark.is_none() {
+        return Err(ContractError::NoCheckmark);
+    }
+
+    // Ensure address does not already have a checkmark or vote for another
+    // holder.
+    if checkmark_for_address(deps.storage, &addr)?.is_some() {
+        return Err(ContractError::AlreadyHasCheckmark);
+    }
+    if DELEGATES.has(deps.storage, addr.clone()) {
+        return Err(ContractError::AddressIsDelegate);
+    }
+
+    // Propose the secondary address, which it must accept.
+    PENDING_SECONDARY_ADDRESSES.save(deps.storage, (info.sender.clone(), addr), &Empty {})?;
+
+    Ok(Response::default()
+        .add_attribute("method", "add_secondary_address")
+        .add_attribute("primary", info.sender)
+        .add_attribute("address", address))
+}
+
+fn execute_accept_secondary_address(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    primary: String,
+) -> Result<Response, ContractError> {
+    let primary = deps.api.addr_validate(&primary)?;
+
+    // Ensure the primary proposed the sender.
+    let key = (primary.clone(), info.sender.clone());
+    if !PENDING_SECONDARY_ADDRESSES.has(deps.storage, key.clone()) {
+        return Err(ContractError::NoPendingSecondaryAddress);
+    }
+    PENDING_SECONDARY_ADDRESSES.remove(deps.storage, key.clone());
+
+    // Ensure sender does not already have a checkmark or vote for another
+    // holder.
+    if checkmark_for_address(deps.storage, &info.sender)?.is_some() {
+        return Err(ContractError::AlreadyHasCheckmark);
+    }
+    if DELEGATES.has(deps.storage, info.sender.clone()) {
+        return Err(ContractError::AddressIsDelegate);
+    }
+
+    // Ensure sender is not banned.
+    if active_address_ban(deps.storage, &env.block, &info.sender)?.is_some() {
+        return Err(ContractError::AddressBanned);
+    }
+
+    // Ensure the primary still has a checkmark.
+    let checkmark_id = ADDRESSES_TO_CHECKMARKS
+        .may_load(deps.storage, primary.clone())?
+        .ok_or(ContractError::NoCheckmark)?;
+
+    // Ensure the primary has not reached the secondary address limit.
+    let max = CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .max_secondary_addresses;
+    let count = PRIMARY_SECONDARY_ADDRESSES
+        .prefix(primary.clone())
+        .keys(deps.storage, None, None, Order::Ascending)
+        .count();
+    if count as u32 >= max {
+        return Err(ContractError::TooManySecondaryAddresses { max });
+    }
+
+    // Link the secondary address.
+    SECONDARY_ADDRESSES.save(deps.storage, info.sender.clone(), &primary)?;
+    PRIMARY_SECONDARY_ADDRESSES.save(deps.storage, key, &Empty {})?;
+
+    Ok(Response::default()
+        .add_attribute("method", "accept_secondary_address")
+        .add_attribute("checkmark_id", checkmark_id)
+        .add_attribute("primary", primary)
+        .add_attribute("address", info.sender))
+}
+
+fn execute_remove_secondary_address(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let addr = deps.api.addr_validate(&address)?;
+
+    // Ensure the address is a secondary address of the sender, or is the
+    // sender.
+    let primary = SECONDARY_ADDRESSES
+        .may_load(deps.storage, addr.clone())?
+        .ok_or(ContractError::NotSecondaryAddress)?;
+    if info.sender != primary && info.sender != addr {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Unlink the secondary address.
+    SECONDARY_ADDRESSES.remove(deps.storage, addr.clone());
+    PRIMARY_SECONDARY_ADDRESSES.remove(deps.storage, (primary.clone(), addr));
+
+    Ok(Response::default()
+        .add_attribute("method", "remove_secondary_address")
+        .add_attribute("primary", primary)
+        .add_attribute("address", address))
+}
+
+fn execute_delegate(
+    deps: DepsMut,
+    info: MessageInfo,
+    to: String,
+) -> Result<Response, ContractError> {
+    let delegate = deps.api.addr_validate(&to)?;
+
+    // Ensure sender has a checkmark.
+    let existing_checkmark = ADDRESSES_TO_CHECKMARKS.may_load(deps.storage, info.sender.clone())?;
+    if existing_checkmark.is_none() {
+        return Err(ContractError::NoCheckmark);
+    }
+
+    // Ensure the delegate does not vote for anyone else, which also prevents
+    // delegation chains.
+    if delegate == info.sender
+        || checkmark_for_address(deps.storage, &delegate)?.is_some()
+        || DELEGATES.has(deps.storage, delegate.clone())
+    {
+        return Err(ContractError::InvalidDelegate);
+    }
+
+    // Replace the previous delegation, if any.
+    if let Some(previous_delegate) = DELEGATIONS.may_load(deps.storage, info.sender.clone())? {
+        DELEGATES.remove(deps.storage, previous_delegate);
+    }
+
+    // Delegate.
+    DELEGATIONS.save(deps.storage, info.sender.clone(), &delegate)?;
+    DELEGATES.save(deps.storage, delegate.clone(), &info.sender)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "delegate")
+        .add_attribute("address", info.sender)
+        .add_attribute("delegate", delegate))
+}
+
+fn execute_undelegate(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    // Ensure sender has delegated.
+    let delegate = DELEGATIONS
+        .may_load(deps.storage, info.sender.clone())?
+        .ok_or(ContractError::NoDelegation)?;
+
+    // Undelegate.
+    DELEGATIONS.remove(deps.storage, info.sender.clone());
+    DELEGATES.remove(deps.storage, delegate.clone());
+
+    Ok(Response::default()
+        .add_attribute("method", "undelegate")
+        .add_attribute("address", info.sender)
+        .add_attribute("delegate", delegate))
+}
+
+fn execute_set_guardians(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    guardians: Vec<String>,
+    threshold: u32,
+) -> Result<Response, ContractError> {
+    apply_guardian_change(deps.storage, &env.block, &info.sender)?;
+
+    // Ensure sender has a checkmark.
+    let existing_checkmark = ADDRESSES_TO_CHECKMARKS.may_load(deps.storage, info.sender.clone())?;
+    if existing_checkmark.is_none() {
+        return Err(ContractError::NoCheckmark);
+    }
+
+    let mut guardians = guardians
+        .iter()
+        .map(|guardian| deps.api.addr_validate(guardian))
+        .collect::<StdResult<Vec<_>>>()?;
+    let count = guardians.len();
+    guardians.sort();
+    guardians.dedup();
+
+    // Ensure guardians are unique, do not include the sender, and can reach
+    // the threshold. An empty list removes the guardians.
+    let threshold = if guardians.is_empty() { 0 } else { threshold };
+    if !guardians.is_empty()
+        && (guardians.len() != count
+            || guardians.contains(&info.sender)
+            || threshold == 0
+            || threshold as usize > guardians.len())
+    {
+        return Err(ContractError::InvalidGuardians);
+    }
+
+    let response = Response::default()
+        .add_attribute("method", "set_guardians")
+        .add_attribute("address", info.sender.clone())
+        .add_attribute("threshold", threshold.to_string());
+
+    // Replace any pending change.
+    PENDING_GUARDIANS.remove(deps.storage, info.sender.clone());
+
+    // Changing existing guardians is delayed, giving them time to recover the
+    // checkmark if the holder's key was compromised. Setting the current
+    // guardians again cancels a pending change.
+    if let Some(current) = GUARDIANS.may_load(deps.storage, info.sender.clone())? {
+        if current.guardians == guardians && current.threshold == threshold {
+            return Ok(response);
+        }
+
+        let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+        let effective_after = config.recovery_delay.after(&env.block);
+        PENDING_GUARDIANS.save(
+            deps.storage,
+            info.sender,
+            &GuardianChange {
+                guardians,
+                threshold,
+                effective_after,
+            },
+        )?;
+
+        return Ok(response.add_attribute("effective_after", effective_after.to_string()));
+    }
+
+    if !guardians.is_empty() {
+        GUARDIANS.save(
+            deps.storage,
+            info.sender.clone(),
+            &Guardians {
+                guardians,
+                threshold,
+            },
+        )?;
+    }
+
+    Ok(response)
+}
+
+/// Applies the holder's pending guardian change once its delay has passed.
+/// Recoveries not yet approved by the previous guardians are cancelled.
+fn apply_guardian_change(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    holder: &Addr,
+) -> StdResult<()> {
+    let change = match PENDING_GUARDIANS.may_load(storage, holder.clone())? {
+        Some(change) if change.effective_after.is_expired(block) => change,
+        _ => return Ok(()),
+    };
+    PENDING_GUARDIANS.remove(storage, holder.clone());
+
+    if change.guardians.is_empty() {
+        GUARDIANS.remove(storage, holder.clone());
+    } else {
+        GUARDIANS.save(
+            storage,
+            holder.clone(),
+            &Guardians {
+                guardians: change.guardians,
+                threshold: change.threshold,
+            },
+        )?;
+    }
+
+    if let Some(recovery) = RECOVERIES.may_load(storage, holder.clone())? {
+        if recovery.new_address.is_none() {
+            RECOVERIES.remove(storage, holder.clone());
+        }
+    }
+
+    Ok(())
+}
+
+fn execute_approve_recovery(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    holder: String,
+    new_address: String,
+) -> Result<Response, ContractError> {
+    let holder = deps.api.addr_validate(&holder)?;
+    let new_address = deps.api.addr_validate(&new_address)?;
+    apply_guardian_change(deps.storage, &env.block, &holder)?;
+
+    // Ensure sender is a guardian of the holder.
+    let guardians = GUARDIANS
+        .may_load(deps.storage, holder.clone())?
+        .ok_or(ContractError::Unauthorized)?;
+    if !guardians.guardians.contains(&info.sender) {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Ensure new address does not already have a checkmark.
+    if checkmark_for_address(deps.storage, &new_address)?.is_some() {
+        return Err(ContractError::AlreadyHasCheckmark);
+    }
+
+    // Ensure new address is not banned.
+    if active_address_ban(deps.storage, &env.block, &new_address)?.is_some() {
+        return Err(ContractError::AddressBanned);
+    }
+
+    let mut recovery = RECOVERIES
+        .may_load(deps.storage, holder.clone())?
+        .unwrap_or(Recovery {
+            new_address: None,
+            proposals: vec![],
+            executable_after: None,
+        });
+
+    // Ensure approval matches the approved recovery, if any.
+    if recovery
+        .new_address
+        .as_ref()
+        .is_some_and(|approved| *approved != new_address)
+    {
+        return Err(ContractError::RecoveryMismatch);
+    }
+
+    // Move the guardian's approval from any other proposed address, so one
+    // guardian cannot block the others from agreeing.
+    for proposal in recovery.proposals.iter_mut() {
+        if proposal.new_address != new_address {
+            proposal
+                .approvals
+                .retain(|guardian| *guardian != info.sender);
+        }
+    }
+    recovery
+        .proposals
+        .retain(|proposal| !proposal.approvals.is_empty());
+
+    let index = match recovery
+        .proposals
+        .iter()
+        .position(|proposal| proposal.new_address == new_address)
+    {
+        Some(index) => index,
+        None => {
+            recovery.proposals.push(RecoveryProposal {
+                new_address: new_address.clone(),
+                approvals: vec![],
+            });
+            recovery.proposals.len() - 1
+        }
+    };
+    let proposal = &mut recovery.proposals[index];
+    if proposal.approvals.contains(&info.sender) {
+        return Err(ContractError::AlreadyApproved);
+    }
+
+    proposal.approvals.push(info.sender.clone());
+    let approvals = proposal.approvals.len();
+
+    // Approve the first address to reach the threshold, and start the delay.
+    if recovery.new_address.is_none() && approvals as u32 >= guardians.threshold {
+        let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+        recovery.new_address = Some(new_address.clone());
+        recovery.executable_after = Some(config.recovery_delay.after(&env.block));
+    }
+
+    RECOVERIES.save(deps.storage, holder.clone(), &recovery)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "approve_recovery")
+        .add_attribute("holder", holder)
+        .add_attribute("new_address", new_address)
+        .add_attribute("guardian", info.sender)
+        .add_attribute("approvals", approvals.to_string()))
+}
+
+fn execute_cancel_recovery(
+    deps: DepsMut,
+    info: MessageInfo,
+    holder: Option<String>,
+) -> Result<Response, ContractError> {
+    // Only the owner or a guardian can cancel another holder's recovery.
+    let holder = match holder {
+        Some(holder) => {
+            let holder = deps.api.addr_validate(&holder)?;
+            if holder != info.sender {
+                assert_owner_or_role(deps.storage, &info.sender, Role::Guardian)?;
+            }
+            holder
+        }
+        None => info.sender,
+    };
+
+    // Ensure a recovery is pending for the holder.
+    if !RECOVERIES.has(deps.storage, holder.clone()) {
+        return Err(ContractError::NoPendingRecovery);
+    }
+
+    RECOVERIES.remove(deps.storage, holder.clone());
+
+    Ok(Response::default()
+        .add_attribute("method", "cancel_recovery")
+        .add_attribute("holder", holder))
+}
+
+fn execute_execute_recovery(
+    deps: DepsMut,
+    env: Env,
+    holder: String,
+) -> Result<Response, ContractError> {
+    let holder = deps.api.addr_validate(&holder)?;
+    apply_guardian_change(deps.storage, &env.block, &holder)?;
+
+    // Ensure the recovery was approved and the delay has passed.
+    let recovery = RECOVERIES
+        .may_load(deps.storage, holder.clone())?
+        .ok_or(ContractError::NoPendingRecovery)?;
+    let new_address = match (recovery.new_address, recovery.executable_after) {
+        (Some(new_address), Some(executable_after)) if executable_after.is_expired(&env.block) => {
+            new_address
+        }
+        _ => return Err(ContractError::RecoveryNotReady),
+    };
+
+    let checkmark_id = ADDRESSES_TO_CHECKMARKS
+        .may_load(deps.storage, holder.clone())?
+        .ok_or(ContractError::NoCheckmark)?;
+
+    // Moving the checkmark would cancel a revocation scheduled against the
+    // holder, so it must be resolved first.
+    if SCHEDULED_REVOCATIONS.has(deps.storage, checkmark_id.clone()) {
+        return Err(ContractError::RevocationAlreadyScheduled);
+    }
+
+    // Ensure new address can be given a checkmark, since it may have changed
+    // since the guardians approved it.
+    ensure_address_assignable(deps.storage, &env.block, &new_address)?;
+    ensure_not_contract(deps.as_ref(), &new_address)?;
+
+    // Keep the guardians for the new address.
+    let mut guardians = GUARDIANS.load(deps.storage, holder.clone())?;
+    guardians
+        .guardians
+        .retain(|guardian| *guardian != new_address);
+    guardians.threshold = guardians.threshold.min(guardians.guardians.len() as u32);
+
+    move_checkmark(deps.storage, &checkmark_id, &holder, &new_address)?;
+    if !guardians.guardians.is_empty() {
+        GUARDIANS.save(deps.storage, new_address.clone(), &guardians)?;
+    }
+
+    Ok(Response::default()
+        .add_attribute("method", "execute_recovery")
+        .add_attribute("checkmark_id", checkmark_id)
+        .add_attribute("holder", holder)
+        .add_attribute("new_address", new_address))
+}
+
+fn execute_update_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    update: UpdateConfigMsg,
+) -> Result<Response, ContractError> {
+    assert_owner_or_role(deps.storage, &info.sender, Role::ConfigAdmin)?;
+
+    let mut config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    if let Some(max_secondary_addresses) = update.max_secondary_addresses {
+        config.max_secondary_addresses = max_secondary_addresses;
+    }
+    if let Some(recovery_delay) = update.recovery_delay {
+        config.recovery_delay = recovery_delay;
+    }
+    if let Some(assign_threshold) = update.assign_threshold {
+        if assign_threshold == 0 {
+            return Err(ContractError::InvalidThreshold);
+        }
+        config.assign_threshold = assign_threshold;
+    }
+    if let Some(assign_approval_timeout) = update.assign_approval_timeout {
+        config.assign_approval_timeout = assign_approval_timeout;
+    }
+    if let Some(timelock_delay) = update.timelock_delay {
+        config.timelock_delay = match timelock_delay {
+            Duration::Height(0) | Duration::Time(0) => None,
+            delay => Some(delay),
+        };
+    }
+    if let Some(period) = update.revocation_challenge_period {
+        config.revocation_challenge_period = match period {
+            Duration::Height(0) | Duration::Time(0) => None,
+            period => Some(period),
+        };
+    }
+    if let Some(report_bond) = update.report_bond {
+        config.report_bond = Some(report_bond).filter(|bond| !bond.amount.is_zero());
+    }
+    if let Some(require_consent) = update.require_consent {
+        config.require_consent = require_consent;
+    }
+    if let Some(offer_timeout) = update.offer_timeout {
+        config.offer_timeout = offer_timeout;
+    }
+    if let Some(fees) = update.fees {
+        config.fees = fees
+            .into_iter()
+            .filter(|fee| !fee.amount.is_zero())
+            .map(|fee| {
+                Ok(Fee {
+                    denom: match fee.denom {
+                        Denom::Cw20(token) => Denom::Cw20(deps.api.addr_validate(token.as_str())?),
+                        denom => denom,
+                    },
+                    amount: fee.amount,
+                })
+            })
+            .collect::<StdResult<_>>()?;
+    }
+    if let Some(fee_split) = update.fee_split {
+        let total = fee_split
+            .provider_share
+            .checked_add(fee_split.operator_share)
+            .map_err(|_| ContractError::InvalidFeeSplit)?;
+        if total > Decimal::one() {
+            return Err(ContractError::InvalidFeeSplit);
+        }
+        config.fee_split = Some(FeeSplit {
+            provider: deps.api.addr_validate(fee_split.provider.as_str())?,
+            operator: deps.api.addr_validate(fee_split.operator.as_str())?,
+            community_pool: deps.api.addr_validate(fee_split.community_pool.as_str())?,
+            ..fee_split
+        });
+    }
+    if let Some(reject_contracts) = update.reject_contracts {
+        config.reject_contracts = reject_contracts;
+    }
+    if let Some(prevent_id_reuse) = update.prevent_id_reuse {
+        config.prevent_id_reuse = prevent_id_reuse;
+    }
+    if let Some(mixing_epoch) = update.mixing_epoch {
+        config.mixing_epoch = match mixing_epoch {
+            Duration::Height(0) | Duration::Time(0) => None,
+            epoch => Some(epoch),
+        };
+
+        // Start the first epoch if none is running.
+        if let Some(epoch) = config.mixing_epoch {
+            if NEXT_EPOCH.may_load(deps.storage)?.is_none() {
+                NEXT_EPOCH.save(deps.storage, &epoch.after(&env.block))?;
+            }
+        }
+    }
+    if let Some(reassign_cooldown) = update.reassign_cooldown {
+        config.reassign_cooldown = match reassign_cooldown {
+            Duration::Height(0) | Duration::Time(0) => None,
+            cooldown => Some(cooldown),
+        };
+    }
+    if let Some(deletion_delay) = update.deletion_delay {
+        config.deletion_delay = match deletion_delay {
+            Duration::Height(0) | Duration::Time(0) => None,
+            delay => Some(delay),
+        };
+    }
+    if let Some(report_reward) = update.report_reward {
+        config.report_reward = Some(report_reward).filter(|reward| !reward.amount.is_zero());
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::default().add_attribute("method", "update_config"))
+}
+
+fn execute_update_allowed_contracts(
+    deps: DepsMut,
+    info: MessageInfo,
+    add: Option<Vec<String>>,
+    remove: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    assert_owner_or_role(deps.storage, &info.sender, Role::ConfigAdmin)?;
+
+    let add = add.unwrap_or_default();
+    let remove = remove.unwrap_or_default();
+
+    for address in &add {
+        let addr = deps.api.addr_validate(address)?;
+        ALLOWED_CONTRACTS.save(deps.storage, addr, &Empty {})?;
+    }
+    for address in &remove {
+        let addr = deps.api.addr_validate(address)?;
+        ALLOWED_CONTRACTS.remove(deps.storage, addr);
+    }
+
+    let mut response = Response::default().add_attribute("method", "update_allowed_contracts");
+    if !add.is_empty() {
+        response = response.add_attribute("add", add.join(","));
+    }
+    if !remove.is_empty() {
+        response = response.add_attribute("remove", remove.join(","));
+    }
+
+    Ok(response)
+}
+
+fn execute_grant_role(
+    deps: DepsMut,
+    info: MessageInfo,
+    role: Role,
+    address: String,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let addr = deps.api.addr_validate(&address)?;
+    ROLES.save(deps.storage, (role.as_str(), addr.clone()), &Empty {})?;
+
+    Ok(Response::default()
+        .add_attribute("method", "grant_role")
+        .add_attribute("role", role.as_str())
+        .add_attribute("address", addr))
+}
+
+fn execute_revoke_role(
+    deps: DepsMut,
+    info: MessageInfo,
+    role: Role,
+    address: String,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let addr = deps.api.addr_validate(&address)?;
+    ROLES.remove(deps.storage, (role.as_str(), addr.clone()));
+
+    Ok(Response::default()
+        .add_attribute("method", "revoke_role")
+        .add_attribute("role", role.as_str())
+        .add_attribute("address", addr))
+}
+
+pub fn execute_update_owner(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: cw_ownable::Action,
+) -> Result<Response, ContractError> {
+    let ownership = cw_ownable::update_ownership(deps, &env.block, &info.sender, action)?;
+    Ok(Response::default().add_attributes(ownership.into_attributes()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::UpdateOwner { owner } => sudo_update_owner(deps, owner),
+        SudoMsg::UpdateAssigner { assigner } => update_assigner(deps, assigner),
+        SudoMsg::SetPaused { paused } => sudo_set_paused(deps, paused),
+        SudoMsg::RevokeCheckmark { checkmark_id } => revoke_checkmark(deps, env, checkmark_id),
+        SudoMsg::RevokeAddress { address } => revoke_address(deps, env, address),
+        SudoMsg::UpdateAddressBan {
+            ban_addresses,
+            unban_addresses,
+            reason,
+            note,
+            expiration,
+        } => update_address_ban(
+            deps,
+            env,
+            ban_addresses,
+            unban_addresses,
+            Ban {
+                reason: reason.unwrap_or(BanReason::Other),
+                note,
+                banned_by: None,
+                expiration,
+            },
+        ),
+        SudoMsg::UpdateCheckmarkBan {
+            ban_ids,
+            unban_ids,
+            reason,
+            note,
+            expiration,
+        } => update_checkmark_ban(
+            deps,
+            env,
+            ban_ids,
+            unban_ids,
+            Ban {
+                reason: reason.unwrap_or(BanReason::Other),
+                note,
+                banned_by: None,
+                expiration,
+            },
+        ),
+    }
+}
+
+fn sudo_update_owner(deps: DepsMut, owner: Option<String>) -> Result<Response, ContractError> {
+    // Replace the owner outright, discarding any pending transfer.
+    cw_ownable::initialize_owner(deps.storage, deps.api, owner.as_deref())?;
+    let ownership = cw_ownable::get_ownership(deps.storage)?;
+    Ok(Response::default()
+        .add_attribute("method", "sudo_update_owner")
+        .add_attributes(ownership.into_attributes()))
+}
+
+fn sudo_set_paused(deps: DepsMut, paused: bool) -> Result<Response, ContractError> {
+    PAUSED.save(deps.storage, &paused)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "sudo_set_paused")
+        .add_attribute("paused", paused.to_string()))
+}
+
+/// Returns whether the address is the assigner or has the assigner role.
+fn is_assigner(storage: &dyn Storage, addr: &Addr) -> StdResult<bool> {
+    Ok(*addr == ASSIGNER.load(storage)?
+        || ROLES.has(storage, (Role::Assigner.as_str(), addr.clone())))
+}
+
+/// Ensures the checkmark ID can be assigned to the address.
+fn ensure_assignable(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    checkmark_id: &str,
+    addr: &Addr,
+) -> Result<(), ContractError> {
+    ensure_checkmark_id_available(storage, block, checkmark_id)?;
+    ensure_address_assignable(storage, block, addr)
+}
+
+/// Ensures the address can be given a checkmark, by assignment or
+/// reassignment.
+fn ensure_address_assignable(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    addr: &Addr,
+) -> Result<(), ContractError> {
+    // Ensure address is not banned.
+    if active_address_ban(storage, block, addr)?.is_some() {
+        return Err(ContractError::AddressBanned);
+    }
+
+    // Ensure address is not in its re-assignment cooldown.
+    if let Some(ends) = reassign_cooldown_end(storage, block, addr)? {
+        return Err(ContractError::ReassignCooldown {
+            ends: ends.to_string(),
+        });
+    }
+
+    // Ensure address has not opted out.
+    if OPTED_OUT.has(storage, addr.clone()) {
+        return Err(ContractError::OptedOut);
+    }
+
+    // Ensure address is not queued for mixing and has no live offer.
+    if MIX_QUEUED_ADDRESSES.has(storage, addr.clone()) {
+        return Err(ContractError::AlreadyQueued);
+    }
+    if let Some(offer) = OFFERS.may_load(storage, addr.clone())? {
+        if !offer.expiration.is_expired(block) {
+            return Err(ContractError::OfferPending);
+        }
+    }
+
+    // Ensure address does not already have a checkmark or vote for another
+    // holder.
+    let existing_checkmark = checkmark_for_address(storage, addr)?;
+    if existing_checkmark.is_some() {
+        return Err(ContractError::AlreadyHasCheckmark);
+    }
+    if DELEGATES.has(storage, addr.clone()) {
+        return Err(ContractError::AddressIsDelegate);
+    }
+
+    Ok(())
+}
+
+/// Returns the native fee sent, if any.
+fn native_payment(info: &MessageInfo) -> Result<Option<Fee>, ContractError> {
+    match info.funds.as_slice() {
+        [] => Ok(None),
+        [coin] => Ok(Some(Fee {
+            denom: Denom::Native(coin.denom.clone()),
+            amount: coin.amount,
+        })),
+        _ => Err(PaymentError::MultipleDenoms {}.into()),
+    }
+}
+
+/// Holds the verification fee for assigning to the address, from the escrow
+/// of its pending verification request, which this marks as assigning, or else
+/// the payment. Returns the fee, if any. The fee is collected once the
+/// checkmark is assigned, or released if it is not.
+fn hold_fee(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+    payment: &Option<Fee>,
+) -> Result<Option<Fee>, ContractError> {
+    // Hold the address's pending request, along with its escrowed fee.
+    if let Some(mut request) = pending_verification_request(storage, addr)? {
+        request.status = VerificationStatus::Assigning;
+        VERIFICATION_REQUESTS.save(storage, addr.clone(), &request)?;
+
+        if let Some(fee) = request.fee {
+            if payment.is_some() {
+                return Err(ContractError::FeeAlreadyPaid);
+            }
+
+            return Ok(Some(fee));
+        }
+    }
+
+    let config = CONFIG.may_load(storage)?.unwrap_or_default();
+    match payment {
+        None if config.fees.is_empty() => Ok(None),
+        Some(_) if config.fees.is_empty() => Err(PaymentError::NonPayable {}.into()),
+        Some(payment) if config.fees.contains(payment) => Ok(Some(payment.clone())),
+        _ => Err(incorrect_fee(&config)),
+    }
+}
+
+/// Collects the fee held for a checkmark just assigned to the address, adding
+/// it to the fee balances and fulfilling the request it was held from, if any.
+/// Returns the fee, if any.
+fn collect_fee(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+    payment: Option<Fee>,
+) -> StdResult<Option<Fee>> {
+    let mut fee = payment;
+    if let Some(mut request) = assigning_verification_request(storage, addr)? {
+        request.status = VerificationStatus::Fulfilled;
+        VERIFICATION_REQUESTS.save(storage, addr.clone(), &request)?;
+        fee = fee.or(request.fee);
+    }
+
+    if let Some(fee) = &fee {
+        credit_fee(storage, fee)?;
+    }
+
+    Ok(fee)
+}
+
+/// Releases the fee held for a checkmark that was not assigned to the
+/// address, making the request it was held from pending again, if any, and
+/// returning the refund of the payment to the payer, if any.
+fn release_fee(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+    payment: Option<Fee>,
+    payer: &Addr,
+) -> StdResult<Option<CosmosMsg>> {
+    if let Some(mut request) = assigning_verification_request(storage, addr)? {
+        request.status = VerificationStatus::Pending;
+        VERIFICATION_REQUESTS.save(storage, addr.clone(), &request)?;
+    }
+
+    payment
+        .filter(|payment| !payment.amount.is_zero())
+        .map(|payment| fee_transfer(&payment.denom, payer, payment.amount))
+        .transpose()
+}
+
+/// Adds the fee to the fee balances awaiting withdrawal.
+fn credit_fee(storage: &mut dyn Storage, fee: &Fee) -> StdResult<()> {
+    if fee.amount.is_zero() {
+        return Ok(());
+    }
+
+    let key = denom_key(&fee.denom);
+    let mut balance = FEE_BALANCES.may_load(storage, key.clone())?.unwrap_or(Fee {
+        denom: fee.denom.clone(),
+        amount: Uint128::zero(),
+    });
+    balance.amount += fee.amount;
+    FEE_BALANCES.save(storage, key, &balance)
+}
+
+fn pending_verification_request(
+    storage: &dyn Storage,
+    addr: &Addr,
+) -> StdResult<Option<VerificationRequest>> {
+    Ok(VERIFICATION_REQUESTS
+        .may_load(storage, addr.clone())?
+        .filter(|request| request.status == VerificationStatus::Pending))
+}
+
+fn assigning_verification_request(
+    storage: &dyn Storage,
+    addr: &Addr,
+) -> StdResult<Option<VerificationRequest>> {
+    Ok(VERIFICATION_REQUESTS
+        .may_load(storage, addr.clone())?
+        .filter(|request| request.status == VerificationStatus::Assigning))
+}
+
+fn incorrect_fee(config: &Config) -> ContractError {
+    ContractError::IncorrectFee {
+        fees: config
+            .fees
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
+fn fee_transfer(denom: &Denom, recipient: &Addr, amount: Uint128) -> StdResult<CosmosMsg> {
+    Ok(match denom {
+        Denom::Native(denom) => BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(amount.u128(), denom),
+        }
+        .into(),
+        Denom::Cw20(token) => WasmMsg::Execute {
+            contract_addr: token.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        }
+        .into(),
+    })
+}
+
+/// Ensures the address is not a contract, unless contracts are allowed or it is
+/// allow-listed. Organization checkmarks are exempt.
+fn ensure_not_contract(deps: Deps, addr: &Addr) -> Result<(), ContractError> {
+    let config = CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    if !config.reject_contracts || ALLOWED_CONTRACTS.has(deps.storage, addr.clone()) {
+        return Ok(());
+    }
+
+    // Only contracts have contract info.
+    if deps.querier.query_wasm_contract_info(addr).is_ok() {
+        return Err(ContractError::ContractAddress);
+    }
+
+    Ok(())
+}
+
+/// Ensures the checkmark ID can be given to an address, by assignment or
+/// rotation.
+fn ensure_checkmark_id_available(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    checkmark_id: &str,
+) -> Result<(), ContractError> {
+    // Ensure checkmark_id is not banned.
+    if active_ban(storage, block, checkmark_id)?.is_some() {
+        return Err(ContractError::CheckmarkBanned);
+    }
+
+    // Ensure checkmark_id is not queued for mixing or live in an offer.
+    if MIX_QUEUED_CHECKMARKS.has(storage, checkmark_id.to_string()) {
+        return Err(ContractError::AlreadyQueued);
+    }
+    if let Some(offered_to) = OFFERED_CHECKMARKS.may_load(storage, checkmark_id.to_string())? {
+        if !OFFERS
+            .load(storage, offered_to)?
+            .expiration
+            .is_expired(block)
+        {
+            return Err(ContractError::CheckmarkOffered);
+        }
+    }
+
+    // Ensure checkmark_id is not already assigned.
+    let existing_address = CHECKMARKS_TO_ADDRESSES.may_load(storage, checkmark_id.to_string())?;
+    if existing_address.is_some() {
+        return Err(ContractError::AlreadyAssigned);
+    }
+
+    // Ensure checkmark_id was never issued before, if reuse is prevented.
+    let config = CONFIG.may_load(storage)?.unwrap_or_default();
+    if config.prevent_id_reuse && ISSUED_CHECKMARK_IDS.has(storage, checkmark_id.to_string()) {
+        return Err(ContractError::CheckmarkIdReused);
+    }
+
+    Ok(())
+}
+
+/// Returns when the address's re-assignment cooldown ends, if it is in one.
+fn reassign_cooldown_end(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    addr: &Addr,
+) -> StdResult<Option<Expiration>> {
+    let config = CONFIG.may_load(storage)?.unwrap_or_default();
+    let Some(cooldown) = config.reassign_cooldown else {
+        return Ok(None);
+    };
+
+    Ok(LAST_LOSSES
+        .may_load(storage, addr.clone())?
+        .map(|loss| loss.cooldown_end(cooldown))
+        .filter(|ends| !ends.is_expired(block)))
+}
+
+/// Returns the checkmark ID identified by exactly one of the address or
+/// checkmark ID, ensuring it is assigned.
+fn target_checkmark(
+    deps: Deps,
+    address: Option<String>,
+    checkmark_id: Option<String>,
+) -> Result<String, ContractError> {
+    match (address, checkmark_id) {
+        (Some(address), None) => {
+            let addr = deps.api.addr_validate(&address)?;
+            ADDRESSES_TO_CHECKMARKS
+                .may_load(deps.storage, addr)?
+                .ok_or(ContractError::NoCheckmark)
+        }
+        (None, Some(checkmark_id)) => {
+            if !CHECKMARKS_TO_ADDRESSES.has(deps.storage, checkmark_id.clone()) {
+                return Err(ContractError::NoCheckmark);
+            }
+            Ok(checkmark_id)
+        }
+        _ => Err(ContractError::InvalidTarget),
+    }
+}
+
+/// Returns the suspension of the checkmark ID, unless it has expired.
+fn active_suspension(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    checkmark_id: &str,
+) -> StdResult<Option<Suspension>> {
+    Ok(SUSPENSIONS
+        .may_load(storage, checkmark_id.to_string())?
+        .filter(|suspension| suspension.is_active(block)))
+}
+
+/// Returns the checkmark response for the checkmark ID, if any, including its
+/// suspension and scheduled deletion.
+fn checkmark_response(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    checkmark_id: Option<String>,
+) -> StdResult<GetCheckmarkResponse> {
+    let (suspension, pending_deletion) = match &checkmark_id {
+        Some(checkmark_id) => (
+            active_suspension(storage, block, checkmark_id)?,
+            PENDING_DELETIONS.may_load(storage, checkmark_id.clone())?,
+        ),
+        None => (None, None),
+    };
+
+    Ok(GetCheckmarkResponse {
+        organization: checkmark_id
+            .as_ref()
+            .is_some_and(|checkmark_id| ORGANIZATIONS.has(storage, checkmark_id.clone())),
+        // Suspended holders are not verified until the suspension ends.
+        checkmark_id: checkmark_id.filter(|_| suspension.is_none()),
+        suspension,
+        pending_deletion,
+    })
+}
+
+/// Returns up to `limit` checkmark IDs and heights assigned by the assigner
+/// between the heights, inclusive, ordered by height and then ID.
+fn issued_by<'a>(
+    storage: &'a dyn Storage,
+    assigner: &Addr,
+    from_height: u64,
+    to_height: u64,
+    start_after: Option<(u64, String)>,
+) -> Result<impl Iterator<Item = StdResult<(String, u64)>> + 'a, ContractError> {
+    if from_height > to_height {
+        return Err(ContractError::InvalidHeightRange);
+    }
+
+    let min = match start_after {
+        Some(start_after) if start_after.0 >= from_height => Bound::exclusive(start_after),
+        _ => Bound::inclusive((from_height, String::new())),
+    };
+
+    Ok(ISSUANCES_BY_ASSIGNER
+        .sub_prefix(assigner.clone())
+        .keys(storage, Some(min), None, Order::Ascending)
+        .take_while(move |item| {
+            item.as_ref()
+                .map_or(true, |(height, _)| *height <= to_height)
+        })
+        .map(|item| item.map(|(height, checkmark_id)| (checkmark_id, height))))
+}
+
+/// Validates a hex-encoded SHA-256 hash and returns it lowercased.
+fn validate_session_commitment(session_commitment: &str) -> Result<String, ContractError> {
+    if session_commitment.len() != 64 || hex::decode(session_commitment).is_err() {
+        return Err(ContractError::InvalidSessionCommitment);
+    }
+
+    Ok(session_commitment.to_lowercase())
+}
+
+fn validate_evidence_hash(evidence_hash: &str) -> Result<String, ContractError> {
+    if evidence_hash.len() != 64 || hex::decode(evidence_hash).is_err() {
+        return Err(ContractError::InvalidEvidenceHash);
+    }
+
+    Ok(evidence_hash.to_lowercase())
+}
+
+/// Returns the ban on the checkmark ID, unless it has expired.
+fn active_ban(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    checkmark_id: &str,
+) -> StdResult<Option<Ban>> {
+    Ok(BANNED_CHECKMARKS
+        .may_load(storage, checkmark_id.to_string())?
+        .filter(|ban| ban.is_active(block)))
+}
+
+/// Returns the ban on the address, unless it has expired.
+fn active_address_ban(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    addr: &Addr,
+) -> StdResult<Option<Ban>> {
+    Ok(BANNED_ADDRESSES
+        .may_load(storage, addr.clone())?
+        .filter(|ban| ban.is_active(block)))
+}
+
+/// Ensures the sender is the owner or has been granted the role. Errors with
+/// the ownership error if neither.
+fn assert_owner_or_role(
+    storage: &dyn Storage,
+    sender: &Addr,
+    role: Role,
+) -> Result<(), ContractError> {
+    match cw_ownable::assert_owner(storage, sender) {
+        Ok(()) => Ok(()),
+        Err(_) if ROLES.has(storage, (role.as_str(), sender.clone())) => Ok(()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Adds the checkmark to both mappings, records its issuance, and increments
+/// the count. The caller must ensure neither the checkmark nor the address is
+/// assigned.
+fn assign_checkmark(
+    storage: &mut dyn Storage,
+    checkmark_id: &str,
+    addr: &Addr,
+    issuance: &Issuance,
+) -> StdResult<()> {
+    // Return any delegation to the address to its delegator, since the
+    // address now votes for itself.
+    if let Some(delegator) = DELEGATES.may_load(storage, addr.clone())? {
+        DELEGATIONS.remove(storage, delegator);
+        DELEGATES.remove(storage, addr.clone());
+    }
+
+    CHECKMARKS_TO_ADDRESSES.save(storage, checkmark_id.to_string(), addr)?;
+    ADDRESSES_TO_CHECKMARKS.save(storage, addr.clone(), &checkmark_id.to_string())?;
+    CHECKMARK_COUNT.update(storage, |count| Ok::<u64, StdError>(count + 1))?;
+
+    ISSUED_CHECKMARK_IDS.save(storage, checkmark_id.to_string(), &Empty {})?;
+    ISSUANCES.save(storage, checkmark_id.to_string(), issuance)?;
+    ISSUANCES_BY_ASSIGNER.save(
+        storage,
+        (
+            issuance.assigner.clone(),
+            issuance.height,
+            checkmark_id.to_string(),
+        ),
+        &Empty {},
+    )?;
+
+    Ok(())
+}
+
+/// Assigns the checkmark, collecting the held fee, or offers it to the address
+/// if consent is required, holding the payment until it is accepted. Any
+/// expired offer of the checkmark is withdrawn. Returns whether it was
+/// offered, and the refund of the withdrawn offer. The caller must ensure it
+/// is assignable, withdraw any expired offer to the address, and hold the fee.
+fn assign_or_offer(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    checkmark_id: &str,
+    addr: &Addr,
+    issuance: Issuance,
+    payment: Option<Fee>,
+) -> StdResult<(bool, Option<CosmosMsg>)> {
+    let config = CONFIG.may_load(storage)?.unwrap_or_default();
+    if !config.require_consent {
+        assign_checkmark(storage, checkmark_id, addr, &issuance)?;
+        collect_fee(storage, addr, payment)?;
+        return Ok((false, None));
+    }
+
+    let mut refund = None;
+    if let Some(offered_to) = OFFERED_CHECKMARKS.may_load(storage, checkmark_id.to_string())? {
+        let offer = OFFERS.load(storage, offered_to.clone())?;
+        refund = withdraw_offer(storage, &offered_to, offer)?;
+    }
+
+    save_offer(
+        storage,
+        addr,
+        &Offer {
+            checkmark_id: checkmark_id.to_string(),
+            issuance,
+            expiration: config.offer_timeout.after(block),
+            payment,
+            reassign_from: None,
+        },
+    )?;
+
+    Ok((true, refund))
+}
+
+fn queue_mix_op(storage: &mut dyn Storage, op: MixOp) -> StdResult<()> {
+    let (checkmark_id, addr) = match &op {
+        MixOp::Assign {
+            checkmark_id,
+            address,
+            ..
+        }
+        | MixOp::Delete {
+            checkmark_id,
+            address,
+        } => (checkmark_id, address),
+    };
+    MIX_QUEUED_CHECKMARKS.save(storage, checkmark_id.clone(), &Empty {})?;
+    MIX_QUEUED_ADDRESSES.save(storage, addr.clone(), &Empty {})?;
+
+    let end = MIX_QUEUE_END.may_load(storage)?.unwrap_or_default();
+    requeue_mix_op(storage, end, &op)?;
+    MIX_QUEUE_END.save(storage, &(end + 1))?;
+    let len = MIX_QUEUE_LEN.may_load(storage)?.unwrap_or_default();
+    MIX_QUEUE_LEN.save(storage, &(len + 1))
+}
+
+/// Saves the operation at the queue position, indexing assignments by
+/// assigner.
+fn requeue_mix_op(storage: &mut dyn Storage, position: u64, op: &MixOp) -> StdResult<()> {
+    MIX_QUEUE.save(storage, position, op)?;
+    if let MixOp::Assign { issuance, .. } = op {
+        MIX_QUEUE_BY_ASSIGNER.save(
+            storage,
+            (issuance.assigner.clone(), issuance.height, position),
+            &Empty {},
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Removes the operation at the queue position and its index entry.
+fn remove_mix_op(storage: &mut dyn Storage, position: u64, op: &MixOp) {
+    MIX_QUEUE.remove(storage, position);
+    if let MixOp::Assign { issuance, .. } = op {
+        MIX_QUEUE_BY_ASSIGNER.remove(
+            storage,
+            (issuance.assigner.clone(), issuance.height, position),
+        );
+    }
+}
+
+/// Takes the operation at the queue position off the mixing queue, releasing
+/// its checkmark ID and address.
+fn unqueue_mix_op(storage: &mut dyn Storage, position: u64, op: &MixOp) -> StdResult<()> {
+    remove_mix_op(storage, position, op);
+
+    let (checkmark_id, addr) = match op {
+        MixOp::Assign {
+            checkmark_id,
+            address,
+            ..
+        }
+        | MixOp::Delete {
+            checkmark_id,
+            address,
+        } => (checkmark_id, address),
+    };
+    MIX_QUEUED_CHECKMARKS.remove(storage, checkmark_id.clone());
+    MIX_QUEUED_ADDRESSES.remove(storage, addr.clone());
+    let len = MIX_QUEUE_LEN.load(storage)?;
+    MIX_QUEUE_LEN.save(storage, &(len - 1))
+}
+
+/// Draws an offset below the bound from the hash of the seed and position, so
+/// the order cannot be chosen by whoever queued the operations.
+fn draw(seed: &Binary, position: u64, bound: u64) -> u64 {
+    let hash = Sha256::new()
+        .chain_update(seed.as_slice())
+        .chain_update(position.to_be_bytes())
+        .finalize();
+    u64::from_be_bytes(hash[..8].try_into().unwrap()) % bound
+}
+
+fn save_offer(storage: &mut dyn Storage, addr: &Addr, offer: &Offer) -> StdResult<()> {
+    OFFERS.save(storage, addr.clone(), offer)?;
+    OFFERED_CHECKMARKS.save(storage, offer.checkmark_id.clone(), addr)?;
+    OFFERS_BY_ASSIGNER.save(
+        storage,
+        (
+            offer.issuance.assigner.clone(),
+            offer.issuance.height,
+            addr.clone(),
+        ),
+        &Empty {},
+    )
+}
+
+fn remove_offer(storage: &mut dyn Storage, addr: &Addr, offer: &Offer) {
+    OFFERS.remove(storage, addr.clone());
+    OFFERED_CHECKMARKS.remove(storage, offer.checkmark_id.clone());
+    OFFERS_BY_ASSIGNER.remove(
+        storage,
+        (
+            offer.issuance.assigner.clone(),
+            offer.issuance.height,
+            addr.clone(),
+        ),
+    );
+}
+
+/// Saves the pending assignment, replacing any previous one and indexing it by
+/// the assigners that approved it.
+fn save_pending_assignment(
+    storage: &mut dyn Storage,
+    checkmark_id: &str,
+    pending: &PendingAssignment,
+) -> StdResult<()> {
+    remove_pending_assignment(storage, checkmark_id)?;
+    PENDING_ASSIGNMENTS.save(storage, checkmark_id.to_string(), pending)?;
+    for assigner in &pending.approvals {
+        PENDING_ASSIGNMENTS_BY_ASSIGNER.save(
+            storage,
+            (assigner.clone(), checkmark_id.to_string()),
+            &Empty {},
+        )?;
+    }
+
+    Ok(())
+}
+
+fn remove_pending_assignment(storage: &mut dyn Storage, checkmark_id: &str) -> StdResult<()> {
+    if let Some(pending) = PENDING_ASSIGNMENTS.may_load(storage, checkmark_id.to_string())? {
+        PENDING_ASSIGNMENTS.remove(storage, checkmark_id.to_string());
+        for assigner in pending.approvals {
+            PENDING_ASSIGNMENTS_BY_ASSIGNER.remove(storage, (assigner, checkmark_id.to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes an offer that was not accepted, releasing its held fee. Returns the
+/// refund of the payment, if any.
+fn withdraw_offer(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+    offer: Offer,
+) -> StdResult<Option<CosmosMsg>> {
+    remove_offer(storage, addr, &offer);
+    release_fee(storage, addr, offer.payment, &offer.issuance.assigner)
+}
+
+/// Withdraws the address's offer if it expired, before the address is given a
+/// checkmark some other way. Returns the refund of its payment, if any.
+fn withdraw_expired_offer(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    addr: &Addr,
+) -> StdResult<Option<CosmosMsg>> {
+    match OFFERS.may_load(storage, addr.clone())? {
+        Some(offer) if offer.expiration.is_expired(block) => withdraw_offer(storage, addr, offer),
+        _ => Ok(None),
+    }
+}
+
+/// Replaces the address's checkmark ID, keeping the old ID's tombstone and
+/// moving its issuance and other state to the new ID. The address keeps
+/// everything keyed by it, and the checkmark count is unchanged.
+fn rotate_checkmark(
+    storage: &mut dyn Storage,
+    old_checkmark_id: &str,
+    new_checkmark_id: &str,
+    addr: &Addr,
+) -> StdResult<()> {
+    CHECKMARKS_TO_ADDRESSES.remove(storage, old_checkmark_id.to_string());
+    CHECKMARKS_TO_ADDRESSES.save(storage, new_checkmark_id.to_string(), addr)?;
+    ADDRESSES_TO_CHECKMARKS.save(storage, addr.clone(), &new_checkmark_id.to_string())?;
+    ISSUED_CHECKMARK_IDS.save(storage, new_checkmark_id.to_string(), &Empty {})?;
+
+    if let Some(issuance) = ISSUANCES.may_load(storage, old_checkmark_id.to_string())? {
+        ISSUANCES.remove(storage, old_checkmark_id.to_string());
+        ISSUANCES_BY_ASSIGNER.remove(
+            storage,
+            (
+                issuance.assigner.clone(),
+                issuance.height,
+                old_checkmark_id.to_string(),
+            ),
+        );
+        ISSUANCES.save(storage, new_checkmark_id.to_string(), &issuance)?;
+        ISSUANCES_BY_ASSIGNER.save(
+            storage,
+            (
+                issuance.assigner,
+                issuance.height,
+                new_checkmark_id.to_string(),
+            ),
+            &Empty {},
+        )?;
+    }
+    if let Some(suspension) = SUSPENSIONS.may_load(storage, old_checkmark_id.to_string())? {
+        SUSPENSIONS.remove(storage, old_checkmark_id.to_string());
+        SUSPENSIONS.save(storage, new_checkmark_id.to_string(), &suspension)?;
+    }
+    if let Some(revocation) =
+        SCHEDULED_REVOCATIONS.may_load(storage, old_checkmark_id.to_string())?
+    {
+        SCHEDULED_REVOCATIONS.remove(storage, old_checkmark_id.to_string());
+        SCHEDULED_REVOCATIONS.save(storage, new_checkmark_id.to_string(), &revocation)?;
+    }
+    if let Some(deletion) = PENDING_DELETIONS.may_load(storage, old_checkmark_id.to_string())? {
+        PENDING_DELETIONS.remove(storage, old_checkmark_id.to_string());
+        PENDING_DELETIONS.save(storage, new_checkmark_id.to_string(), &deletion)?;
+    }
+    if ORGANIZATIONS.has(storage, old_checkmark_id.to_string()) {
+        ORGANIZATIONS.remove(storage, old_checkmark_id.to_string());
+        ORGANIZATIONS.save(storage, new_checkmark_id.to_string(), &Empty {})?;
+    }
+
+    Ok(())
+}
+
+/// Replaces the issuance of an assigned checkmark, updating its index.
+fn save_issuance(
+    storage: &mut dyn Storage,
+    checkmark_id: &str,
+    issuance: &Issuance,
+) -> StdResult<()> {
+    if let Some(old) = ISSUANCES.may_load(storage, checkmark_id.to_string())? {
+        ISSUANCES_BY_ASSIGNER.remove(
+            storage,
+            (old.assigner, old.height, checkmark_id.to_string()),
+        );
+    }
+
+    ISSUANCES.save(storage, checkmark_id.to_string(), issuance)?;
+    ISSUANCES_BY_ASSIGNER.save(
+        storage,
+        (
+            issuance.assigner.clone(),
+            issuance.height,
+            checkmark_id.to_string(),
+        ),
+        &Empty {},
+    )
+}
+
+/// Removes the checkmark from an address that lost it by deletion, revocation
+/// or ban, recording when for the re-assignment cooldown.
+fn lose_checkmark(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    checkmark_id: &str,
+    addr: &Addr,
+) -> StdResult<()> {
+    remove_checkmark(storage, checkmark_id, addr)?;
+    LAST_LOSSES.save(
+        storage,
+        addr.clone(),
+        &LastLoss {
+            height: block.height,
+            time: block.time,
+        },
+    )
+}
+
+/// Flags an assigned checkmark as an organization and increments the
+/// organization count.
+fn save_organization(storage: &mut dyn Storage, checkmark_id: &str) -> StdResult<()> {
+    ORGANIZATIONS.save(storage, checkmark_id.to_string(), &Empty {})?;
+    let count = ORGANIZATION_COUNT.may_load(storage)?.unwrap_or_default();
+    ORGANIZATION_COUNT.save(storage, &(count + 1))
+}
+
+/// Moves the checkmark to another address, keeping its issuance, suspension,
+/// and organization flag. Anything linked to the old address is removed,
+/// including the deletion it scheduled and any revocation scheduled against
+/// it.
+fn move_checkmark(
+    storage: &mut dyn Storage,
+    checkmark_id: &str,
+    from: &Addr,
+    to: &Addr,
+) -> StdResult<()> {
+    let issuance = ISSUANCES.load(storage, checkmark_id.to_string())?;
+    let suspension = SUSPENSIONS.may_load(storage, checkmark_id.to_string())?;
+    let organization = ORGANIZATIONS.has(storage, checkmark_id.to_string());
+
+    remove_checkmark(storage, checkmark_id, from)?;
+    assign_checkmark(storage, checkmark_id, to, &issuance)?;
+
+    if organization {
+        save_organization(storage, checkmark_id)?;
+    }
+
+    if let Some(suspension) = suspension {
+        SUSPENSIONS.save(storage, checkmark_id.to_string(), &suspension)?;
+    }
+
+    Ok(())
+}
+
+/// Removes the checkmark from both mappings and decrements the count, along
+/// with anything linked to the address.
+fn remove_checkmark(storage: &mut dyn Storage, checkmark_id: &str, addr: &Addr) -> StdResult<()> {
+    CHECKMARKS_TO_ADDRESSES.remove(storage, checkmark_id.to_string());
+    ADDRESSES_TO_CHECKMARKS.remove(storage, addr.clone());
+    CHECKMARK_COUNT.update(storage, |count| Ok::<u64, StdError>(count - 1))?;
+
+    // Remove issuance, suspension, scheduled revocation, and scheduled
+    // deletion.
+    if let Some(issuance) = ISSUANCES.may_load(storage, checkmark_id.to_string())? {
+        ISSUANCES.remove(storage, checkmark_id.to_string());
+        ISSUANCES_BY_ASSIGNER.remove(
+            storage,
+            (issuance.assigner, issuance.height, checkmark_id.to_string()),
+        );
+    }
+    SUSPENSIONS.remove(storage, checkmark_id.to_string());
+    SCHEDULED_REVOCATIONS.remove(storage, checkmark_id.to_string());
+    PENDING_DELETIONS.remove(storage, checkmark_id.to_string());
+    if ORGANIZATIONS.has(storage, checkmark_id.to_string()) {
+        ORGANIZATIONS.remove(storage, checkmark_id.to_string());
+        ORGANIZATION_COUNT.update(storage, |count| Ok::<u64, StdError>(count - 1))?;
+    }
+
+    // Remove linked EVM address.
+    if let Some(eth_address) = ADDRESSES_TO_EVM_ADDRESSES.may_load(storage, addr.clone())? {
+        EVM_ADDRESSES_TO_ADDRESSES.remove(storage, eth_address);
+        ADDRESSES_TO_EVM_ADDRESSES.remove(storage, addr.clone());
+    }
+
+    // Remove registered public key.
+    remove_pubkey(storage, addr)?;
+
+    // Remove delegation.
+    if let Some(delegate) = DELEGATIONS.may_load(storage, addr.clone())? {
+        DELEGATIONS.remove(storage, addr.clone());
+        DELEGATES.remove(storage, delegate);
+    }
+
+    // Remove guardians, pending guardian change, and pending recovery.
+    GUARDIANS.remove(storage, addr.clone());
+    PENDING_GUARDIANS.remove(storage, addr.clone());
+    RECOVERIES.remove(storage, addr.clone());
+
+    // Remove secondary addresses and pending proposals.
+    let secondary_addresses = PRIMARY_SECONDARY_ADDRESSES
+        .prefix(addr.clone())
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for secondary in secondary_addresses {
+        SECONDARY_ADDRESSES.remove(storage, secondary.clone());
+        PRIMARY_SECONDARY_ADDRESSES.remove(storage, (addr.clone(), secondary));
+    }
+    let pending_addresses = PENDING_SECONDARY_ADDRESSES
+        .prefix(addr.clone())
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for pending in pending_addresses {
+        PENDING_SECONDARY_ADDRESSES.remove(storage, (addr.clone(), pending));
+    }
+
+    Ok(())
+}
+
+/// Returns the checkmark ID assigned to the address, or to the primary address
+/// if it is a secondary address.
+fn checkmark_for_address(storage: &dyn Storage, addr: &Addr) -> StdResult<Option<String>> {
+    let primary = SECONDARY_ADDRESSES
+        .may_load(storage, addr.clone())?
+        .unwrap_or_else(|| addr.clone());
+
+    ADDRESSES_TO_CHECKMARKS.may_load(storage, primary)
+}
+
+/// Removes the public key registered by the address, if any.
+fn remove_pubkey(storage: &mut dyn Storage, addr: &Addr) -> StdResult<()> {
+    if let Some(pubkey) = ADDRESSES_TO_PUBKEYS.may_load(storage, addr.clone())? {
+        // The public key was validated when registered.
+        for canonical in pubkey::derive_canonical_addresses(&pubkey).unwrap_or_default() {
+            CANONICAL_ADDRESSES_TO_ADDRESSES.remove(storage, &canonical);
+        }
+        ADDRESSES_TO_PUBKEYS.remove(storage, addr.clone());
+    }
+
+    Ok(())
+}
+
+/// Resolves a canonical address to the address it belongs to on this chain,
+/// using registered public keys before falling back to this chain's address
+/// encoding.
+fn resolve_canonical_address(deps: Deps, canonical: &[u8]) -> StdResult<Option<Addr>> {
+    if let Some(addr) = CANONICAL_ADDRESSES_TO_ADDRESSES.may_load(deps.storage, canonical)? {
+        return Ok(Some(addr));
+    }
+
+    Ok(deps.api.addr_humanize(&CanonicalAddr::from(canonical)).ok())
+}
+
+/// Returns the checkmark ID assigned to the first of the canonical addresses
+/// that has one, if any.
+fn checkmark_for_canonical_addresses(
+    deps: Deps,
+    canonical_addresses: &[Vec<u8>],
+) -> StdResult<Option<String>> {
+    for canonical in canonical_addresses {
+        if let Some(addr) = resolve_canonical_address(deps, canonical)? {
+            if let Some(checkmark_id) = checkmark_for_address(deps.storage, &addr)? {
+                return Ok(Some(checkmark_id));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Returns the verified principal whose vote the address casts. A delegate
+/// votes for its delegator, and a checkmark holder votes for itself unless it
+/// has delegated.
+fn resolve_voter(deps: Deps, block: &BlockInfo, addr: Addr) -> StdResult<ResolveVoterResponse> {
+    let principal = match DELEGATES.may_load(deps.storage, addr.clone())? {
+        Some(delegator) => Some(delegator),
+        None => {
+            let primary = SECONDARY_ADDRESSES
+                .may_load(deps.storage, addr.clone())?
+                .unwrap_or(addr);
+            if DELEGATIONS.has(deps.storage, primary.clone()) {
+                None
+            } else {
+                Some(primary)
+            }
+        }
+    };
+
+    let checkmark_id = match &principal {
+        Some(principal) => ADDRESSES_TO_CHECKMARKS.may_load(deps.storage, principal.clone())?,
+        None => None,
+    };
+
+    // Suspended and organization checkmarks cannot vote.
+    let checkmark_id = match checkmark_id {
+        Some(checkmark_id)
+            if active_suspension(deps.storage, block, &checkmark_id)?.is_some()
+                || ORGANIZATIONS.has(deps.storage, checkmark_id.clone()) =>
+        {
+            None
+        }
+        checkmark_id => checkmark_id,
+    };
+
+    Ok(ResolveVoterResponse {
+        principal: checkmark_id.as_ref().and(principal),
+        checkmark_id,
+    })
+}
+
+fn load_reports(storage: &dyn Storage, ids: Vec<u64>) -> StdResult<Vec<ReportEntry>> {
+    ids.into_iter()
+        .map(|id| {
+            Ok(ReportEntry {
+                id,
+                report: REPORTS.load(storage, id)?,
+            })
+        })
+        .collect()
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::GetCheckmark { address } => {
+            let addr = deps.api.addr_validate(&address)?;
+
+            to_binary(&checkmark_response(
+                deps.storage,
+                &env.block,
+                checkmark_for_address(deps.storage, &addr)?,
+            )?)
+        }
+        QueryMsg::GetAddress { checkmark_id } => to_binary(&GetAddressResponse {
+            address: CHECKMARKS_TO_ADDRESSES
+                .may_load(deps.storage, checkmark_id)
+                .unwrap(),
+        }),
+        QueryMsg::GetCheckmarkByEvmAddress { eth_address } => {
+            let eth_address = evm::validate_address(&eth_address)
+                .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+            let checkmark_id =
+                match EVM_ADDRESSES_TO_ADDRESSES.may_load(deps.storage, eth_address)? {
+                    Some(addr) => checkmark_for_address(deps.storage, &addr)?,
+                    None => None,
+                };
+
+            to_binary(&checkmark_response(deps.storage, &env.block, checkmark_id)?)
+        }
+        QueryMsg::GetCheckmarkByCanonicalAddress { canonical_address } => {
+            to_binary(&checkmark_response(
+                deps.storage,
+                &env.block,
+                checkmark_for_canonical_addresses(deps, &[canonical_address.to_vec()])?,
+            )?)
+        }
+        QueryMsg::GetCheckmarkByPubkey { pubkey } => {
+            let canonical_addresses = pubkey::derive_canonical_addresses(&pubkey)
+                .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+            to_binary(&checkmark_response(
+                deps.storage,
+                &env.block,
+                checkmark_for_canonical_addresses(deps, &canonical_addresses)?,
+            )?)
+        }
+        QueryMsg::Count {} => to_binary(&CountResponse {
+            count: CHECKMARK_COUNT.load(deps.storage)?
+                - ORGANIZATION_COUNT
+                    .may_load(deps.storage)?
+                    .unwrap_or_default(),
+        }),
+        QueryMsg::ActiveCount {} => {
+            // Suspensions are removed with their checkmark, so each active one
+            // belongs to an assigned checkmark.
+            let mut suspended = 0;
+            for item in SUSPENSIONS.range(deps.storage, None, None, Order::Ascending) {
+                let (checkmark_id, suspension) = item?;
+                if suspension.is_active(&env.block)
+                    && !ORGANIZATIONS.has(deps.storage, checkmark_id)
+                {
+                    suspended += 1;
+                }
+            }
+
+            to_binary(&CountResponse {
+                count: CHECKMARK_COUNT.load(deps.storage)?
+                    - ORGANIZATION_COUNT
+                        .may_load(deps.storage)?
+                        .unwrap_or_default()
+                    - suspended,
+            })
+        }
+        QueryMsg::CheckmarkIssued { checkmark_id } => to_binary(&CheckmarkIssuedResponse {
+            // Checkmarks assigned before tombstones were kept have none.
+            issued: ISSUED_CHECKMARK_IDS.has(deps.storage, checkmark_id.clone())
+                || CHECKMARKS_TO_ADDRESSES.has(deps.storage, checkmark_id),
+        }),
+        QueryMsg::CheckmarkBanned { checkmark_id } => {
+            let ban = active_ban(deps.storage, &env.block, &checkmark_id)?;
+
+            to_binary(&CheckmarkBannedResponse {
+                banned: ban.is_some(),
+                ban,
+            })
+        }
+        QueryMsg::ListBannedCheckmarks { start_after, limit } => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+            let bans = BANNED_CHECKMARKS
+                .range(
+                    deps.storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .filter(|item| {
+                    item.as_ref()
+                        .map_or(true, |(_, ban)| ban.is_active(&env.block))
+                })
+                .take(limit)
+                .map(|item| {
+                    item.map(|(checkmark_id, ban)| BannedCheckmarkEntry { checkmark_id, ban })
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+
+            to_binary(&ListBannedCheckmarksResponse { bans })
+        }
+        QueryMsg::Paused {} => to_binary(&PausedResponse {
+            paused: PAUSED.may_load(deps.storage)?.unwrap_or_default(),
+        }),
+        QueryMsg::MixingEpoch {} => to_binary(&MixingEpochResponse {
+            queued: MIX_QUEUE_LEN.may_load(deps.storage)?.unwrap_or_default(),
+            next_epoch: NEXT_EPOCH.may_load(deps.storage)?,
+            processing: MIX_BATCH
+                .may_load(deps.storage)?
+                .is_some_and(|batch| batch.next < batch.end),
+        }),
+        QueryMsg::AddressBanned { address } => {
+            let addr = deps.api.addr_validate(&address)?;
+            let ban = active_address_ban(deps.storage, &env.block, &addr)?;
+
+            to_binary(&AddressBannedResponse {
+                banned: ban.is_some(),
+                ban,
+            })
+        }
+        QueryMsg::ReportPool {} => to_binary(&ReportPoolResponse {
+            balances: REPORT_POOL
+                .range(deps.storage, None, None, Order::Ascending)
+                .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+                .collect::<StdResult<_>>()?,
+        }),
+        QueryMsg::FeeBalances {} => to_binary(&FeeBalancesResponse {
+            balances: FEE_BALANCES
+                .range(deps.storage, None, None, Order::Ascending)
+                .map(|item| item.map(|(_, balance)| balance))
+                .collect::<StdResult<_>>()?,
+        }),
+        QueryMsg::VerificationRequest { address } => {
+            let addr = deps.api.addr_validate(&address)?;
+
+            to_binary(&VerificationRequestResponse {
+                request: VERIFICATION_REQUESTS.may_load(deps.storage, addr)?,
+            })
+        }
+        QueryMsg::ListPendingVerificationRequests { start_after, limit } => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+            let start_after = start_after
+                .map(|address| deps.api.addr_validate(&address))
+                .transpose()?;
+
+            let requests = VERIFICATION_REQUESTS
+                .range(
+                    deps.storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .filter(|item| {
+                    item.as_ref().map_or(true, |(_, request)| {
+                        request.status == VerificationStatus::Pending
+                    })
+                })
+                .take(limit)
+                .map(|item| {
+                    item.map(|(address, request)| VerificationRequestEntry { address, request })
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+
+            to_binary(&ListVerificationRequestsResponse { requests })
+        }
+        QueryMsg::ListAllowedContracts { start_after, limit } => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+            let start_after = start_after
+                .map(|address| deps.api.addr_validate(&address))
+                .transpose()?;
+
+            let contracts = ALLOWED_CONTRACTS
+                .keys(
+                    deps.storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .take(limit)
+                .collect::<StdResult<Vec<_>>>()?;
+
+            to_binary(&ListAllowedContractsResponse { contracts })
+        }
+        QueryMsg::ListOrganizations { start_after, limit } => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+            let organizations = ORGANIZATIONS
+                .keys(
+                    deps.storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .take(limit)
+                .map(|checkmark_id| {
+                    let checkmark_id = checkmark_id?;
+                    let address =
+                        CHECKMARKS_TO_ADDRESSES.load(deps.storage, checkmark_id.clone())?;
+                    Ok(OrganizationEntry {
+                        checkmark_id,
+                        address,
+                    })
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+
+            to_binary(&ListOrganizationsResponse { organizations })
+        }
+        QueryMsg::ListBannedAddresses { start_after, limit } => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+            let start_after = start_after
+                .map(|address| deps.api.addr_validate(&address))
+                .transpose()?;
+
+            let bans = BANNED_ADDRESSES
+                .range(
+                    deps.storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .filter(|item| {
+                    item.as_ref()
+                        .map_or(true, |(_, ban)| ban.is_active(&env.block))
+                })
+                .take(limit)
+                .map(|item| item.map(|(address, ban)| BannedAddressEntry { address, ban }))
+                .collect::<StdResult<Vec<_>>>()?;
+
+            to_binary(&ListBannedAddressesResponse { bans })
+        }
+        QueryMsg::Assigner {} => to_binary(&AssignerResponse {
+            assigner: ASSIGNER.load(deps.storage)?,
+        }),
+        QueryMsg::SecondaryAddresses { address } => {
+            let addr = deps.api.addr_validate(&address)?;
+
+            to_binary(&SecondaryAddressesResponse {
+                addresses: PRIMARY_SECONDARY_ADDRESSES
+                    .prefix(addr)
+                    .keys(deps.storage, None, None, Order::Ascending)
+                    .collect::<StdResult<Vec<_>>>()?,
+            })
+        }
+        QueryMsg::GetDelegation { address } => {
+            let addr = deps.api.addr_validate(&address)?;
+
+            to_binary(&GetDelegationResponse {
+                delegate: DELEGATIONS.may_load(deps.storage, addr)?,
+            })
+        }
+        QueryMsg::ResolveVoter { address } => {
+            let addr = deps.api.addr_validate(&address)?;
+            to_binary(&resolve_voter(deps, &env.block, addr)?)
+        }
+        QueryMsg::Guardians { address } => {
+            let addr = deps.api.addr_validate(&address)?;
+            let mut guardians = GUARDIANS.may_load(deps.storage, addr.clone())?;
+            let mut pending_change = PENDING_GUARDIANS.may_load(deps.storage, addr)?;
+
+            // Show a change that has taken effect but not yet been applied.
+            if let Some(change) =
+                pending_change.take_if(|change| change.effective_after.is_expired(&env.block))
+            {
+                guardians = (!change.guardians.is_empty()).then_some(Guardians {
+                    guardians: change.guardians,
+                    threshold: change.threshold,
+                });
+            }
+
+            to_binary(&GuardiansResponse {
+                guardians: guardians
+                    .as_ref()
+                    .map(|guardians| guardians.guardians.clone())
+                    .unwrap_or_default(),
+                threshold: guardians.map_or(0, |guardians| guardians.threshold),
+                pending_change,
+            })
+        }
+        QueryMsg::PendingRecovery { address } => {
+            let addr = deps.api.addr_validate(&address)?;
+
+            to_binary(&PendingRecoveryResponse {
+                recovery: RECOVERIES.may_load(deps.storage, addr)?,
+            })
+        }
+        QueryMsg::ListPendingRecoveries { start_after, limit } => {
+            let start_after = start_after
+                .map(|address| deps.api.addr_validate(&address))
+                .transpose()?;
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+            to_binary(&ListPendingRecoveriesResponse {
+                recoveries: RECOVERIES
+                    .range(
+                        deps.storage,
+                        start_after.map(Bound::exclusive),
+                        None,
+                        Order::Ascending,
+                    )
+                    .take(limit)
+                    .map(|item| item.map(|(holder, recovery)| PendingRecovery { holder, recovery }))
+                    .collect::<StdResult<Vec<_>>>()?,
+            })
+        }
+        QueryMsg::ScheduledRevocation { checkmark_id } => to_binary(&ScheduledRevocationResponse {
+            revocation: SCHEDULED_REVOCATIONS.may_load(deps.storage, checkmark_id)?,
+        }),
+        QueryMsg::ListScheduledRevocations { start_after, limit } => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+            to_binary(&ListScheduledRevocationsResponse {
+                revocations: SCHEDULED_REVOCATIONS
+                    .range(
+                        deps.storage,
+                        start_after.map(Bound::exclusive),
+                        None,
+                        Order::Ascending,
+                    )
+                    .take(limit)
+                    .map(|item| {
+                        item.map(|(checkmark_id, revocation)| ScheduledRevocationEntry {
+                            checkmark_id,
+                            revocation,
+                        })
+                    })
+                    .collect::<StdResult<Vec<_>>>()?,
+            })
+        }
+        QueryMsg::Report { id } => to_binary(&ReportResponse {
+            report: REPORTS.may_load(deps.storage, id)?,
+        }),
+        QueryMsg::ListReportsByStatus {
+            status,
+            start_after,
+            limit,
+        } => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+            let ids = REPORTS_BY_STATUS
+                .prefix(status.as_str())
+                .keys(
+                    deps.storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .take(limit)
+                .collect::<StdResult<Vec<_>>>()?;
+
+            to_binary(&ListReportsResponse {
+                reports: load_reports(deps.storage, ids)?,
+            })
+        }
+        QueryMsg::ListReportsByTarget {
+            address,
+            start_after,
+            limit,
+        } => {
+            let addr = deps.api.addr_validate(&address)?;
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+            let ids = REPORTS_BY_TARGET
+                .prefix(addr)
+                .keys(
+                    deps.storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .take(limit)
+                .collect::<StdResult<Vec<_>>>()?;
+
+            to_binary(&ListReportsResponse {
+                reports: load_reports(deps.storage, ids)?,
+            })
+        }
+        QueryMsg::ListIssuedBy {
+            assigner,
+            from_height,
+            to_height,
+            start_after,
+            limit,
+        } => {
+            let assigner = deps.api.addr_validate(&assigner)?;
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+            let checkmarks =
+                issued_by(deps.storage, &assigner, from_height, to_height, start_after)
+                    .map_err(|err| StdError::generic_err(err.to_string()))?
+                    .take(limit)
+                    .map(|item| {
+                        let (checkmark_id, height) = item?;
+                        Ok(IssuedCheckmark {
+                            address: CHECKMARKS_TO_ADDRESSES
+                                .load(deps.storage, checkmark_id.clone())?,
+                            checkmark_id,
+                            height,
+                        })
+                    })
+                    .collect::<StdResult<Vec<_>>>()?;
+
+            to_binary(&ListIssuedByResponse { checkmarks })
+        }
+        QueryMsg::Offer { address } => {
+            let addr = deps.api.addr_validate(&address)?;
+
+            to_binary(&OfferResponse {
+                offer: OFFERS.may_load(deps.storage, addr)?,
+            })
+        }
+        QueryMsg::OptedOut { address } => {
+            let addr = deps.api.addr_validate(&address)?;
+
+            to_binary(&OptedOutResponse {
+                opted_out: OPTED_OUT.has(deps.storage, addr),
+            })
+        }
+        QueryMsg::ReassignCooldown { address } => {
+            let addr = deps.api.addr_validate(&address)?;
+            let ends = reassign_cooldown_end(deps.storage, &env.block, &addr)?;
+
+            to_binary(&ReassignCooldownResponse {
+                ends,
+                remaining: ends.and_then(|ends| match ends {
+                    Expiration::AtHeight(height) => {
+                        Some(Duration::Height(height - env.block.height))
+                    }
+                    Expiration::AtTime(time) => {
+                        Some(Duration::Time(time.seconds() - env.block.time.seconds()))
+                    }
+                    Expiration::Never {} => None,
+                }),
+            })
+        }
+        QueryMsg::ListRoleMembers {
+            role,
+            start_after,
+            limit,
+        } => {
+            let start_after = start_after
+                .map(|address| deps.api.addr_validate(&address))
+                .transpose()?;
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+            to_binary(&ListRoleMembersResponse {
+                members: ROLES
+                    .prefix(role.as_str())
+                    .keys(
+                        deps.storage,
+                        start_after.map(Bound::exclusive),
+                        None,
+                        Order::Ascending,
+                    )
+                    .take(limit)
+                    .collect::<StdResult<Vec<_>>>()?,
+            })
+        }
+        QueryMsg::HasRole { role, address } => {
+            let addr = deps.api.addr_validate(&address)?;
+
+            to_binary(&HasRoleResponse {
+                has_role: ROLES.has(deps.storage, (role.as_str(), addr)),
+            })
+        }
+        QueryMsg::ListPendingAssignments { start_after, limit } => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+            to_binary(&ListPendingAssignmentsResponse {
+                assignments: PENDING_ASSIGNMENTS
+                    .range(
+                        deps.storage,
+                        start_after.map(Bound::exclusive),
+                        None,
+                        Order::Ascending,
+                    )
+                    .take(limit)
+                    .map(|item| {
+                        item.map(|(checkmark_id, pending)| PendingAssignmentEntry {
+                            checkmark_id,
+                            pending,
+                        })
+                    })
+                    .collect::<StdResult<Vec<_>>>()?,
+            })
+        }
+        QueryMsg::TimelockedAction { id } => to_binary(&TimelockedActionResponse {
+            action: TIMELOCKED_ACTIONS.may_load(deps.storage, id)?,
+        }),
+        QueryMsg::ListTimelockedActions { start_after, limit } => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+            to_binary(&ListTimelockedActionsResponse {
+                actions: TIMELOCKED_ACTIONS
+                    .range(
+                        deps.storage,
+                        start_after.map(Bound::exclusive),
+                        None,
+                        Order::Ascending,
+                    )
+                    .take(limit)
+                    .map(|item| item.map(|(id, action)| TimelockedActionEntry { id, action }))
+                    .collect::<StdResult<Vec<_>>>()?,
+            })
+        }
+        QueryMsg::Config {} => to_binary(&CONFIG.may_load(deps.storage)?.unwrap_or_default()),
 
         QueryMsg::Ownership {} => to_binary(&cw_ownable::get_ownership(deps.storage)?),
     }
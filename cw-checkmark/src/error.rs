@@ -1,5 +1,6 @@
 use cosmwasm_std::StdError;
 use cw_ownable::OwnershipError;
+use cw_utils::PaymentError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -10,18 +11,210 @@ pub enum ContractError {
     #[error(transparent)]
     Ownable(#[from] OwnershipError),
 
+    #[error(transparent)]
+    Payment(#[from] PaymentError),
+
     #[error("Unauthorized")]
     Unauthorized,
 
+    #[error("contract is paused")]
+    Paused,
+
     #[error("no checkmark assigned")]
     NoCheckmark,
 
     #[error("checkmark_id is banned")]
     CheckmarkBanned,
 
+    #[error("checkmark_id was already issued and cannot be reused")]
+    CheckmarkIdReused,
+
+    #[error("contract addresses cannot be assigned individual checkmarks")]
+    ContractAddress,
+
+    #[error("organization checkmarks can only be assigned to contracts")]
+    NotContract,
+
+    #[error("verification fee of one of {fees} required")]
+    IncorrectFee { fees: String },
+
+    #[error("verification fee already paid by the recipient")]
+    FeeAlreadyPaid,
+
+    #[error("verification already requested")]
+    AlreadyRequested,
+
+    #[error("no pending verification request")]
+    NoVerificationRequest,
+
+    #[error("refund exceeds the escrowed fee")]
+    InvalidRefund,
+
+    #[error("session_commitment must be a hex-encoded SHA-256 hash")]
+    InvalidSessionCommitment,
+
+    #[error("no fees collected in this denom")]
+    NoFeeBalance,
+
+    #[error("no fee split configured")]
+    NoFeeSplit,
+
+    #[error("fee split shares exceed one")]
+    InvalidFeeSplit,
+
+    #[error("address is banned")]
+    AddressBanned,
+
+    #[error("exactly one of address or checkmark_id must be set")]
+    InvalidTarget,
+
+    #[error("checkmark is not suspended")]
+    NotSuspended,
+
     #[error("checkmark_id already assigned")]
     AlreadyAssigned,
 
     #[error("address already has a checkmark")]
     AlreadyHasCheckmark,
+
+    #[error("invalid EVM address")]
+    InvalidEvmAddress,
+
+    #[error("invalid signature")]
+    InvalidSignature,
+
+    #[error("EVM address already linked to another address")]
+    EvmAddressAlreadyLinked,
+
+    #[error("invalid secp256k1 public key")]
+    InvalidPubkey,
+
+    #[error("public key does not match sender")]
+    PubkeyMismatch,
+
+    #[error("public key already registered by another address")]
+    PubkeyAlreadyRegistered,
+
+    #[error("no pending secondary address proposal")]
+    NoPendingSecondaryAddress,
+
+    #[error("address is not a secondary address")]
+    NotSecondaryAddress,
+
+    #[error("cannot link more than {max} secondary addresses")]
+    TooManySecondaryAddresses { max: u32 },
+
+    #[error("delegate must not have a checkmark or another delegation")]
+    InvalidDelegate,
+
+    #[error("no delegation")]
+    NoDelegation,
+
+    #[error("address is a delegate and already votes for a checkmark holder")]
+    AddressIsDelegate,
+
+    #[error("guardians must be unique, exclude the holder, and reach a nonzero threshold")]
+    InvalidGuardians,
+
+    #[error("no pending recovery")]
+    NoPendingRecovery,
+
+    #[error("a recovery to a different address was already approved")]
+    RecoveryMismatch,
+
+    #[error("already approved")]
+    AlreadyApproved,
+
+    #[error("recovery not approved or delay not passed")]
+    RecoveryNotReady,
+
+    #[error("assignments require approval by multiple assigners")]
+    ApprovalRequired,
+
+    #[error("an assignment of this checkmark_id to a different address is pending")]
+    AssignmentMismatch,
+
+    #[error("threshold must be at least 1")]
+    InvalidThreshold,
+
+    #[error("revocation already scheduled")]
+    RevocationAlreadyScheduled,
+
+    #[error("no scheduled revocation")]
+    NoScheduledRevocation,
+
+    #[error("challenge period has ended")]
+    ChallengePeriodEnded,
+
+    #[error("challenge period has not ended")]
+    ChallengePeriodNotEnded,
+
+    #[error("evidence_hash must be a hex-encoded SHA-256 hash")]
+    InvalidEvidenceHash,
+
+    #[error("revocation already appealed")]
+    AlreadyAppealed,
+
+    #[error("no pending appeal")]
+    NoAppeal,
+
+    #[error("appeal pending")]
+    AppealPending,
+
+    #[error("report bond of {bond} required")]
+    IncorrectBond { bond: String },
+
+    #[error("no report with this ID")]
+    NoReport,
+
+    #[error("report already resolved")]
+    ReportResolved,
+
+    #[error("reports cannot be resolved as open")]
+    InvalidResolution,
+
+    #[error("from_height must not exceed to_height")]
+    InvalidHeightRange,
+
+    #[error("deletion already scheduled")]
+    DeletionAlreadyScheduled,
+
+    #[error("no scheduled deletion")]
+    NoScheduledDeletion,
+
+    #[error("deletion delay has not passed")]
+    DeletionNotReady,
+
+    #[error("address cannot be assigned a checkmark until {ends}")]
+    ReassignCooldown { ends: String },
+
+    #[error("checkmark_id or address already queued in the mixing epoch")]
+    AlreadyQueued,
+
+    #[error("mixing epoch has not ended")]
+    EpochNotEnded,
+
+    #[error("checkmarks cannot be reassigned while mixing mode is enabled")]
+    MixingEnabled,
+
+    #[error("address has opted out of assignments")]
+    OptedOut,
+
+    #[error("checkmark_id already offered to another address")]
+    CheckmarkOffered,
+
+    #[error("address already has a pending offer")]
+    OfferPending,
+
+    #[error("no checkmark offered")]
+    NoOffer,
+
+    #[error("offer expired")]
+    OfferExpired,
+
+    #[error("no timelocked action with this ID")]
+    NoTimelockedAction,
+
+    #[error("timelock has not expired")]
+    TimelockNotExpired,
 }
@@ -0,0 +1,55 @@
+use cosmwasm_std::{Addr, Api};
+use sha3::{Digest, Keccak256};
+
+use crate::error::ContractError;
+
+/// Returns the message a checkmark holder must sign with `personal_sign` to
+/// link an EVM address to their checkmark.
+pub fn link_message(contract: &Addr, holder: &Addr) -> String {
+    format!("Link EVM address to checkmark held by {holder} in contract {contract}")
+}
+
+/// Validates a `0x`-prefixed hex EVM address and returns it lowercased.
+pub fn validate_address(eth_address: &str) -> Result<String, ContractError> {
+    let hex_address = eth_address
+        .strip_prefix("0x")
+        .ok_or(ContractError::InvalidEvmAddress)?;
+    if hex_address.len() != 40 || hex::decode(hex_address).is_err() {
+        return Err(ContractError::InvalidEvmAddress);
+    }
+
+    Ok(format!("0x{}", hex_address.to_lowercase()))
+}
+
+/// Recovers the lowercased `0x`-prefixed EVM address that produced the
+/// 65-byte `personal_sign` signature over the message.
+pub fn recover_address(
+    api: &dyn Api,
+    message: &str,
+    signature: &[u8],
+) -> Result<String, ContractError> {
+    if signature.len() != 65 {
+        return Err(ContractError::InvalidSignature);
+    }
+
+    // personal_sign uses v = 27 or 28, but some wallets use 0 or 1.
+    let recovery_param = match signature[64] {
+        0 | 27 => 0,
+        1 | 28 => 1,
+        _ => return Err(ContractError::InvalidSignature),
+    };
+
+    let message_hash = Keccak256::new()
+        .chain_update(format!("\x19Ethereum Signed Message:\n{}", message.len()))
+        .chain_update(message)
+        .finalize();
+
+    let pubkey = api
+        .secp256k1_recover_pubkey(&message_hash, &signature[..64], recovery_param)
+        .map_err(|_| ContractError::InvalidSignature)?;
+
+    // The address is the last 20 bytes of the hash of the uncompressed public
+    // key without its 0x04 prefix.
+    let pubkey_hash = Keccak256::digest(&pubkey[1..]);
+    Ok(format!("0x{}", hex::encode(&pubkey_hash[12..])))
+}
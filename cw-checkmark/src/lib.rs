@@ -1,7 +1,9 @@
 pub mod contract;
-mod tests;
 mod error;
+mod evm;
 pub mod msg;
+mod pubkey;
 pub mod state;
+mod tests;
 
 pub use crate::error::ContractError;
@@ -1,6 +1,14 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Binary, Coin, Uint128};
+use cw20::{Cw20ReceiveMsg, Denom};
 use cw_ownable::{cw_ownable_execute, cw_ownable_query};
+use cw_utils::{Duration, Expiration};
+
+use crate::state::{
+    Ban, BanReason, Config, Fee, FeeSplit, GuardianChange, Offer, PendingAssignment, Recovery,
+    Report, ReportStatus, Role, ScheduledRevocation, Suspension, TimelockedAction,
+    VerificationRequest,
+};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -12,39 +20,407 @@ pub struct InstantiateMsg {
 
 #[cw_ownable_execute]
 #[cw_serde]
+#[allow(clippy::large_enum_variant)]
 pub enum ExecuteMsg {
-    /// Assign a checmark; this can only be called by the owner or assigner.
+    /// Assign a checmark; this can only be called by the owner, assigner, or
+    /// assigner role. If multiple assigner approvals are required, only the
+    /// owner can call this. In mixing mode, queues the assignment until the
+    /// end of the epoch. If fees are configured, one must be sent unless the
+    /// recipient requested verification.
     Assign {
         checkmark_id: String,
         address: String,
     },
 
+    /// Pays a verification fee with cw20 tokens.
+    Receive(Cw20ReceiveMsg),
+
+    /// Requests verification of the sender, committing to the off-chain
+    /// session and paying any configured fee into escrow. The fee is collected
+    /// when the sender is assigned a checkmark, instead of from the assigner.
+    RequestVerification { session_commitment: String },
+
+    /// Rejects the address's pending verification request with a reason
+    /// code, refunding the given amount of its fee, or all of it if omitted.
+    /// The rest of the fee is collected. This can only be called by the owner,
+    /// assigner, or assigner role.
+    RejectVerification {
+        address: String,
+        reason_code: u32,
+        refund: Option<Uint128>,
+    },
+
+    /// Cancels the sender's pending verification request, refunding its fee.
+    /// Requests for which a checkmark was offered or queued cannot be
+    /// cancelled.
+    CancelVerification {},
+
+    /// Sends the collected fees of the denom to the fee split recipients. Only
+    /// the owner can call this.
+    WithdrawFees { denom: Denom },
+
+    /// Approves assigning a checkmark; this can only be called by the assigner
+    /// or assigner role. The checkmark is assigned once the configured number
+    /// of distinct assigners approve the same address before the proposal
+    /// expires.
+    ApproveAssign {
+        checkmark_id: String,
+        address: String,
+    },
+
+    /// Assigns an organization checkmark to a verified DAO or other contract,
+    /// erroring if the address is not a contract. These are reported
+    /// distinctly and cannot vote. This can only be called by the owner,
+    /// assigner, or assigner role. If multiple assigner approvals are
+    /// required, only the owner can call this. If fees are configured, one
+    /// must be sent unless the recipient requested verification.
+    AssignOrganization {
+        checkmark_id: String,
+        address: String,
+    },
+
+    /// Moves an assigned checkmark ID to a different address, correcting a
+    /// mistaken assignment in one step. The old holder's scheduled deletion
+    /// and any revocation scheduled against it are cancelled. If consent is
+    /// required, the checkmark is offered to the address instead and moved
+    /// once accepted. Errors in mixing mode. This can only be called by the
+    /// owner, assigner, or assigner role. If multiple assigner approvals are
+    /// required, only the owner can call this.
+    ReassignCheckmark {
+        checkmark_id: String,
+        address: String,
+    },
+
+    /// Replaces the checkmark ID assigned to the address with a fresh one,
+    /// keeping its verified status and voting power. The old ID is tombstoned.
+    /// The issuance is kept unless `reissue` is true, which records the sender
+    /// as the issuer, such as when correcting a mistaken assignment. This can
+    /// only be called by the owner, assigner, or assigner role. If multiple
+    /// assigner approvals are required, only the owner can call this.
+    RotateCheckmark {
+        address: String,
+        new_checkmark_id: String,
+        reissue: Option<bool>,
+    },
+
+    /// Applies up to `limit` of the assignments and deletions queued in the
+    /// mixing epoch in a shuffled order, once it has ended, and starts the
+    /// next epoch. Call repeatedly until the response reports none remaining.
+    /// Anyone can call this, so the order is only as unpredictable as the
+    /// block of the first call; see the README.
+    ProcessEpoch { limit: Option<u32> },
+
+    /// Removes expired pending assignments. Anyone can call this.
+    PrunePendingAssignments { limit: Option<u32> },
+
     /// Deletes the checkmark assigned to the sender, if any. Errors if no
-    /// checkmark assigned.
+    /// checkmark assigned. If a deletion delay is configured, schedules the
+    /// deletion instead. In mixing mode, queues the deletion until the end of
+    /// the epoch.
     Delete {},
 
-    /// Deletes the checkmark. Only the owner can call this.
-    RevokeCheckmark { checkmark_id: String },
+    /// Accepts the checkmark offered to the sender, binding it to their
+    /// address.
+    AcceptCheckmark {},
 
-    /// Deletes the checkmark assigned to the address, if any. Only the owner
-    /// can call this.
-    RevokeAddress { address: String },
+    /// Rejects the checkmark offered to the sender.
+    RejectCheckmark {},
+
+    /// Refuses all future assignments to the sender.
+    OptOut {},
+
+    /// Allows assignments to the sender again after opting out.
+    OptIn {},
+
+    /// Cancels the sender's scheduled deletion.
+    CancelDelete {},
+
+    /// Deletes the address's checkmark once its scheduled deletion delay has
+    /// passed. Anyone can call this.
+    FinalizeDelete { address: String },
+
+    /// Deletes the checkmark. If a revocation challenge period is configured,
+    /// schedules the revocation instead. Only the owner or revoker role can
+    /// call this.
+    RevokeCheckmark {
+        checkmark_id: String,
+        reason: Option<String>,
+    },
 
-    /// Update whether checkmark IDs are banned or not.
+    /// Deletes the checkmark assigned to the address, if any. If a revocation
+    /// challenge period is configured, schedules the revocation instead. Only
+    /// the owner or revoker role can call this.
+    RevokeAddress {
+        address: String,
+        reason: Option<String>,
+    },
+
+    /// Appeals the scheduled revocation of the sender's checkmark during its
+    /// challenge period, with the hex-encoded SHA-256 hash of evidence shared
+    /// with the owner. Blocks the revocation until the owner resolves it.
+    Appeal { evidence_hash: String },
+
+    /// Resolves an appeal. Upholding it cancels the revocation, and dismissing
+    /// it lets the revocation be finalized. Only the owner can call this.
+    ResolveAppeal { checkmark_id: String, uphold: bool },
+
+    /// Reports a checkmark holder for fraud, such as verifying more than once.
+    /// Must be sent with the configured report bond, if any. Anyone can call
+    /// this.
+    Report {
+        address: String,
+        reason: String,
+        evidence_hash: String,
+    },
+
+    /// Resolves an open report as upheld, rejected, or slashed. Upholding a
+    /// report revokes the target. Only the owner or moderator role can call
+    /// this.
+    ResolveReport { id: u64, status: ReportStatus },
+
+    /// Adds the sent funds to the report pool, which pays report rewards.
+    /// Anyone can call this.
+    FundReportPool {},
+
+    /// Revokes up to `limit` checkmarks assigned by the assigner between the
+    /// heights, inclusive, or schedules their revocation if a challenge period
+    /// is configured. Also withdraws the assigner's pending offers and queued
+    /// assignments in the range, and its approvals of pending assignments,
+    /// which count toward the limit. Call repeatedly until the response
+    /// reports none remaining. Only the owner can call this.
+    RevokeIssuedBy {
+        assigner: String,
+        from_height: u64,
+        to_height: u64,
+        limit: Option<u32>,
+    },
+
+    /// Revokes a checkmark whose challenge period has ended without a pending
+    /// or upheld appeal. Anyone can call this.
+    FinalizeRevocation { checkmark_id: String },
+
+    /// Marks a checkmark inactive without revoking it, identified by exactly
+    /// one of address or checkmark ID, until unsuspended or the optional
+    /// expiration. Only the owner or revoker role can call this.
+    Suspend {
+        address: Option<String>,
+        checkmark_id: Option<String>,
+        reason: String,
+        until: Option<Expiration>,
+    },
+
+    /// Lifts a suspension, identified by exactly one of address or checkmark
+    /// ID. Only the owner or revoker role can call this.
+    Unsuspend {
+        address: Option<String>,
+        checkmark_id: Option<String>,
+    },
+
+    /// Update whether checkmark IDs are banned or not. Bans record the reason
+    /// (defaulting to other) and an optional note, and lift automatically at
+    /// the expiration, if set. Only the owner or ban manager role can call
+    /// this.
     UpdateCheckmarkBan {
         ban_ids: Option<Vec<String>>,
         unban_ids: Option<Vec<String>>,
+        reason: Option<BanReason>,
+        note: Option<String>,
+        expiration: Option<Expiration>,
+    },
+
+    /// Update whether addresses are banned or not. Banning an address revokes
+    /// its checkmark, unlinks it if it is a secondary address, and prevents it
+    /// from being assigned one. Only the owner or ban manager role can call
+    /// this.
+    UpdateAddressBan {
+        ban_addresses: Option<Vec<String>>,
+        unban_addresses: Option<Vec<String>>,
+        reason: Option<BanReason>,
+        note: Option<String>,
+        expiration: Option<Expiration>,
     },
 
     /// Update assigner. Only the owner can call this.
     UpdateAssigner { assigner: String },
+
+    /// Executes a queued owner action once its timelock has expired. Anyone
+    /// can call this.
+    ExecuteTimelocked { id: u64 },
+
+    /// Cancels a queued owner action. Only the owner or guardian role can call
+    /// this.
+    CancelTimelocked { id: u64 },
+
+    /// Links an EVM address to the checkmark assigned to the sender, replacing
+    /// any previously linked EVM address. The signature must be a 65-byte
+    /// `personal_sign` signature from the EVM address over the message "Link
+    /// EVM address to checkmark held by {sender} in contract {contract}".
+    LinkEvmAddress {
+        eth_address: String,
+        signature: Binary,
+    },
+
+    /// Registers the secp256k1 public key of the sender, which must have a
+    /// checkmark, so that addresses derived from it on other chains resolve to
+    /// the sender's checkmark. An uncompressed public key also resolves
+    /// Ethereum-style derived addresses. Replaces any previously registered
+    /// public key.
+    RegisterPubkey { pubkey: Binary },
+
+    /// Proposes linking the address as a secondary address of the sender's
+    /// checkmark. The address must accept before it is linked.
+    AddSecondaryAddress { address: String },
+
+    /// Accepts a proposal from the primary address to link the sender as a
+    /// secondary address of its checkmark. Errors if the primary has reached
+    /// the maximum number of secondary addresses.
+    AcceptSecondaryAddress { primary: String },
+
+    /// Unlinks a secondary address. Can be called by the primary address or by
+    /// the secondary address itself.
+    RemoveSecondaryAddress { address: String },
+
+    /// Delegates the vote of the sender's checkmark to another address, such as
+    /// a smart contract wallet, replacing any previous delegation. The delegate
+    /// must not have a checkmark or already be a delegate.
+    Delegate { to: String },
+
+    /// Removes the sender's delegation. Errors if not delegated.
+    Undelegate {},
+
+    /// Sets the guardians that can recover the sender's checkmark and how many
+    /// must approve. An empty list removes the guardians. If the sender already
+    /// has guardians, the change takes effect after the recovery delay, and
+    /// recoveries the previous guardians have not approved are then cancelled.
+    /// Setting the current guardians again cancels a pending change.
+    SetGuardians {
+        guardians: Vec<String>,
+        threshold: u32,
+    },
+
+    /// Approves moving the holder's checkmark to the new address, replacing
+    /// the guardian's approval of any other address. Only the holder's
+    /// guardians can call this. Once an address reaches the threshold, the
+    /// recovery to it can be executed after the configured delay.
+    ApproveRecovery { holder: String, new_address: String },
+
+    /// Cancels the pending recovery of the holder's checkmark, defaulting to
+    /// the sender. Only the owner or guardian role can cancel another holder's
+    /// recovery.
+    CancelRecovery { holder: Option<String> },
+
+    /// Moves the holder's checkmark to the new address of its approved
+    /// recovery once the delay has passed. Anyone can call this.
+    ExecuteRecovery { holder: String },
+
+    /// Update config. Only the owner or config admin role can call this.
+    UpdateConfig(UpdateConfigMsg),
+
+    /// Adds and removes contract addresses that can be assigned individual
+    /// checkmarks when contracts are rejected. Only the owner or config admin
+    /// role can call this.
+    UpdateAllowedContracts {
+        add: Option<Vec<String>>,
+        remove: Option<Vec<String>>,
+    },
+
+    /// Grants the role to the address. Only the owner can call this.
+    GrantRole { role: Role, address: String },
+
+    /// Revokes the role from the address. Only the owner can call this.
+    RevokeRole { role: Role, address: String },
+}
+
+/// Messages chain governance can send to intervene in the contract, such as
+/// when the owner key is lost.
+#[cw_serde]
+pub enum SudoMsg {
+    /// Replaces the owner, cancelling any pending ownership transfer. No
+    /// owner renounces ownership.
+    UpdateOwner { owner: Option<String> },
+    /// Replaces the assigner.
+    UpdateAssigner { assigner: String },
+    /// Pauses or unpauses the contract. While paused, all execute messages are
+    /// rejected.
+    SetPaused { paused: bool },
+    /// Revokes a checkmark by ID, bypassing any timelock.
+    RevokeCheckmark { checkmark_id: String },
+    /// Revokes the checkmark held by an address, bypassing any timelock.
+    RevokeAddress { address: String },
+    /// Bans and unbans checkmark IDs, bypassing any timelock.
+    UpdateCheckmarkBan {
+        ban_ids: Option<Vec<String>>,
+        unban_ids: Option<Vec<String>>,
+        reason: Option<BanReason>,
+        note: Option<String>,
+        expiration: Option<Expiration>,
+    },
+    /// Bans and unbans addresses, bypassing any timelock.
+    UpdateAddressBan {
+        ban_addresses: Option<Vec<String>>,
+        unban_addresses: Option<Vec<String>>,
+        reason: Option<BanReason>,
+        note: Option<String>,
+        expiration: Option<Expiration>,
+    },
+}
+
+/// Config fields to update. Fields that are not set are left unchanged.
+#[cw_serde]
+#[derive(Default)]
+pub struct UpdateConfigMsg {
+    pub max_secondary_addresses: Option<u32>,
+    pub recovery_delay: Option<Duration>,
+    pub assign_threshold: Option<u32>,
+    pub assign_approval_timeout: Option<Duration>,
+    /// A zero duration disables the timelock.
+    pub timelock_delay: Option<Duration>,
+    /// A zero duration makes revocations immediate.
+    pub revocation_challenge_period: Option<Duration>,
+    /// A zero amount removes the bond.
+    pub report_bond: Option<Coin>,
+    /// A zero amount removes the reward.
+    pub report_reward: Option<Coin>,
+    /// Zero amounts are ignored. An empty list makes assignments free.
+    pub fees: Option<Vec<Fee>>,
+    pub fee_split: Option<FeeSplit>,
+    /// A zero duration makes deletions immediate.
+    pub deletion_delay: Option<Duration>,
+    pub require_consent: Option<bool>,
+    pub offer_timeout: Option<Duration>,
+    /// A zero duration removes the cooldown.
+    pub reassign_cooldown: Option<Duration>,
+    /// A zero duration disables mixing mode. Operations already queued are
+    /// still applied when the current epoch ends.
+    pub mixing_epoch: Option<Duration>,
+    pub prevent_id_reuse: Option<bool>,
+    pub reject_contracts: Option<bool>,
+}
+
+/// Messages sent with cw20 tokens paying a verification fee.
+#[cw_serde]
+pub enum ReceiveMsg {
+    Assign {
+        checkmark_id: String,
+        address: String,
+    },
+    RequestVerification {
+        session_commitment: String,
+    },
+    AssignOrganization {
+        checkmark_id: String,
+        address: String,
+    },
 }
 
 #[cw_ownable_query]
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
-    /// Returns the checkmark ID assigned to the address, if any.
+    /// Returns the checkmark ID assigned to the address, or to its primary
+    /// address if it is a secondary address, if any. The checkmark ID is
+    /// omitted while the checkmark is suspended, and the suspension is returned
+    /// instead. This applies to all `GetCheckmark*` queries.
     #[returns(GetCheckmarkResponse)]
     GetCheckmark { address: String },
 
@@ -52,23 +428,238 @@ pub enum QueryMsg {
     #[returns(GetAddressResponse)]
     GetAddress { checkmark_id: String },
 
-    /// Returns the count of checkmarks assigned.
+    /// Returns the checkmark ID assigned to the address the EVM address is
+    /// linked to, if any.
+    #[returns(GetCheckmarkResponse)]
+    GetCheckmarkByEvmAddress { eth_address: String },
+
+    /// Returns the checkmark ID assigned to the address with the canonical
+    /// address bytes, regardless of bech32 prefix, if any.
+    #[returns(GetCheckmarkResponse)]
+    GetCheckmarkByCanonicalAddress { canonical_address: Binary },
+
+    /// Returns the checkmark ID assigned to an address derived from the
+    /// secp256k1 public key, if any.
+    #[returns(GetCheckmarkResponse)]
+    GetCheckmarkByPubkey { pubkey: Binary },
+
+    /// Returns the count of checkmarks assigned, excluding organizations.
     #[returns(CountResponse)]
     Count {},
 
-    /// Returns whether the checkmark ID is banned.
+    /// Returns whether the checkmark ID was ever issued, even if it has since
+    /// been deleted or revoked.
+    #[returns(CheckmarkIssuedResponse)]
+    CheckmarkIssued { checkmark_id: String },
+
+    /// Returns whether the checkmark ID is banned, and the ban if so.
     #[returns(CheckmarkBannedResponse)]
     CheckmarkBanned { checkmark_id: String },
 
+    /// Returns the checkmark IDs currently banned, ordered by ID.
+    #[returns(ListBannedCheckmarksResponse)]
+    ListBannedCheckmarks {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Returns whether the address is banned, and the ban if so.
+    #[returns(AddressBannedResponse)]
+    AddressBanned { address: String },
+
+    /// Returns pending verification requests, ordered by address.
+    #[returns(ListVerificationRequestsResponse)]
+    ListPendingVerificationRequests {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the collected fees awaiting withdrawal.
+    #[returns(FeeBalancesResponse)]
+    FeeBalances {},
+
+    /// Returns the address's latest verification request and its status, if
+    /// any.
+    #[returns(VerificationRequestResponse)]
+    VerificationRequest { address: String },
+
+    /// Returns the contract addresses allowed individual checkmarks, ordered by
+    /// address.
+    #[returns(ListAllowedContractsResponse)]
+    ListAllowedContracts {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Returns organization checkmarks, ordered by checkmark ID.
+    #[returns(ListOrganizationsResponse)]
+    ListOrganizations {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the addresses currently banned, ordered by address.
+    #[returns(ListBannedAddressesResponse)]
+    ListBannedAddresses {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
     /// Returns the assigner.
     #[returns(AssignerResponse)]
     Assigner {},
+
+    /// Returns whether governance has paused the contract.
+    #[returns(PausedResponse)]
+    Paused {},
+
+    /// Returns how many operations are queued in the mixing epoch and when it
+    /// ends.
+    #[returns(MixingEpochResponse)]
+    MixingEpoch {},
+
+    /// Returns the secondary addresses linked to the primary address.
+    #[returns(SecondaryAddressesResponse)]
+    SecondaryAddresses { address: String },
+
+    /// Returns the address the checkmark holder delegated to, if any.
+    #[returns(GetDelegationResponse)]
+    GetDelegation { address: String },
+
+    /// Returns count of checkmarks assigned and not suspended, excluding
+    /// organizations.
+    #[returns(CountResponse)]
+    ActiveCount {},
+
+    /// Returns the verified principal whose vote the address casts, if any.
+    /// Suspended checkmarks cast no vote.
+    #[returns(ResolveVoterResponse)]
+    ResolveVoter { address: String },
+
+    /// Returns the guardians of the checkmark holder.
+    #[returns(GuardiansResponse)]
+    Guardians { address: String },
+
+    /// Returns the pending recovery of the checkmark holder, if any.
+    #[returns(PendingRecoveryResponse)]
+    PendingRecovery { address: String },
+
+    /// Returns pending recoveries, ordered by holder address.
+    #[returns(ListPendingRecoveriesResponse)]
+    ListPendingRecoveries {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the scheduled revocation of the checkmark ID, if any.
+    #[returns(ScheduledRevocationResponse)]
+    ScheduledRevocation { checkmark_id: String },
+
+    /// Returns scheduled revocations, ordered by checkmark ID.
+    #[returns(ListScheduledRevocationsResponse)]
+    ListScheduledRevocations {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the report, if any.
+    #[returns(ReportResponse)]
+    Report { id: u64 },
+
+    /// Returns reports with the status, ordered by ID.
+    #[returns(ListReportsResponse)]
+    ListReportsByStatus {
+        status: ReportStatus,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    /// Returns reports against the address, ordered by ID.
+    #[returns(ListReportsResponse)]
+    ListReportsByTarget {
+        address: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the funds available to pay report rewards.
+    #[returns(ReportPoolResponse)]
+    ReportPool {},
+
+    /// Returns the checkmarks assigned by the assigner between the heights,
+    /// inclusive, ordered by height. `RevokeIssuedBy` revokes these. Pages
+    /// resume after the height and checkmark ID of the last entry.
+    #[returns(ListIssuedByResponse)]
+    ListIssuedBy {
+        assigner: String,
+        from_height: u64,
+        to_height: u64,
+        start_after: Option<(u64, String)>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the checkmark offered to the address, if any.
+    #[returns(OfferResponse)]
+    Offer { address: String },
+
+    /// Returns whether the address refuses all assignments.
+    #[returns(OptedOutResponse)]
+    OptedOut { address: String },
+
+    /// Returns when the address can be assigned a checkmark again after
+    /// losing one, if it is in cooldown.
+    #[returns(ReassignCooldownResponse)]
+    ReassignCooldown { address: String },
+
+    /// Returns the addresses granted the role.
+    #[returns(ListRoleMembersResponse)]
+    ListRoleMembers {
+        role: Role,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Returns whether the address has been granted the role.
+    #[returns(HasRoleResponse)]
+    HasRole { role: Role, address: String },
+
+    /// Returns assignments awaiting approval, ordered by checkmark ID.
+    /// Includes expired proposals that have not been pruned.
+    #[returns(ListPendingAssignmentsResponse)]
+    ListPendingAssignments {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the queued owner action, if any.
+    #[returns(TimelockedActionResponse)]
+    TimelockedAction { id: u64 },
+
+    /// Returns queued owner actions, ordered by ID.
+    #[returns(ListTimelockedActionsResponse)]
+    ListTimelockedActions {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the config.
+    #[returns(Config)]
+    Config {},
 }
 
-/// Shows the checkmark ID assigned to the address, if any.
+/// Shows the checkmark ID assigned to the address, if any, and whether it is
+/// suspended or pending deletion.
 #[cw_serde]
 pub struct GetCheckmarkResponse {
+    /// The checkmark ID, or `None` if the address has no checkmark or it is
+    /// suspended.
     pub checkmark_id: Option<String>,
+    pub suspension: Option<Suspension>,
+    /// When the holder's scheduled deletion can be finalized, if any.
+    pub pending_deletion: Option<Expiration>,
+    /// Whether the checkmark belongs to an organization rather than an
+    /// individual.
+    pub organization: bool,
 }
 
 /// Shows the address the checkmark ID is assigned to, if any.
@@ -87,6 +678,40 @@ pub struct CountResponse {
 #[cw_serde]
 pub struct CheckmarkBannedResponse {
     pub banned: bool,
+    pub ban: Option<Ban>,
+}
+
+/// Shows a banned checkmark ID and its ban.
+#[cw_serde]
+pub struct BannedCheckmarkEntry {
+    pub checkmark_id: String,
+    pub ban: Ban,
+}
+
+/// Shows a page of banned checkmark IDs.
+#[cw_serde]
+pub struct ListBannedCheckmarksResponse {
+    pub bans: Vec<BannedCheckmarkEntry>,
+}
+
+/// Shows whether the address is banned.
+#[cw_serde]
+pub struct AddressBannedResponse {
+    pub banned: bool,
+    pub ban: Option<Ban>,
+}
+
+/// Shows a banned address and its ban.
+#[cw_serde]
+pub struct BannedAddressEntry {
+    pub address: Addr,
+    pub ban: Ban,
+}
+
+/// Shows a page of banned addresses.
+#[cw_serde]
+pub struct ListBannedAddressesResponse {
+    pub bans: Vec<BannedAddressEntry>,
 }
 
 /// Shows who can assign checkmarks.
@@ -94,3 +719,231 @@ pub struct CheckmarkBannedResponse {
 pub struct AssignerResponse {
     pub assigner: Addr,
 }
+
+/// Shows whether the contract is paused.
+#[cw_serde]
+pub struct PausedResponse {
+    pub paused: bool,
+}
+
+/// Shows the collected fees awaiting withdrawal.
+#[cw_serde]
+pub struct FeeBalancesResponse {
+    pub balances: Vec<Fee>,
+}
+
+/// Shows an address's latest verification request, if any.
+#[cw_serde]
+pub struct VerificationRequestResponse {
+    pub request: Option<VerificationRequest>,
+}
+
+#[cw_serde]
+pub struct VerificationRequestEntry {
+    pub address: Addr,
+    pub request: VerificationRequest,
+}
+
+/// Shows verification requests.
+#[cw_serde]
+pub struct ListVerificationRequestsResponse {
+    pub requests: Vec<VerificationRequestEntry>,
+}
+
+/// Shows contract addresses allowed individual checkmarks.
+#[cw_serde]
+pub struct ListAllowedContractsResponse {
+    pub contracts: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct OrganizationEntry {
+    pub checkmark_id: String,
+    pub address: Addr,
+}
+
+/// Shows organization checkmarks.
+#[cw_serde]
+pub struct ListOrganizationsResponse {
+    pub organizations: Vec<OrganizationEntry>,
+}
+
+/// Shows whether a checkmark ID was ever issued.
+#[cw_serde]
+pub struct CheckmarkIssuedResponse {
+    pub issued: bool,
+}
+
+/// Shows the mixing queue depth and when the current epoch ends, if any.
+#[cw_serde]
+pub struct MixingEpochResponse {
+    pub queued: u64,
+    pub next_epoch: Option<Expiration>,
+    /// Whether an ended epoch is still being processed.
+    pub processing: bool,
+}
+
+/// Shows the secondary addresses linked to a primary address.
+#[cw_serde]
+pub struct SecondaryAddressesResponse {
+    pub addresses: Vec<Addr>,
+}
+
+/// Shows the address a checkmark holder delegated to, if any.
+#[cw_serde]
+pub struct GetDelegationResponse {
+    pub delegate: Option<Addr>,
+}
+
+/// Shows the verified principal whose vote an address casts, and its
+/// checkmark ID, if any.
+#[cw_serde]
+pub struct ResolveVoterResponse {
+    pub principal: Option<Addr>,
+    pub checkmark_id: Option<String>,
+}
+
+/// Shows the guardians of a checkmark holder and how many must approve a
+/// recovery.
+#[cw_serde]
+pub struct GuardiansResponse {
+    pub guardians: Vec<Addr>,
+    pub threshold: u32,
+    /// A change to the guardians that has not taken effect yet.
+    pub pending_change: Option<GuardianChange>,
+}
+
+/// Shows the pending recovery of a checkmark holder, if any.
+#[cw_serde]
+pub struct PendingRecoveryResponse {
+    pub recovery: Option<Recovery>,
+}
+
+#[cw_serde]
+pub struct PendingRecovery {
+    pub holder: Addr,
+    pub recovery: Recovery,
+}
+
+/// Shows pending recoveries.
+#[cw_serde]
+pub struct ListPendingRecoveriesResponse {
+    pub recoveries: Vec<PendingRecovery>,
+}
+
+/// Shows the scheduled revocation of a checkmark ID, if any.
+#[cw_serde]
+pub struct ScheduledRevocationResponse {
+    pub revocation: Option<ScheduledRevocation>,
+}
+
+#[cw_serde]
+pub struct ScheduledRevocationEntry {
+    pub checkmark_id: String,
+    pub revocation: ScheduledRevocation,
+}
+
+/// Shows scheduled revocations.
+#[cw_serde]
+pub struct ListScheduledRevocationsResponse {
+    pub revocations: Vec<ScheduledRevocationEntry>,
+}
+
+/// Shows a report, if any.
+#[cw_serde]
+pub struct ReportResponse {
+    pub report: Option<Report>,
+}
+
+#[cw_serde]
+pub struct ReportEntry {
+    pub id: u64,
+    pub report: Report,
+}
+
+/// Shows a page of reports.
+#[cw_serde]
+pub struct ListReportsResponse {
+    pub reports: Vec<ReportEntry>,
+}
+
+/// Shows the funds available to pay report rewards.
+#[cw_serde]
+pub struct ReportPoolResponse {
+    pub balances: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct IssuedCheckmark {
+    pub checkmark_id: String,
+    pub address: Addr,
+    pub height: u64,
+}
+
+/// Shows a page of checkmarks issued by an assigner.
+#[cw_serde]
+pub struct ListIssuedByResponse {
+    pub checkmarks: Vec<IssuedCheckmark>,
+}
+
+/// Shows the checkmark offered to an address, if any.
+#[cw_serde]
+pub struct OfferResponse {
+    pub offer: Option<Offer>,
+}
+
+/// Shows whether an address refuses all assignments.
+#[cw_serde]
+pub struct OptedOutResponse {
+    pub opted_out: bool,
+}
+
+/// Shows the remaining re-assignment cooldown of an address, if any.
+#[cw_serde]
+pub struct ReassignCooldownResponse {
+    pub ends: Option<Expiration>,
+    /// The blocks or seconds left until the cooldown ends.
+    pub remaining: Option<Duration>,
+}
+
+/// Shows the addresses granted a role.
+#[cw_serde]
+pub struct ListRoleMembersResponse {
+    pub members: Vec<Addr>,
+}
+
+/// Shows whether an address has been granted a role.
+#[cw_serde]
+pub struct HasRoleResponse {
+    pub has_role: bool,
+}
+
+#[cw_serde]
+pub struct PendingAssignmentEntry {
+    pub checkmark_id: String,
+    pub pending: PendingAssignment,
+}
+
+/// Shows assignments awaiting approval.
+#[cw_serde]
+pub struct ListPendingAssignmentsResponse {
+    pub assignments: Vec<PendingAssignmentEntry>,
+}
+
+/// Shows a queued owner action, if any.
+#[cw_serde]
+pub struct TimelockedActionResponse {
+    pub action: Option<TimelockedAction>,
+}
+
+#[cw_serde]
+pub struct TimelockedActionEntry {
+    pub id: u64,
+    pub action: TimelockedAction,
+}
+
+/// Shows queued owner actions.
+#[cw_serde]
+pub struct ListTimelockedActionsResponse {
+    pub actions: Vec<TimelockedActionEntry>,
+}
@@ -0,0 +1,30 @@
+use ripemd::Ripemd160;
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+
+use crate::error::ContractError;
+
+/// Returns the canonical addresses a secp256k1 public key derives on Cosmos
+/// chains. A compressed (33-byte) key derives the standard Cosmos SDK address.
+/// An uncompressed (65-byte) key additionally derives the Ethereum-style
+/// address used by chains such as Injective and Evmos.
+pub fn derive_canonical_addresses(pubkey: &[u8]) -> Result<Vec<Vec<u8>>, ContractError> {
+    match pubkey {
+        [0x02 | 0x03, ..] if pubkey.len() == 33 => Ok(vec![cosmos_address(pubkey)]),
+        [0x04, ..] if pubkey.len() == 65 => {
+            // The parity of y determines the compressed key prefix.
+            let mut compressed = vec![0x02 | (pubkey[64] & 1)];
+            compressed.extend_from_slice(&pubkey[1..33]);
+
+            Ok(vec![
+                cosmos_address(&compressed),
+                Keccak256::digest(&pubkey[1..])[12..].to_vec(),
+            ])
+        }
+        _ => Err(ContractError::InvalidPubkey),
+    }
+}
+
+fn cosmos_address(compressed_pubkey: &[u8]) -> Vec<u8> {
+    Ripemd160::digest(Sha256::digest(compressed_pubkey)).to_vec()
+}
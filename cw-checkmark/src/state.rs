@@ -1,11 +1,596 @@
-use cosmwasm_std::{Addr, Empty};
+use cosmwasm_schema::cw_serde;
+use std::fmt;
+
+use cosmwasm_std::{Addr, Binary, BlockInfo, Coin, Decimal, Empty, Timestamp, Uint128};
+use cw20::Denom;
 
 use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration, DAY, WEEK};
+
+use crate::msg::UpdateConfigMsg;
+
+#[cw_serde]
+#[serde(default)]
+pub struct Config {
+    /// The maximum number of secondary addresses a checkmark holder can link
+    /// to their checkmark.
+    pub max_secondary_addresses: u32,
+    /// How long after guardians approve a recovery before it can be executed,
+    /// during which the holder can cancel it.
+    pub recovery_delay: Duration,
+    /// The number of distinct assigners that must approve an assignment. If
+    /// more than one, assigners must use `ApproveAssign` instead of `Assign`.
+    pub assign_threshold: u32,
+    /// How long assigners have to reach the threshold once an assignment is
+    /// first approved.
+    pub assign_approval_timeout: Duration,
+    /// If set, owner actions such as revocations, bans, and assigner updates
+    /// are queued and can only be executed after this delay.
+    pub timelock_delay: Option<Duration>,
+    /// If set, revocations are scheduled and can only be finalized after this
+    /// period, during which the holder can appeal.
+    pub revocation_challenge_period: Option<Duration>,
+    /// If set, the funds that must be sent with a fraud report, refunded
+    /// unless the report is slashed.
+    pub report_bond: Option<Coin>,
+    /// If set, the funds paid from the report pool to the reporter of an
+    /// upheld fraud report, capped at the pool's balance.
+    pub report_reward: Option<Coin>,
+    /// If set, `Delete` schedules the sender's checkmark for removal after this
+    /// delay, during which they can cancel it.
+    pub deletion_delay: Option<Duration>,
+    /// Whether assignments are offered to the recipient, who must accept them
+    /// before the checkmark is bound to their address.
+    pub require_consent: bool,
+    /// How long recipients have to accept an offered checkmark.
+    pub offer_timeout: Duration,
+    /// If set, how long after an address loses its checkmark by deletion,
+    /// revocation or ban before it can be assigned one again.
+    pub reassign_cooldown: Option<Duration>,
+    /// If set, assignments and self-deletions are queued and applied in a
+    /// shuffled order at the end of each epoch of this length. The queueing
+    /// transactions are still public.
+    pub mixing_epoch: Option<Duration>,
+    /// Whether checkmark IDs that were ever issued are rejected when assigned
+    /// again after being deleted or revoked.
+    pub prevent_id_reuse: bool,
+    /// Whether contract addresses are rejected for individual checkmarks,
+    /// unless allow-listed.
+    pub reject_contracts: bool,
+    /// The verification fees accepted, any one of which must be paid for each
+    /// assignment, either with it or by the recipient requesting verification.
+    /// Empty if assignments are free.
+    pub fees: Vec<Fee>,
+    /// How withdrawn fees are split.
+    pub fee_split: Option<FeeSplit>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_secondary_addresses: 0,
+            recovery_delay: WEEK,
+            assign_threshold: 1,
+            assign_approval_timeout: DAY,
+            timelock_delay: None,
+            revocation_challenge_period: None,
+            report_bond: None,
+            report_reward: None,
+            deletion_delay: None,
+            require_consent: false,
+            offer_timeout: WEEK,
+            reassign_cooldown: None,
+            mixing_epoch: None,
+            prevent_id_reuse: false,
+            reject_contracts: false,
+            fees: vec![],
+            fee_split: None,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct PendingAssignment {
+    /// The address the checkmark will be assigned to.
+    pub address: Addr,
+    /// The assigners that approved the assignment.
+    pub approvals: Vec<Addr>,
+    /// When the proposal expires if the threshold is not reached.
+    pub expiration: Expiration,
+}
+
+/// Owner actions that are queued when the timelock is enabled.
+#[cw_serde]
+#[allow(clippy::large_enum_variant)]
+pub enum TimelockedMsg {
+    RevokeCheckmark {
+        checkmark_id: String,
+        reason: Option<String>,
+    },
+    RevokeAddress {
+        address: String,
+        reason: Option<String>,
+    },
+    UpdateCheckmarkBan {
+        ban_ids: Option<Vec<String>>,
+        unban_ids: Option<Vec<String>>,
+        reason: Option<BanReason>,
+        note: Option<String>,
+        expiration: Option<Expiration>,
+    },
+    UpdateAddressBan {
+        ban_addresses: Option<Vec<String>>,
+        unban_addresses: Option<Vec<String>>,
+        reason: Option<BanReason>,
+        note: Option<String>,
+        expiration: Option<Expiration>,
+    },
+    UpdateAssigner {
+        assigner: String,
+    },
+    /// Queued so that disabling or shortening the timelock itself waits out
+    /// the current delay.
+    UpdateConfig(UpdateConfigMsg),
+    UpdateAllowedContracts {
+        add: Option<Vec<String>>,
+        remove: Option<Vec<String>>,
+    },
+    GrantRole {
+        role: Role,
+        address: String,
+    },
+    RevokeRole {
+        role: Role,
+        address: String,
+    },
+    /// Transferring or renouncing ownership. Accepting a transfer is not
+    /// queued.
+    UpdateOwnership(cw_ownable::Action),
+    RevokeIssuedBy {
+        assigner: String,
+        from_height: u64,
+        to_height: u64,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct TimelockedAction {
+    pub msg: TimelockedMsg,
+    /// The address that queued the action, which must still be authorized
+    /// when it is executed.
+    pub proposer: Addr,
+    /// When the action can be executed.
+    pub execute_after: Expiration,
+}
+
+/// Why a checkmark ID or address was banned.
+#[cw_serde]
+#[derive(Copy)]
+pub enum BanReason {
+    Fraud,
+    DuplicateIdentity,
+    CompromisedAccount,
+    Other,
+}
+
+#[cw_serde]
+pub struct Ban {
+    pub reason: BanReason,
+    pub note: Option<String>,
+    /// The address that issued the ban, or none if chain governance did.
+    pub banned_by: Option<Addr>,
+    /// When the ban lifts, if ever.
+    pub expiration: Option<Expiration>,
+}
+
+impl Ban {
+    /// Returns whether the ban has not yet expired.
+    pub fn is_active(&self, block: &BlockInfo) -> bool {
+        !self
+            .expiration
+            .is_some_and(|expiration| expiration.is_expired(block))
+    }
+}
+
+/// Marks an assigned checkmark inactive without removing it, such as while a
+/// fraud investigation is pending.
+#[cw_serde]
+pub struct Suspension {
+    pub reason: String,
+    pub suspended_by: Addr,
+    /// When the suspension lifts, if ever.
+    pub until: Option<Expiration>,
+}
+
+impl Suspension {
+    /// Returns whether the suspension has not yet expired.
+    pub fn is_active(&self, block: &BlockInfo) -> bool {
+        !self.until.is_some_and(|until| until.is_expired(block))
+    }
+}
+
+/// Who assigned a checkmark and when.
+#[cw_serde]
+pub struct Issuance {
+    /// The owner or assigner that assigned the checkmark, or that gave the
+    /// final approval if multiple were required.
+    pub assigner: Addr,
+    pub height: u64,
+}
+
+/// An amount of native or cw20 tokens paid as a verification fee.
+#[cw_serde]
+pub struct Fee {
+    pub denom: Denom,
+    pub amount: Uint128,
+}
+
+impl fmt::Display for Fee {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.denom {
+            Denom::Native(denom) => write!(f, "{}{}", self.amount, denom),
+            Denom::Cw20(token) => write!(f, "{} {}", self.amount, token),
+        }
+    }
+}
+
+/// The shares of withdrawn fees paid to the verification provider and the
+/// operator. The community pool receives the remainder.
+#[cw_serde]
+pub struct FeeSplit {
+    pub provider: Addr,
+    pub provider_share: Decimal,
+    pub operator: Addr,
+    pub operator_share: Decimal,
+    pub community_pool: Addr,
+}
+
+/// An address's request to be verified, holding its fee in escrow until an
+/// assigner fulfills the request by assigning it a checkmark, or rejects it,
+/// or the address cancels it.
+#[cw_serde]
+pub struct VerificationRequest {
+    /// The hash committing to the verification session off-chain.
+    pub session_commitment: String,
+    /// The fee paid into escrow, if fees were configured.
+    pub fee: Option<Fee>,
+    pub status: VerificationStatus,
+}
+
+#[cw_serde]
+pub enum VerificationStatus {
+    Pending,
+    /// The address was offered a checkmark or one was queued for it. The fee
+    /// is held until it is assigned, and the request is pending again if not.
+    Assigning,
+    /// The address was assigned a checkmark and the fee was collected.
+    Fulfilled,
+    /// The request was rejected and the amount refunded. The rest of the fee
+    /// was collected.
+    Rejected {
+        reason_code: u32,
+        refunded: Uint128,
+    },
+    /// The request was cancelled by the address and the fee refunded.
+    Cancelled,
+}
+
+/// The block at which an address lost its checkmark.
+#[cw_serde]
+pub struct LastLoss {
+    pub height: u64,
+    pub time: Timestamp,
+}
+
+impl LastLoss {
+    /// Returns when the cooldown that started at this loss ends.
+    pub fn cooldown_end(&self, cooldown: Duration) -> Expiration {
+        match cooldown {
+            Duration::Height(height) => Expiration::AtHeight(self.height + height),
+            Duration::Time(time) => Expiration::AtTime(self.time.plus_seconds(time)),
+        }
+    }
+}
+
+/// An assignment or self-deletion queued until the end of the mixing epoch.
+#[cw_serde]
+pub enum MixOp {
+    Assign {
+        checkmark_id: String,
+        address: Addr,
+        issuance: Issuance,
+        /// The fee paid by the assigner, held until the checkmark is assigned
+        /// and refunded if it is not.
+        payment: Option<Fee>,
+    },
+    Delete {
+        checkmark_id: String,
+        address: Addr,
+    },
+}
+
+/// The queue positions of an ended mixing epoch that are being applied, in
+/// batches, in a shuffled order drawn from the seed.
+#[cw_serde]
+pub struct MixBatch {
+    /// The next position to apply.
+    pub next: u64,
+    /// The position after the epoch's last operation.
+    pub end: u64,
+    pub seed: Binary,
+}
+
+/// A checkmark offered to an address, awaiting its consent.
+#[cw_serde]
+pub struct Offer {
+    pub checkmark_id: String,
+    pub issuance: Issuance,
+    pub expiration: Expiration,
+    /// The fee paid by the assigner, held until the offer is accepted and
+    /// refunded if it is not.
+    pub payment: Option<Fee>,
+    /// If reassigning, the address the checkmark is moved from once the offer
+    /// is accepted. It keeps the checkmark until then.
+    pub reassign_from: Option<Addr>,
+}
+
+/// A revocation waiting for its challenge period to end.
+#[cw_serde]
+pub struct ScheduledRevocation {
+    pub reason: Option<String>,
+    pub proposer: Addr,
+    /// When the revocation can be finalized, unless an appeal is pending.
+    pub finalize_after: Expiration,
+    pub appeal: Option<Appeal>,
+}
+
+/// A holder's appeal of a scheduled revocation.
+#[cw_serde]
+pub struct Appeal {
+    /// Hex-encoded SHA-256 hash of evidence shared with the owner off-chain.
+    pub evidence_hash: String,
+    /// Whether the owner dismissed the appeal, letting the revocation proceed.
+    pub dismissed: bool,
+}
+
+#[cw_serde]
+#[derive(Copy)]
+pub enum ReportStatus {
+    Open,
+    /// The target was revoked, and the reporter refunded and rewarded.
+    Upheld,
+    /// The reporter was refunded.
+    Rejected,
+    /// The reporter's bond was kept by the contract.
+    Slashed,
+}
+
+impl ReportStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportStatus::Open => "open",
+            ReportStatus::Upheld => "upheld",
+            ReportStatus::Rejected => "rejected",
+            ReportStatus::Slashed => "slashed",
+        }
+    }
+}
+
+/// A fraud report against a checkmark holder.
+#[cw_serde]
+pub struct Report {
+    pub reporter: Addr,
+    pub target: Addr,
+    pub reason: String,
+    /// Hex-encoded SHA-256 hash of evidence shared with the owner off-chain.
+    pub evidence_hash: String,
+    /// The bond sent by the reporter, if any.
+    pub bond: Option<Coin>,
+    pub status: ReportStatus,
+}
+
+/// Roles the owner can grant to let other addresses perform some owner actions.
+#[cw_serde]
+#[derive(Copy)]
+pub enum Role {
+    /// Can assign checkmarks.
+    Assigner,
+    /// Can revoke checkmarks.
+    Revoker,
+    /// Can ban and unban checkmark IDs.
+    BanManager,
+    /// Can cancel pending recoveries and timelocked actions.
+    Guardian,
+    /// Can update the config.
+    ConfigAdmin,
+    /// Can resolve fraud reports.
+    Moderator,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Assigner => "assigner",
+            Role::Revoker => "revoker",
+            Role::BanManager => "ban_manager",
+            Role::Guardian => "guardian",
+            Role::ConfigAdmin => "config_admin",
+            Role::Moderator => "moderator",
+        }
+    }
+}
+
+#[cw_serde]
+pub struct Guardians {
+    pub guardians: Vec<Addr>,
+    /// The number of guardians that must approve a recovery.
+    pub threshold: u32,
+}
+
+/// A change to a checkmark holder's guardians that takes effect after the
+/// recovery delay, so a compromised key cannot immediately replace the
+/// guardians that would recover from it.
+#[cw_serde]
+pub struct GuardianChange {
+    /// The new guardians. Empty if the guardians are being removed.
+    pub guardians: Vec<Addr>,
+    pub threshold: u32,
+    pub effective_after: Expiration,
+}
+
+#[cw_serde]
+pub struct Recovery {
+    /// The address the checkmark will be moved to. Set once enough guardians
+    /// approve the same address.
+    pub new_address: Option<Addr>,
+    /// The addresses proposed by the guardians. Each guardian approves at most
+    /// one address at a time.
+    pub proposals: Vec<RecoveryProposal>,
+    /// When the recovery can be executed. Set once enough guardians approve.
+    pub executable_after: Option<Expiration>,
+}
+
+#[cw_serde]
+pub struct RecoveryProposal {
+    pub new_address: Addr,
+    /// The guardians that approved moving the checkmark to the address.
+    pub approvals: Vec<Addr>,
+}
 
 pub const ASSIGNER: Item<Addr> = Item::new("assigner");
+/// Whether governance has paused the contract.
+pub const PAUSED: Item<bool> = Item::new("paused");
+/// Addresses granted each role, keyed by role.
+pub const ROLES: Map<(&str, Addr), Empty> = Map::new("roles");
+/// Owner-configurable settings. Defaults apply if never updated.
+pub const CONFIG: Item<Config> = Item::new("config");
 
 pub const CHECKMARKS_TO_ADDRESSES: Map<String, Addr> = Map::new("checkmarks_to_addresses");
 pub const ADDRESSES_TO_CHECKMARKS: Map<Addr, String> = Map::new("addresses_to_checkmarks");
 pub const CHECKMARK_COUNT: Item<u64> = Item::new("checkmark_count");
 
-pub const BANNED_CHECKMARKS: Map<String, Empty> = Map::new("banned_checkmarks");
+pub const BANNED_CHECKMARKS: Map<String, Ban> = Map::new("banned_checkmarks");
+/// Returns the key fee balances of the denom are stored under.
+pub fn denom_key(denom: &Denom) -> String {
+    match denom {
+        Denom::Native(denom) => format!("native:{denom}"),
+        Denom::Cw20(token) => format!("cw20:{token}"),
+    }
+}
+
+/// Collected fees awaiting withdrawal, keyed by denom key.
+pub const FEE_BALANCES: Map<String, Fee> = Map::new("fee_balances");
+/// The latest verification request of each address.
+pub const VERIFICATION_REQUESTS: Map<Addr, VerificationRequest> = Map::new("verification_requests");
+
+/// Contract addresses that can be assigned individual checkmarks even when
+/// contracts are rejected.
+pub const ALLOWED_CONTRACTS: Map<Addr, Empty> = Map::new("allowed_contracts");
+/// Checkmark IDs of organization checkmarks, issued to verified DAOs and
+/// contracts rather than individuals.
+pub const ORGANIZATIONS: Map<String, Empty> = Map::new("organizations");
+/// Number of organization checkmarks, which are left out of the checkmark
+/// counts. Missing if none were ever assigned.
+pub const ORGANIZATION_COUNT: Item<u64> = Item::new("organization_count");
+
+/// Tombstones of every checkmark ID ever issued, kept after the checkmark is
+/// deleted or revoked.
+pub const ISSUED_CHECKMARK_IDS: Map<String, Empty> = Map::new("issued_checkmark_ids");
+
+/// Issuances of assigned checkmarks, keyed by checkmark ID, and indexed by
+/// assigner and height.
+pub const ISSUANCES: Map<String, Issuance> = Map::new("issuances");
+pub const ISSUANCES_BY_ASSIGNER: Map<(Addr, u64, String), Empty> =
+    Map::new("issuances_by_assigner");
+
+/// Revocations in their challenge period, keyed by checkmark ID.
+pub const SCHEDULED_REVOCATIONS: Map<String, ScheduledRevocation> =
+    Map::new("scheduled_revocations");
+
+/// Fraud reports by ID, indexed by status and by target.
+pub const REPORT_COUNT: Item<u64> = Item::new("report_count");
+pub const REPORTS: Map<u64, Report> = Map::new("reports");
+pub const REPORTS_BY_STATUS: Map<(&str, u64), Empty> = Map::new("reports_by_status");
+pub const REPORTS_BY_TARGET: Map<(Addr, u64), Empty> = Map::new("reports_by_target");
+/// Funds that pay report rewards, keyed by denom. Slashed bonds and
+/// contributions are added, and rewards are paid out. The bonds of open
+/// reports are held separately, on the reports themselves.
+pub const REPORT_POOL: Map<&str, Uint128> = Map::new("report_pool");
+
+/// Checkmarks offered to addresses in consent mode, keyed by recipient, and
+/// the recipient of each offered checkmark ID. Offers are indexed by assigner
+/// and height.
+pub const OFFERS: Map<Addr, Offer> = Map::new("offers");
+pub const OFFERED_CHECKMARKS: Map<String, Addr> = Map::new("offered_checkmarks");
+pub const OFFERS_BY_ASSIGNER: Map<(Addr, u64, Addr), Empty> = Map::new("offers_by_assigner");
+/// Addresses that refuse all assignments.
+pub const OPTED_OUT: Map<Addr, Empty> = Map::new("opted_out");
+
+/// When each address last lost its checkmark by deletion, revocation or ban.
+pub const LAST_LOSSES: Map<Addr, LastLoss> = Map::new("last_losses");
+
+/// Queued mixing operations, keyed by queue position, along with how many are
+/// queued, the position of the next one, and the checkmark IDs and addresses
+/// they touch. Queued assignments are indexed by assigner and height.
+pub const MIX_QUEUE: Map<u64, MixOp> = Map::new("mix_queue");
+pub const MIX_QUEUE_BY_ASSIGNER: Map<(Addr, u64, u64), Empty> = Map::new("mix_queue_by_assigner");
+pub const MIX_QUEUE_LEN: Item<u64> = Item::new("mix_queue_len");
+pub const MIX_QUEUE_END: Item<u64> = Item::new("mix_queue_end");
+pub const MIX_QUEUED_CHECKMARKS: Map<String, Empty> = Map::new("mix_queued_checkmarks");
+pub const MIX_QUEUED_ADDRESSES: Map<Addr, Empty> = Map::new("mix_queued_addresses");
+/// When the current mixing epoch ends and its queue can be processed.
+pub const NEXT_EPOCH: Item<Expiration> = Item::new("next_epoch");
+/// The most recently ended mixing epoch, which is processed in batches.
+pub const MIX_BATCH: Item<MixBatch> = Item::new("mix_batch");
+
+/// When scheduled self-deletions can be finalized, keyed by checkmark ID.
+pub const PENDING_DELETIONS: Map<String, Expiration> = Map::new("pending_deletions");
+
+/// Suspensions of assigned checkmarks, keyed by checkmark ID.
+pub const SUSPENSIONS: Map<String, Suspension> = Map::new("suspensions");
+
+/// Addresses banned from being assigned a checkmark.
+pub const BANNED_ADDRESSES: Map<Addr, Ban> = Map::new("banned_addresses");
+
+/// EVM addresses linked by checkmark holders, lowercased with a `0x` prefix.
+pub const EVM_ADDRESSES_TO_ADDRESSES: Map<String, Addr> = Map::new("evm_addresses_to_addresses");
+pub const ADDRESSES_TO_EVM_ADDRESSES: Map<Addr, String> = Map::new("addresses_to_evm_addresses");
+
+/// Public keys registered by checkmark holders, and the canonical addresses
+/// derived from them.
+pub const ADDRESSES_TO_PUBKEYS: Map<Addr, Binary> = Map::new("addresses_to_pubkeys");
+pub const CANONICAL_ADDRESSES_TO_ADDRESSES: Map<&[u8], Addr> =
+    Map::new("canonical_addresses_to_addresses");
+
+/// Secondary addresses mapped to the primary address holding the checkmark.
+pub const SECONDARY_ADDRESSES: Map<Addr, Addr> = Map::new("secondary_addresses");
+/// Secondary addresses keyed by primary address, for listing and cleanup.
+pub const PRIMARY_SECONDARY_ADDRESSES: Map<(Addr, Addr), Empty> =
+    Map::new("primary_secondary_addresses");
+/// Secondary addresses proposed by a primary address that have not accepted.
+pub const PENDING_SECONDARY_ADDRESSES: Map<(Addr, Addr), Empty> =
+    Map::new("pending_secondary_addresses");
+
+/// Checkmark holders mapped to the address they delegated their vote to.
+pub const DELEGATIONS: Map<Addr, Addr> = Map::new("delegations");
+/// Delegates mapped to the checkmark holder that delegated to them.
+pub const DELEGATES: Map<Addr, Addr> = Map::new("delegates");
+
+/// Guardians that can recover a checkmark holder's checkmark.
+pub const GUARDIANS: Map<Addr, Guardians> = Map::new("guardians");
+/// Pending guardian changes keyed by checkmark holder.
+pub const PENDING_GUARDIANS: Map<Addr, GuardianChange> = Map::new("pending_guardians");
+/// Pending recoveries keyed by checkmark holder.
+pub const RECOVERIES: Map<Addr, Recovery> = Map::new("recoveries");
+
+/// Assignments awaiting approval by more assigners, keyed by checkmark ID, and
+/// indexed by the assigners that approved them.
+pub const PENDING_ASSIGNMENTS: Map<String, PendingAssignment> = Map::new("pending_assignments");
+pub const PENDING_ASSIGNMENTS_BY_ASSIGNER: Map<(Addr, String), Empty> =
+    Map::new("pending_assignments_by_assigner");
+
+/// Queued owner actions keyed by ID.
+pub const TIMELOCKED_ACTIONS: Map<u64, TimelockedAction> = Map::new("timelocked_actions");
+/// The ID of the most recently queued owner action.
+pub const TIMELOCKED_ACTION_COUNT: Item<u64> = Item::new("timelocked_action_count");
@@ -1,11 +1,39 @@
 #![cfg(test)]
-use cosmwasm_std::{Addr, Empty};
-use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+use bech32::{FromBase32, ToBase32, Variant};
+use cosmwasm_std::{
+    coin, coins, testing::MockApi, to_binary, Addr, Api, Binary, CanonicalAddr, Decimal, Empty,
+    RecoverPubkeyError, StdResult, Uint128, VerificationError,
+};
+use cw20::{Cw20ReceiveMsg, Denom};
+use cw_multi_test::{App, AppBuilder, Contract, ContractWrapper, Executor};
+use k256::{
+    ecdsa::{recoverable, signature::Signer, SigningKey},
+    elliptic_curve::sec1::ToEncodedPoint,
+};
+use ripemd::Ripemd160;
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
 
 use crate::{
     msg::{
-        AssignerResponse, CheckmarkBannedResponse, CountResponse, ExecuteMsg, GetAddressResponse,
-        GetCheckmarkResponse, InstantiateMsg, QueryMsg,
+        AddressBannedResponse, AssignerResponse, BannedAddressEntry, BannedCheckmarkEntry,
+        CheckmarkBannedResponse, CheckmarkIssuedResponse, CountResponse, ExecuteMsg,
+        FeeBalancesResponse, GetAddressResponse, GetCheckmarkResponse, GetDelegationResponse,
+        GuardiansResponse, HasRoleResponse, InstantiateMsg, IssuedCheckmark,
+        ListAllowedContractsResponse, ListBannedAddressesResponse, ListBannedCheckmarksResponse,
+        ListIssuedByResponse, ListOrganizationsResponse, ListPendingAssignmentsResponse,
+        ListPendingRecoveriesResponse, ListReportsResponse, ListRoleMembersResponse,
+        ListScheduledRevocationsResponse, ListTimelockedActionsResponse,
+        ListVerificationRequestsResponse, MixingEpochResponse, OfferResponse, OptedOutResponse,
+        OrganizationEntry, PausedResponse, PendingRecoveryResponse, QueryMsg,
+        ReassignCooldownResponse, ReceiveMsg, ReportPoolResponse, ReportResponse,
+        ResolveVoterResponse, ScheduledRevocationResponse, SecondaryAddressesResponse, SudoMsg,
+        TimelockedActionResponse, UpdateConfigMsg, VerificationRequestResponse,
+    },
+    state::{
+        Ban, BanReason, Config, Fee, FeeSplit, Issuance, Offer, RecoveryProposal, Report,
+        ReportStatus, Role, ScheduledRevocation, Suspension, VerificationRequest,
+        VerificationStatus,
     },
     ContractError,
 };
@@ -15,13 +43,15 @@ const ASSIGNER: &str = "assigner";
 const USER: &str = "user";
 const ANOTHER_USER: &str = "another_user";
 const CHECKMARK: &str = "checkmark";
+const SESSION_COMMITMENT: &str = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
 
 fn setup_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         crate::contract::execute,
         crate::contract::instantiate,
         crate::contract::query,
-    );
+    )
+    .with_sudo(crate::contract::sudo);
     Box::new(contract)
 }
 
@@ -47,6 +77,98 @@ fn instantiate() -> (App, Addr) {
     (app, addr)
 }
 
+/// Api that uses real bech32 addresses with the `juno` prefix, for tests that
+/// derive addresses from public keys. Falls back to `MockApi` for other
+/// addresses, such as contract addresses.
+struct Bech32Api(MockApi);
+
+impl Bech32Api {
+    fn address(canonical: &[u8]) -> String {
+        bech32::encode("juno", canonical.to_base32(), Variant::Bech32).unwrap()
+    }
+}
+
+impl Api for Bech32Api {
+    fn addr_validate(&self, human: &str) -> StdResult<Addr> {
+        self.addr_humanize(&self.addr_canonicalize(human)?)
+    }
+
+    fn addr_canonicalize(&self, human: &str) -> StdResult<CanonicalAddr> {
+        match bech32::decode(human) {
+            Ok((prefix, data, Variant::Bech32)) if prefix == "juno" => {
+                Ok(Vec::<u8>::from_base32(&data).unwrap().into())
+            }
+            _ => self.0.addr_canonicalize(human),
+        }
+    }
+
+    fn addr_humanize(&self, canonical: &CanonicalAddr) -> StdResult<Addr> {
+        match canonical.len() {
+            20 | 32 => Ok(Addr::unchecked(Self::address(canonical.as_slice()))),
+            _ => self.0.addr_humanize(canonical),
+        }
+    }
+
+    fn secp256k1_verify(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Result<bool, VerificationError> {
+        self.0.secp256k1_verify(message_hash, signature, public_key)
+    }
+
+    fn secp256k1_recover_pubkey(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        recovery_param: u8,
+    ) -> Result<Vec<u8>, RecoverPubkeyError> {
+        self.0
+            .secp256k1_recover_pubkey(message_hash, signature, recovery_param)
+    }
+
+    fn ed25519_verify(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Result<bool, VerificationError> {
+        self.0.ed25519_verify(message, signature, public_key)
+    }
+
+    fn ed25519_batch_verify(
+        &self,
+        messages: &[&[u8]],
+        signatures: &[&[u8]],
+        public_keys: &[&[u8]],
+    ) -> Result<bool, VerificationError> {
+        self.0
+            .ed25519_batch_verify(messages, signatures, public_keys)
+    }
+
+    fn debug(&self, message: &str) {
+        self.0.debug(message)
+    }
+}
+
+/// Signs the message like an EVM wallet's `personal_sign`.
+fn personal_sign(signing_key: &SigningKey, message: &str) -> Binary {
+    let prefixed = format!("\x19Ethereum Signed Message:\n{}{}", message.len(), message);
+    let signature: recoverable::Signature = signing_key.sign(prefixed.as_bytes());
+
+    let mut bytes = signature.as_ref().to_vec();
+    bytes[64] += 27;
+    Binary::from(bytes)
+}
+
+/// Derives the EVM address for the signing key.
+fn evm_address(signing_key: &SigningKey) -> String {
+    let pubkey = signing_key.verifying_key().to_encoded_point(false);
+    let hash = Keccak256::digest(&pubkey.as_bytes()[1..]);
+    format!("0x{}", hex::encode(&hash[12..]))
+}
+
 #[test]
 pub fn test_instantiate() {
     instantiate();
@@ -239,7 +361,15 @@ pub fn test_assign() {
             },
         )
         .unwrap();
-    assert_eq!(res, GetCheckmarkResponse { checkmark_id: None });
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
 
     // Ensure no user for checkmark.
     let res: GetAddressResponse = app
@@ -285,7 +415,10 @@ pub fn test_assign() {
     assert_eq!(
         res,
         GetCheckmarkResponse {
-            checkmark_id: Some(CHECKMARK.to_string())
+            checkmark_id: Some(CHECKMARK.to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: false
         }
     );
 
@@ -341,7 +474,15 @@ pub fn test_assign() {
             },
         )
         .unwrap();
-    assert_eq!(res, GetCheckmarkResponse { checkmark_id: None });
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
 
     // Ensure no user for non_assigner checkmark.
     let res: GetAddressResponse = app
@@ -388,7 +529,15 @@ pub fn test_assign() {
             },
         )
         .unwrap();
-    assert_eq!(res, GetCheckmarkResponse { checkmark_id: None });
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
 
     // Ensure still same user for checkmark.
     let res: GetAddressResponse = app
@@ -444,7 +593,10 @@ pub fn test_assign() {
     assert_eq!(
         res,
         GetCheckmarkResponse {
-            checkmark_id: Some(CHECKMARK.to_string())
+            checkmark_id: Some(CHECKMARK.to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: false
         }
     );
 
@@ -510,7 +662,10 @@ pub fn test_assign_delete_assign() {
     assert_eq!(
         res,
         GetCheckmarkResponse {
-            checkmark_id: Some(CHECKMARK.to_string())
+            checkmark_id: Some(CHECKMARK.to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: false
         }
     );
 
@@ -557,7 +712,15 @@ pub fn test_assign_delete_assign() {
             },
         )
         .unwrap();
-    assert_eq!(res, GetCheckmarkResponse { checkmark_id: None });
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
 
     // Ensure no user for checkmark.
     let res: GetAddressResponse = app
@@ -603,7 +766,10 @@ pub fn test_assign_delete_assign() {
     assert_eq!(
         res,
         GetCheckmarkResponse {
-            checkmark_id: Some(CHECKMARK.to_string())
+            checkmark_id: Some(CHECKMARK.to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: false
         }
     );
 
@@ -636,6 +802,7 @@ pub fn test_revoke_checkmark() {
             addr.clone(),
             &ExecuteMsg::RevokeCheckmark {
                 checkmark_id: CHECKMARK.to_string(),
+                reason: None,
             },
             &[],
         )
@@ -669,7 +836,10 @@ pub fn test_revoke_checkmark() {
     assert_eq!(
         res,
         GetCheckmarkResponse {
-            checkmark_id: Some(CHECKMARK.to_string())
+            checkmark_id: Some(CHECKMARK.to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: false
         }
     );
 
@@ -704,6 +874,7 @@ pub fn test_revoke_checkmark() {
             addr.clone(),
             &ExecuteMsg::RevokeCheckmark {
                 checkmark_id: CHECKMARK.to_string(),
+                reason: None,
             },
             &[],
         )
@@ -721,6 +892,7 @@ pub fn test_revoke_checkmark() {
         addr.clone(),
         &ExecuteMsg::RevokeCheckmark {
             checkmark_id: CHECKMARK.to_string(),
+            reason: None,
         },
         &[],
     )
@@ -736,7 +908,15 @@ pub fn test_revoke_checkmark() {
             },
         )
         .unwrap();
-    assert_eq!(res, GetCheckmarkResponse { checkmark_id: None });
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
 
     // Ensure no user for checkmark.
     let res: GetAddressResponse = app
@@ -769,6 +949,7 @@ pub fn test_revoke_address() {
             addr.clone(),
             &ExecuteMsg::RevokeAddress {
                 address: USER.to_string(),
+                reason: None,
             },
             &[],
         )
@@ -802,7 +983,10 @@ pub fn test_revoke_address() {
     assert_eq!(
         res,
         GetCheckmarkResponse {
-            checkmark_id: Some(CHECKMARK.to_string())
+            checkmark_id: Some(CHECKMARK.to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: false
         }
     );
 
@@ -837,6 +1021,7 @@ pub fn test_revoke_address() {
             addr.clone(),
             &ExecuteMsg::RevokeAddress {
                 address: USER.to_string(),
+                reason: None,
             },
             &[],
         )
@@ -854,6 +1039,7 @@ pub fn test_revoke_address() {
         addr.clone(),
         &ExecuteMsg::RevokeAddress {
             address: USER.to_string(),
+            reason: None,
         },
         &[],
     )
@@ -869,7 +1055,15 @@ pub fn test_revoke_address() {
             },
         )
         .unwrap();
-    assert_eq!(res, GetCheckmarkResponse { checkmark_id: None });
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
 
     // Ensure no user for checkmark.
     let res: GetAddressResponse = app
@@ -920,7 +1114,10 @@ pub fn test_ban_unban_checkmark() {
     assert_eq!(
         res,
         GetCheckmarkResponse {
-            checkmark_id: Some(CHECKMARK.to_string())
+            checkmark_id: Some(CHECKMARK.to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: false
         }
     );
 
@@ -956,6 +1153,9 @@ pub fn test_ban_unban_checkmark() {
             &ExecuteMsg::UpdateCheckmarkBan {
                 ban_ids: Some(vec![CHECKMARK.to_string()]),
                 unban_ids: None,
+                reason: None,
+                note: None,
+                expiration: None,
             },
             &[],
         )
@@ -977,7 +1177,13 @@ pub fn test_ban_unban_checkmark() {
             },
         )
         .unwrap();
-    assert_eq!(res, CheckmarkBannedResponse { banned: false });
+    assert_eq!(
+        res,
+        CheckmarkBannedResponse {
+            banned: false,
+            ban: None
+        }
+    );
 
     // Ban checkmark.
     app.execute_contract(
@@ -986,6 +1192,9 @@ pub fn test_ban_unban_checkmark() {
         &ExecuteMsg::UpdateCheckmarkBan {
             ban_ids: Some(vec![CHECKMARK.to_string()]),
             unban_ids: None,
+            reason: Some(BanReason::Fraud),
+            note: Some("forged document".to_string()),
+            expiration: None,
         },
         &[],
     )
@@ -1001,7 +1210,18 @@ pub fn test_ban_unban_checkmark() {
             },
         )
         .unwrap();
-    assert_eq!(res, CheckmarkBannedResponse { banned: true });
+    assert_eq!(
+        res,
+        CheckmarkBannedResponse {
+            banned: true,
+            ban: Some(Ban {
+                reason: BanReason::Fraud,
+                note: Some("forged document".to_string()),
+                banned_by: Some(Addr::unchecked(OWNER)),
+                expiration: None,
+            }),
+        }
+    );
 
     // Try to assign checkmark.
     let err: ContractError = app
@@ -1029,7 +1249,15 @@ pub fn test_ban_unban_checkmark() {
             },
         )
         .unwrap();
-    assert_eq!(res, GetCheckmarkResponse { checkmark_id: None });
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
 
     // Ensure no user for checkmark.
     let res: GetAddressResponse = app
@@ -1057,6 +1285,9 @@ pub fn test_ban_unban_checkmark() {
         &ExecuteMsg::UpdateCheckmarkBan {
             ban_ids: None,
             unban_ids: Some(vec![CHECKMARK.to_string()]),
+            reason: None,
+            note: None,
+            expiration: None,
         },
         &[],
     )
@@ -1072,7 +1303,13 @@ pub fn test_ban_unban_checkmark() {
             },
         )
         .unwrap();
-    assert_eq!(res, CheckmarkBannedResponse { banned: false });
+    assert_eq!(
+        res,
+        CheckmarkBannedResponse {
+            banned: false,
+            ban: None
+        }
+    );
 
     // Assign checkmark.
     app.execute_contract(
@@ -1099,7 +1336,10 @@ pub fn test_ban_unban_checkmark() {
     assert_eq!(
         res,
         GetCheckmarkResponse {
-            checkmark_id: Some(CHECKMARK.to_string())
+            checkmark_id: Some(CHECKMARK.to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: false
         }
     );
 
@@ -1127,3 +1367,7125 @@ pub fn test_ban_unban_checkmark() {
         .unwrap();
     assert_eq!(res, CountResponse { count: 1 });
 }
+
+#[test]
+pub fn test_link_evm_address() {
+    let (mut app, addr) = instantiate();
+
+    let signing_key = SigningKey::from_bytes(&[1; 32]).unwrap();
+    let eth_address = evm_address(&signing_key);
+    let message = format!("Link EVM address to checkmark held by {USER} in contract {addr}");
+
+    // Try to link before assigned.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::LinkEvmAddress {
+                eth_address: eth_address.clone(),
+                signature: personal_sign(&signing_key, &message),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoCheckmark);
+
+    // Assign checkmark.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Try to link with an invalid EVM address.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::LinkEvmAddress {
+                eth_address: "0x1234".to_string(),
+                signature: personal_sign(&signing_key, &message),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidEvmAddress);
+
+    // Try to link with a signature from a different key.
+    let other_key = SigningKey::from_bytes(&[2; 32]).unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::LinkEvmAddress {
+                eth_address: eth_address.clone(),
+                signature: personal_sign(&other_key, &message),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidSignature);
+
+    // Try to link with a signature over a different message.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::LinkEvmAddress {
+                eth_address: eth_address.clone(),
+                signature: personal_sign(&signing_key, "something else"),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidSignature);
+
+    // Link EVM address, using uppercase hex.
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::LinkEvmAddress {
+            eth_address: format!("0x{}", eth_address[2..].to_uppercase()),
+            signature: personal_sign(&signing_key, &message),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure checkmark found by EVM address.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmarkByEvmAddress {
+                eth_address: eth_address.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: Some(CHECKMARK.to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+
+    // Assign checkmark to ANOTHER_USER.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: "another_checkmark".to_string(),
+            address: ANOTHER_USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure ANOTHER_USER cannot link the same EVM address.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ANOTHER_USER),
+            addr.clone(),
+            &ExecuteMsg::LinkEvmAddress {
+                eth_address: eth_address.clone(),
+                signature: personal_sign(
+                    &signing_key,
+                    &format!(
+                        "Link EVM address to checkmark held by {ANOTHER_USER} in contract {addr}"
+                    ),
+                ),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::EvmAddressAlreadyLinked);
+
+    // Delete checkmark.
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::Delete {},
+        &[],
+    )
+    .unwrap();
+
+    // Ensure link removed with the checkmark.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmarkByEvmAddress {
+                eth_address: eth_address.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+
+    // Ensure ANOTHER_USER can now link the EVM address.
+    app.execute_contract(
+        Addr::unchecked(ANOTHER_USER),
+        addr.clone(),
+        &ExecuteMsg::LinkEvmAddress {
+            eth_address: eth_address.clone(),
+            signature: personal_sign(
+                &signing_key,
+                &format!("Link EVM address to checkmark held by {ANOTHER_USER} in contract {addr}"),
+            ),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(addr, &QueryMsg::GetCheckmarkByEvmAddress { eth_address })
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: Some("another_checkmark".to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+}
+
+#[test]
+pub fn test_prefix_agnostic_lookups() {
+    let mut app = AppBuilder::new()
+        .with_api(Bech32Api(MockApi::default()))
+        .build(|_, _, _| {});
+
+    let owner = Bech32Api::address(&[1; 20]);
+    let assigner = Bech32Api::address(&[2; 20]);
+
+    let code_id = app.store_code(setup_contract());
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(&owner),
+            &InstantiateMsg {
+                owner: Some(owner),
+                assigner: assigner.clone(),
+            },
+            &[],
+            "checkmark",
+            None,
+        )
+        .unwrap();
+
+    let signing_key = SigningKey::from_bytes(&[1; 32]).unwrap();
+    let compressed_pubkey = signing_key.verifying_key().to_encoded_point(true);
+    let uncompressed_pubkey = signing_key.verifying_key().to_encoded_point(false);
+
+    // Derive the Cosmos SDK and Ethereum-style canonical addresses.
+    let cosmos_canonical = Ripemd160::digest(Sha256::digest(compressed_pubkey.as_bytes())).to_vec();
+    let eth_canonical = Keccak256::digest(&uncompressed_pubkey.as_bytes()[1..])[12..].to_vec();
+    let user = Addr::unchecked(Bech32Api::address(&cosmos_canonical));
+
+    // Assign checkmark.
+    app.execute_contract(
+        Addr::unchecked(&assigner),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: user.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure checkmark found by canonical address.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmarkByCanonicalAddress {
+                canonical_address: Binary::from(cosmos_canonical),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: Some(CHECKMARK.to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+
+    // Ensure checkmark found by public key.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmarkByPubkey {
+                pubkey: Binary::from(compressed_pubkey.as_bytes()),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: Some(CHECKMARK.to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+
+    // Ensure Ethereum-style address does not resolve before registering.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmarkByCanonicalAddress {
+                canonical_address: Binary::from(eth_canonical.clone()),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+
+    // Try to register a public key as an address without a checkmark.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(&assigner),
+            addr.clone(),
+            &ExecuteMsg::RegisterPubkey {
+                pubkey: Binary::from(uncompressed_pubkey.as_bytes()),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoCheckmark);
+
+    // Try to register an invalid public key.
+    let err: ContractError = app
+        .execute_contract(
+            user.clone(),
+            addr.clone(),
+            &ExecuteMsg::RegisterPubkey {
+                pubkey: Binary::from(vec![5; 33]),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidPubkey);
+
+    // Try to register a public key that does not derive the sender.
+    let other_key = SigningKey::from_bytes(&[2; 32]).unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            user.clone(),
+            addr.clone(),
+            &ExecuteMsg::RegisterPubkey {
+                pubkey: Binary::from(other_key.verifying_key().to_encoded_point(false).as_bytes()),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::PubkeyMismatch);
+
+    // Register public key.
+    app.execute_contract(
+        user.clone(),
+        addr.clone(),
+        &ExecuteMsg::RegisterPubkey {
+            pubkey: Binary::from(uncompressed_pubkey.as_bytes()),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure Ethereum-style address resolves to the checkmark.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmarkByCanonicalAddress {
+                canonical_address: Binary::from(eth_canonical.clone()),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: Some(CHECKMARK.to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+
+    // Delete checkmark.
+    app.execute_contract(user, addr.clone(), &ExecuteMsg::Delete {}, &[])
+        .unwrap();
+
+    // Ensure Ethereum-style address no longer resolves.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmarkByCanonicalAddress {
+                canonical_address: Binary::from(eth_canonical),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+
+    // Ensure public key no longer resolves.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr,
+            &QueryMsg::GetCheckmarkByPubkey {
+                pubkey: Binary::from(uncompressed_pubkey.as_bytes()),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+}
+
+#[test]
+pub fn test_secondary_addresses() {
+    let (mut app, addr) = instantiate();
+
+    let secondary = "secondary";
+    let another_secondary = "another_secondary";
+
+    // Assign checkmark.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Propose secondary address.
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::AddSecondaryAddress {
+            address: secondary.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure secondary cannot accept when secondary addresses are disabled.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(secondary),
+            addr.clone(),
+            &ExecuteMsg::AcceptSecondaryAddress {
+                primary: USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::TooManySecondaryAddresses { max: 0 });
+
+    // Ensure non-owner cannot update config.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+                max_secondary_addresses: Some(1),
+                ..Default::default()
+            }),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+
+    // Allow one secondary address.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            max_secondary_addresses: Some(1),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let res: Config = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.max_secondary_addresses, 1);
+
+    // Ensure only the proposed address can accept.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(another_secondary),
+            addr.clone(),
+            &ExecuteMsg::AcceptSecondaryAddress {
+                primary: USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoPendingSecondaryAddress);
+
+    // Accept secondary address.
+    app.execute_contract(
+        Addr::unchecked(secondary),
+        addr.clone(),
+        &ExecuteMsg::AcceptSecondaryAddress {
+            primary: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure secondary resolves to the primary's checkmark.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: secondary.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: Some(CHECKMARK.to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+
+    let res: SecondaryAddressesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::SecondaryAddresses {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.addresses, vec![Addr::unchecked(secondary)]);
+
+    // Ensure checkmark count still counts one person.
+    let res: CountResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Count {})
+        .unwrap();
+    assert_eq!(res, CountResponse { count: 1 });
+
+    // Ensure secondary cannot be assigned its own checkmark.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: "another_checkmark".to_string(),
+                address: secondary.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AlreadyHasCheckmark);
+
+    // Ensure the limit is enforced.
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::AddSecondaryAddress {
+            address: another_secondary.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(another_secondary),
+            addr.clone(),
+            &ExecuteMsg::AcceptSecondaryAddress {
+                primary: USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::TooManySecondaryAddresses { max: 1 });
+
+    // Ensure only the primary or the secondary can remove it.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ANOTHER_USER),
+            addr.clone(),
+            &ExecuteMsg::RemoveSecondaryAddress {
+                address: secondary.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized);
+
+    // Secondary removes itself, then accepts again.
+    app.execute_contract(
+        Addr::unchecked(secondary),
+        addr.clone(),
+        &ExecuteMsg::RemoveSecondaryAddress {
+            address: secondary.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(another_secondary),
+        addr.clone(),
+        &ExecuteMsg::AcceptSecondaryAddress {
+            primary: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: secondary.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+
+    // Ban the secondary address.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateAddressBan {
+            ban_addresses: Some(vec![another_secondary.to_string()]),
+            unban_addresses: None,
+            reason: None,
+            note: None,
+            expiration: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure the banned address is unlinked and the primary keeps its
+    // checkmark.
+    let res: SecondaryAddressesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::SecondaryAddresses {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.addresses, Vec::<Addr>::new());
+
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.checkmark_id, Some(CHECKMARK.to_string()));
+
+    // Unban the address and link it again.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateAddressBan {
+            ban_addresses: None,
+            unban_addresses: Some(vec![another_secondary.to_string()]),
+            reason: None,
+            note: None,
+            expiration: None,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::AddSecondaryAddress {
+            address: another_secondary.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(another_secondary),
+        addr.clone(),
+        &ExecuteMsg::AcceptSecondaryAddress {
+            primary: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Revoke the primary's checkmark.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::RevokeAddress {
+            address: USER.to_string(),
+            reason: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure secondary links are removed.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: another_secondary.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+
+    let res: SecondaryAddressesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::SecondaryAddresses {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.addresses, Vec::<Addr>::new());
+
+    // Ensure former secondary can be assigned its own checkmark.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr,
+        &ExecuteMsg::Assign {
+            checkmark_id: "another_checkmark".to_string(),
+            address: another_secondary.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+pub fn test_delegation() {
+    let (mut app, addr) = instantiate();
+
+    let delegate = "delegate";
+
+    // Try to delegate before assigned.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::Delegate {
+                to: delegate.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoCheckmark);
+
+    // Assign checkmarks.
+    for (checkmark_id, address) in [(CHECKMARK, USER), ("another_checkmark", ANOTHER_USER)] {
+        app.execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: checkmark_id.to_string(),
+                address: address.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Ensure holder votes for itself.
+    let res: ResolveVoterResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ResolveVoter {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ResolveVoterResponse {
+            principal: Some(Addr::unchecked(USER)),
+            checkmark_id: Some(CHECKMARK.to_string()),
+        }
+    );
+
+    // Ensure cannot delegate to another checkmark holder.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::Delegate {
+                to: ANOTHER_USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidDelegate);
+
+    // Propose the delegate as another holder's secondary address.
+    app.execute_contract(
+        Addr::unchecked(ANOTHER_USER),
+        addr.clone(),
+        &ExecuteMsg::AddSecondaryAddress {
+            address: delegate.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Delegate.
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::Delegate {
+            to: delegate.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: GetDelegationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetDelegation {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetDelegationResponse {
+            delegate: Some(Addr::unchecked(delegate))
+        }
+    );
+
+    // Ensure delegate votes for the holder, and the holder no longer votes.
+    let res: ResolveVoterResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ResolveVoter {
+                address: delegate.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ResolveVoterResponse {
+            principal: Some(Addr::unchecked(USER)),
+            checkmark_id: Some(CHECKMARK.to_string()),
+        }
+    );
+    let res: ResolveVoterResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ResolveVoter {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ResolveVoterResponse {
+            principal: None,
+            checkmark_id: None,
+        }
+    );
+
+    // Ensure another holder cannot delegate to the same delegate.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ANOTHER_USER),
+            addr.clone(),
+            &ExecuteMsg::Delegate {
+                to: delegate.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidDelegate);
+
+    // Ensure the delegate cannot become another holder's secondary address or
+    // be assigned a checkmark, which would let it vote twice.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(delegate),
+            addr.clone(),
+            &ExecuteMsg::AcceptSecondaryAddress {
+                primary: ANOTHER_USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AddressIsDelegate);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ANOTHER_USER),
+            addr.clone(),
+            &ExecuteMsg::AddSecondaryAddress {
+                address: delegate.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AddressIsDelegate);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: "delegate_checkmark".to_string(),
+                address: delegate.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AddressIsDelegate);
+
+    // Undelegate.
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::Undelegate {},
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::Undelegate {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoDelegation);
+
+    let res: ResolveVoterResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ResolveVoter {
+                address: delegate.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ResolveVoterResponse {
+            principal: None,
+            checkmark_id: None,
+        }
+    );
+
+    // Delegate again, then revoke the checkmark.
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::Delegate {
+            to: delegate.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::RevokeAddress {
+            address: USER.to_string(),
+            reason: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure delegation is cleared.
+    let res: GetDelegationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetDelegation {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, GetDelegationResponse { delegate: None });
+
+    let res: ResolveVoterResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr,
+            &QueryMsg::ResolveVoter {
+                address: delegate.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ResolveVoterResponse {
+            principal: None,
+            checkmark_id: None,
+        }
+    );
+}
+
+#[test]
+pub fn test_social_recovery() {
+    let (mut app, addr) = instantiate();
+
+    let guardians = ["guardian1", "guardian2", "guardian3"];
+    let new_address = "new_address";
+
+    // Assign checkmark.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure threshold must be reachable.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::SetGuardians {
+                guardians: guardians.iter().map(|g| g.to_string()).collect(),
+                threshold: 4,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidGuardians);
+
+    // Set guardians.
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::SetGuardians {
+            guardians: guardians.iter().map(|g| g.to_string()).collect(),
+            threshold: 2,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: GuardiansResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::Guardians {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GuardiansResponse {
+            guardians: guardians.iter().map(|g| Addr::unchecked(*g)).collect(),
+            threshold: 2,
+            pending_change: None,
+        }
+    );
+
+    // Ensure non-guardian cannot approve.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ANOTHER_USER),
+            addr.clone(),
+            &ExecuteMsg::ApproveRecovery {
+                holder: USER.to_string(),
+                new_address: new_address.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized);
+
+    // First guardian approves.
+    app.execute_contract(
+        Addr::unchecked(guardians[0]),
+        addr.clone(),
+        &ExecuteMsg::ApproveRecovery {
+            holder: USER.to_string(),
+            new_address: new_address.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Second guardian disagrees, approving a different address.
+    app.execute_contract(
+        Addr::unchecked(guardians[1]),
+        addr.clone(),
+        &ExecuteMsg::ApproveRecovery {
+            holder: USER.to_string(),
+            new_address: ANOTHER_USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure recovery cannot be executed before the threshold is reached.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ANOTHER_USER),
+            addr.clone(),
+            &ExecuteMsg::ExecuteRecovery {
+                holder: USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::RecoveryNotReady);
+
+    // Third guardian approves the first address, reaching the threshold.
+    app.execute_contract(
+        Addr::unchecked(guardians[2]),
+        addr.clone(),
+        &ExecuteMsg::ApproveRecovery {
+            holder: USER.to_string(),
+            new_address: new_address.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure a different address cannot be approved once one is.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(guardians[1]),
+            addr.clone(),
+            &ExecuteMsg::ApproveRecovery {
+                holder: USER.to_string(),
+                new_address: ANOTHER_USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::RecoveryMismatch);
+
+    let res: PendingRecoveryResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::PendingRecovery {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    let recovery = res.recovery.unwrap();
+    assert_eq!(recovery.new_address, Some(Addr::unchecked(new_address)));
+    assert_eq!(
+        recovery.proposals,
+        vec![
+            RecoveryProposal {
+                new_address: Addr::unchecked(new_address),
+                approvals: vec![Addr::unchecked(guardians[0]), Addr::unchecked(guardians[2])],
+            },
+            RecoveryProposal {
+                new_address: Addr::unchecked(ANOTHER_USER),
+                approvals: vec![Addr::unchecked(guardians[1])],
+            },
+        ]
+    );
+    assert!(recovery.executable_after.is_some());
+
+    let res: ListPendingRecoveriesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListPendingRecoveries {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.recoveries.len(), 1);
+    assert_eq!(res.recoveries[0].holder, Addr::unchecked(USER));
+
+    // Ensure recovery cannot be executed before the delay passes.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ANOTHER_USER),
+            addr.clone(),
+            &ExecuteMsg::ExecuteRecovery {
+                holder: USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::RecoveryNotReady);
+
+    // Holder cancels during the delay.
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::CancelRecovery { holder: None },
+        &[],
+    )
+    .unwrap();
+
+    let res: PendingRecoveryResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::PendingRecovery {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.recovery, None);
+
+    // Guardians approve again.
+    for guardian in &guardians[..2] {
+        app.execute_contract(
+            Addr::unchecked(*guardian),
+            addr.clone(),
+            &ExecuteMsg::ApproveRecovery {
+                holder: USER.to_string(),
+                new_address: new_address.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Wait out the delay and execute.
+    app.update_block(|block| block.time = block.time.plus_seconds(7 * 24 * 60 * 60));
+    app.execute_contract(
+        Addr::unchecked(ANOTHER_USER),
+        addr.clone(),
+        &ExecuteMsg::ExecuteRecovery {
+            holder: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure checkmark moved to the new address.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+
+    let res: GetAddressResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetAddress {
+                checkmark_id: CHECKMARK.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetAddressResponse {
+            address: Some(Addr::unchecked(new_address))
+        }
+    );
+
+    let res: CountResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Count {})
+        .unwrap();
+    assert_eq!(res, CountResponse { count: 1 });
+
+    // Ensure guardians carried over to the new address.
+    let res: GuardiansResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::Guardians {
+                address: new_address.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.threshold, 2);
+
+    let res: PendingRecoveryResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr,
+            &QueryMsg::PendingRecovery {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.recovery, None);
+}
+
+#[test]
+pub fn test_guardian_change_delay() {
+    let (mut app, addr) = instantiate();
+
+    let new_address = "new_address";
+
+    // Assign checkmark and set guardians, which takes effect immediately.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::SetGuardians {
+            guardians: vec!["guardian1".to_string(), "guardian2".to_string()],
+            threshold: 2,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Change guardians, which is delayed.
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::SetGuardians {
+            guardians: vec!["guardian3".to_string()],
+            threshold: 1,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: GuardiansResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::Guardians {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.guardians,
+        vec![Addr::unchecked("guardian1"), Addr::unchecked("guardian2")]
+    );
+    assert_eq!(
+        res.pending_change.map(|change| change.guardians),
+        Some(vec![Addr::unchecked("guardian3")])
+    );
+
+    // Ensure the new guardian cannot approve before the change takes effect.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("guardian3"),
+            addr.clone(),
+            &ExecuteMsg::ApproveRecovery {
+                holder: USER.to_string(),
+                new_address: "attacker".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized);
+
+    // Current guardians approve a recovery during the delay.
+    for guardian in ["guardian1", "guardian2"] {
+        app.execute_contract(
+            Addr::unchecked(guardian),
+            addr.clone(),
+            &ExecuteMsg::ApproveRecovery {
+                holder: USER.to_string(),
+                new_address: new_address.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // New address opts out before the recovery executes.
+    app.execute_contract(
+        Addr::unchecked(new_address),
+        addr.clone(),
+        &ExecuteMsg::OptOut {},
+        &[],
+    )
+    .unwrap();
+
+    // Ensure the recovery checks the new address when executed.
+    app.update_block(|block| block.time = block.time.plus_seconds(7 * 24 * 60 * 60));
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ANOTHER_USER),
+            addr.clone(),
+            &ExecuteMsg::ExecuteRecovery {
+                holder: USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::OptedOut);
+
+    // Ensure the change took effect after the delay.
+    let res: GuardiansResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::Guardians {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GuardiansResponse {
+            guardians: vec![Addr::unchecked("guardian3")],
+            threshold: 1,
+            pending_change: None,
+        }
+    );
+
+    // Ensure the recovery the previous guardians approved can still execute.
+    app.execute_contract(
+        Addr::unchecked(new_address),
+        addr.clone(),
+        &ExecuteMsg::OptIn {},
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ANOTHER_USER),
+        addr.clone(),
+        &ExecuteMsg::ExecuteRecovery {
+            holder: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: GetAddressResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr,
+            &QueryMsg::GetAddress {
+                checkmark_id: CHECKMARK.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetAddressResponse {
+            address: Some(Addr::unchecked(new_address))
+        }
+    );
+}
+
+#[test]
+pub fn test_roles() {
+    let (mut app, addr) = instantiate();
+
+    let moderator = "moderator";
+
+    // Assign checkmark.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure moderator cannot revoke or ban before granted roles.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(moderator),
+            addr.clone(),
+            &ExecuteMsg::RevokeAddress {
+                address: USER.to_string(),
+                reason: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+
+    // Ensure non-owner cannot grant roles.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(moderator),
+            addr.clone(),
+            &ExecuteMsg::GrantRole {
+                role: Role::Revoker,
+                address: moderator.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+
+    // Grant revoker role.
+    let res = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::GrantRole {
+                role: Role::Revoker,
+                address: moderator.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm")
+            .add_attribute("method", "grant_role")
+            .add_attribute("role", "revoker")
+            .add_attribute("address", moderator)
+    ));
+
+    let res: HasRoleResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::HasRole {
+                role: Role::Revoker,
+                address: moderator.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, HasRoleResponse { has_role: true });
+
+    let res: ListRoleMembersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListRoleMembers {
+                role: Role::Revoker,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.members, vec![Addr::unchecked(moderator)]);
+
+    // Ensure roles are separate.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(moderator),
+            addr.clone(),
+            &ExecuteMsg::UpdateCheckmarkBan {
+                ban_ids: Some(vec![CHECKMARK.to_string()]),
+                unban_ids: None,
+                reason: None,
+                note: None,
+                expiration: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+
+    // Revoke as moderator.
+    app.execute_contract(
+        Addr::unchecked(moderator),
+        addr.clone(),
+        &ExecuteMsg::RevokeAddress {
+            address: USER.to_string(),
+            reason: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Grant assigner role and assign as moderator.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::GrantRole {
+            role: Role::Assigner,
+            address: moderator.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(moderator),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Revoke revoker role.
+    let res = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::RevokeRole {
+                role: Role::Revoker,
+                address: moderator.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm")
+            .add_attribute("method", "revoke_role")
+            .add_attribute("role", "revoker")
+            .add_attribute("address", moderator)
+    ));
+
+    let res: HasRoleResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::HasRole {
+                role: Role::Revoker,
+                address: moderator.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, HasRoleResponse { has_role: false });
+
+    // Ensure moderator can no longer revoke.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(moderator),
+            addr.clone(),
+            &ExecuteMsg::RevokeAddress {
+                address: USER.to_string(),
+                reason: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+
+    // Grant config admin role and update config as moderator.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::GrantRole {
+            role: Role::ConfigAdmin,
+            address: moderator.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(moderator),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            max_secondary_addresses: Some(2),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let res: Config = app
+        .wrap()
+        .query_wasm_smart(addr, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.max_secondary_addresses, 2);
+}
+
+#[test]
+pub fn test_assign_threshold() {
+    let (mut app, addr) = instantiate();
+
+    let second_assigner = "second_assigner";
+
+    // Register a second assigner and require two approvals.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::GrantRole {
+            role: Role::Assigner,
+            address: second_assigner.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            assign_threshold: Some(2),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Ensure assigner cannot assign directly.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: CHECKMARK.to_string(),
+                address: USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ApprovalRequired);
+
+    // Ensure non-assigner cannot approve.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::ApproveAssign {
+                checkmark_id: CHECKMARK.to_string(),
+                address: USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized);
+
+    // First approval.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::ApproveAssign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure the same assigner cannot approve twice.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::ApproveAssign {
+                checkmark_id: CHECKMARK.to_string(),
+                address: USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AlreadyApproved);
+
+    // Ensure a mismatched approval is rejected.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(second_assigner),
+            addr.clone(),
+            &ExecuteMsg::ApproveAssign {
+                checkmark_id: CHECKMARK.to_string(),
+                address: ANOTHER_USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AssignmentMismatch);
+
+    // Ensure not yet assigned, and pending approval listed.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+
+    let res: ListPendingAssignmentsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListPendingAssignments {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.assignments.len(), 1);
+    assert_eq!(res.assignments[0].checkmark_id, CHECKMARK);
+    assert_eq!(
+        res.assignments[0].pending.approvals,
+        vec![Addr::unchecked(ASSIGNER)]
+    );
+
+    // Second approval assigns the checkmark.
+    app.execute_contract(
+        Addr::unchecked(second_assigner),
+        addr.clone(),
+        &ExecuteMsg::ApproveAssign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: Some(CHECKMARK.to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+
+    let res: ListPendingAssignmentsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListPendingAssignments {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(res.assignments.is_empty());
+
+    // Approve another assignment, then let it expire.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::ApproveAssign {
+            checkmark_id: "another_checkmark".to_string(),
+            address: ANOTHER_USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+
+    // Ensure an expired proposal is replaced by a new one.
+    app.execute_contract(
+        Addr::unchecked(second_assigner),
+        addr.clone(),
+        &ExecuteMsg::ApproveAssign {
+            checkmark_id: "another_checkmark".to_string(),
+            address: ANOTHER_USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: ANOTHER_USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+
+    // Prune expired proposals.
+    app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::PrunePendingAssignments { limit: None },
+        &[],
+    )
+    .unwrap();
+
+    let res: ListPendingAssignmentsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr,
+            &QueryMsg::ListPendingAssignments {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(res.assignments.is_empty());
+}
+
+#[test]
+pub fn test_timelock() {
+    let (mut app, addr) = instantiate();
+
+    let guardian = "guardian";
+
+    // Assign checkmark.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Enable a one day timelock.
+    for role in [Role::Guardian, Role::ConfigAdmin] {
+        app.execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::GrantRole {
+                role,
+                address: guardian.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            timelock_delay: Some(cw_utils::DAY),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Ensure non-owner cannot queue owner actions.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::UpdateAssigner {
+                assigner: USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+
+    // Queue revocation and assigner update.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::RevokeAddress {
+            address: USER.to_string(),
+            reason: None,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateAssigner {
+            assigner: "new_assigner".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure neither took effect.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: Some(CHECKMARK.to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+    let res: AssignerResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Assigner {})
+        .unwrap();
+    assert_eq!(res.assigner, Addr::unchecked(ASSIGNER));
+
+    let res: ListTimelockedActionsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListTimelockedActions {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.actions.iter().map(|entry| entry.id).collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+
+    // Ensure actions cannot be executed before the delay.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::ExecuteTimelocked { id: 1 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::TimelockNotExpired);
+
+    // Ensure non-guardian cannot cancel.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::CancelTimelocked { id: 1 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+
+    // Guardian cancels the assigner update.
+    app.execute_contract(
+        Addr::unchecked(guardian),
+        addr.clone(),
+        &ExecuteMsg::CancelTimelocked { id: 2 },
+        &[],
+    )
+    .unwrap();
+
+    let res: TimelockedActionResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::TimelockedAction { id: 2 })
+        .unwrap();
+    assert_eq!(res.action, None);
+
+    // Anyone executes the revocation after the delay.
+    app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::ExecuteTimelocked { id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::ExecuteTimelocked { id: 2 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoTimelockedAction);
+
+    // Ensure role and ownership changes are queued.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::RevokeRole {
+            role: Role::Guardian,
+            address: guardian.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateOwnership(cw_ownable::Action::TransferOwnership {
+            new_owner: USER.to_string(),
+            expiry: None,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let res: ListTimelockedActionsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListTimelockedActions {
+                start_after: Some(2),
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.actions.iter().map(|entry| entry.id).collect::<Vec<_>>(),
+        vec![3, 4]
+    );
+    let res: cw_ownable::Ownership<Addr> = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Ownership {})
+        .unwrap();
+    assert_eq!(res.pending_owner, None);
+
+    for id in [3, 4] {
+        app.execute_contract(
+            Addr::unchecked(guardian),
+            addr.clone(),
+            &ExecuteMsg::CancelTimelocked { id },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Ensure disabling the timelock waits out the current delay, even when
+    // the config admin does it.
+    app.execute_contract(
+        Addr::unchecked(guardian),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            timelock_delay: Some(cw_utils::Duration::Time(0)),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let res: Config = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.timelock_delay, Some(cw_utils::DAY));
+
+    app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::ExecuteTimelocked { id: 5 },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure actions take effect immediately once disabled.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateAssigner {
+            assigner: "new_assigner".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: AssignerResponse = app
+        .wrap()
+        .query_wasm_smart(addr, &QueryMsg::Assigner {})
+        .unwrap();
+    assert_eq!(res.assigner, Addr::unchecked("new_assigner"));
+}
+
+#[test]
+pub fn test_sudo() {
+    let (mut app, addr) = instantiate();
+
+    // Assign checkmarks.
+    for (checkmark_id, address) in [(CHECKMARK, USER), ("checkmark2", "user2")] {
+        app.execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: checkmark_id.to_string(),
+                address: address.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Enable a timelock, which governance bypasses.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            timelock_delay: Some(cw_utils::DAY),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Replace owner and assigner.
+    app.wasm_sudo(
+        addr.clone(),
+        &SudoMsg::UpdateOwner {
+            owner: Some("new_owner".to_string()),
+        },
+    )
+    .unwrap();
+    app.wasm_sudo(
+        addr.clone(),
+        &SudoMsg::UpdateAssigner {
+            assigner: "new_assigner".to_string(),
+        },
+    )
+    .unwrap();
+
+    let res: cw_ownable::Ownership<Addr> = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Ownership {})
+        .unwrap();
+    assert_eq!(res.owner, Some(Addr::unchecked("new_owner")));
+    let res: AssignerResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Assigner {})
+        .unwrap();
+    assert_eq!(res.assigner, Addr::unchecked("new_assigner"));
+
+    // Revoke by ID and by address, and ban.
+    app.wasm_sudo(
+        addr.clone(),
+        &SudoMsg::RevokeCheckmark {
+            checkmark_id: CHECKMARK.to_string(),
+        },
+    )
+    .unwrap();
+    app.wasm_sudo(
+        addr.clone(),
+        &SudoMsg::RevokeAddress {
+            address: "user2".to_string(),
+        },
+    )
+    .unwrap();
+    app.wasm_sudo(
+        addr.clone(),
+        &SudoMsg::UpdateCheckmarkBan {
+            ban_ids: Some(vec![CHECKMARK.to_string()]),
+            unban_ids: None,
+            reason: None,
+            note: None,
+            expiration: None,
+        },
+    )
+    .unwrap();
+
+    let res: CountResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Count {})
+        .unwrap();
+    assert_eq!(res.count, 0);
+    let res: CheckmarkBannedResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::CheckmarkBanned {
+                checkmark_id: CHECKMARK.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.banned);
+
+    // Pause, and ensure execute messages are rejected, even from the owner.
+    app.wasm_sudo(addr.clone(), &SudoMsg::SetPaused { paused: true })
+        .unwrap();
+
+    let res: PausedResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Paused {})
+        .unwrap();
+    assert!(res.paused);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("new_assigner"),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: "checkmark3".to_string(),
+                address: USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Paused);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("new_owner"),
+            addr.clone(),
+            &ExecuteMsg::UpdateAssigner {
+                assigner: ASSIGNER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Paused);
+
+    // Unpause, and ensure execute messages work again.
+    app.wasm_sudo(addr.clone(), &SudoMsg::SetPaused { paused: false })
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("new_assigner"),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: "checkmark3".to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr,
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: Some("checkmark3".to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+}
+
+#[test]
+pub fn test_temporary_ban() {
+    let (mut app, addr) = instantiate();
+
+    // Assign checkmark.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ban two checkmarks for a day, and ensure the event lists them.
+    let expiration = cw_utils::DAY.after(&app.block_info());
+    let res = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::UpdateCheckmarkBan {
+                ban_ids: Some(vec![CHECKMARK.to_string(), "checkmark2".to_string()]),
+                unban_ids: None,
+                reason: None,
+                note: None,
+                expiration: Some(expiration),
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm")
+            .add_attribute("method", "update_checkmark_ban")
+            .add_attribute("ban_ids", format!("{CHECKMARK},checkmark2"))
+    ));
+
+    // Ensure both bans are listed, with the default reason.
+    let res: ListBannedCheckmarksResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListBannedCheckmarks {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let ban = Ban {
+        reason: BanReason::Other,
+        note: None,
+        banned_by: Some(Addr::unchecked(OWNER)),
+        expiration: Some(expiration),
+    };
+    assert_eq!(
+        res,
+        ListBannedCheckmarksResponse {
+            bans: vec![
+                BannedCheckmarkEntry {
+                    checkmark_id: CHECKMARK.to_string(),
+                    ban: ban.clone(),
+                },
+                BannedCheckmarkEntry {
+                    checkmark_id: "checkmark2".to_string(),
+                    ban,
+                },
+            ]
+        }
+    );
+
+    // Ensure pagination.
+    let res: ListBannedCheckmarksResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListBannedCheckmarks {
+                start_after: Some(CHECKMARK.to_string()),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.bans.len(), 1);
+    assert_eq!(res.bans[0].checkmark_id, "checkmark2");
+
+    // Ensure the checkmark cannot be reassigned while banned.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: CHECKMARK.to_string(),
+                address: USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::CheckmarkBanned);
+
+    // Ensure the bans lift once expired.
+    app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+
+    let res: CheckmarkBannedResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::CheckmarkBanned {
+                checkmark_id: CHECKMARK.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        CheckmarkBannedResponse {
+            banned: false,
+            ban: None
+        }
+    );
+
+    let res: ListBannedCheckmarksResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListBannedCheckmarks {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.bans, vec![]);
+
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure governance bans record no actor.
+    app.wasm_sudo(
+        addr.clone(),
+        &SudoMsg::UpdateCheckmarkBan {
+            ban_ids: Some(vec!["checkmark3".to_string()]),
+            unban_ids: None,
+            reason: Some(BanReason::CompromisedAccount),
+            note: None,
+            expiration: None,
+        },
+    )
+    .unwrap();
+
+    let res: CheckmarkBannedResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr,
+            &QueryMsg::CheckmarkBanned {
+                checkmark_id: "checkmark3".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.ban,
+        Some(Ban {
+            reason: BanReason::CompromisedAccount,
+            note: None,
+            banned_by: None,
+            expiration: None,
+        })
+    );
+}
+
+#[test]
+pub fn test_address_ban() {
+    let (mut app, addr) = instantiate();
+
+    // Assign checkmark.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Try to ban address as non_owner.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("non_owner"),
+            addr.clone(),
+            &ExecuteMsg::UpdateAddressBan {
+                ban_addresses: Some(vec![USER.to_string()]),
+                unban_addresses: None,
+                reason: None,
+                note: None,
+                expiration: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+
+    // Ban address for a day.
+    let expiration = cw_utils::DAY.after(&app.block_info());
+    let res = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::UpdateAddressBan {
+                ban_addresses: Some(vec![USER.to_string()]),
+                unban_addresses: None,
+                reason: Some(BanReason::DuplicateIdentity),
+                note: Some("second wallet".to_string()),
+                expiration: Some(expiration),
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm")
+            .add_attribute("method", "update_address_ban")
+            .add_attribute("ban_addresses", USER)
+    ));
+
+    // Ensure the checkmark was revoked.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false
+        }
+    );
+
+    let ban = Ban {
+        reason: BanReason::DuplicateIdentity,
+        note: Some("second wallet".to_string()),
+        banned_by: Some(Addr::unchecked(OWNER)),
+        expiration: Some(expiration),
+    };
+    let res: AddressBannedResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::AddressBanned {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        AddressBannedResponse {
+            banned: true,
+            ban: Some(ban.clone()),
+        }
+    );
+
+    let res: ListBannedAddressesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListBannedAddresses {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ListBannedAddressesResponse {
+            bans: vec![BannedAddressEntry {
+                address: Addr::unchecked(USER),
+                ban,
+            }]
+        }
+    );
+
+    // Ensure the address cannot be assigned a new checkmark.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: "checkmark2".to_string(),
+                address: USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AddressBanned);
+
+    // Ensure the ban lifts once expired.
+    app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+
+    let res: AddressBannedResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::AddressBanned {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        AddressBannedResponse {
+            banned: false,
+            ban: None
+        }
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: "checkmark2".to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ban indefinitely, then unban.
+    for (ban_addresses, unban_addresses) in [
+        (Some(vec![USER.to_string()]), None),
+        (None, Some(vec![USER.to_string()])),
+    ] {
+        app.execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::UpdateAddressBan {
+                ban_addresses,
+                unban_addresses,
+                reason: None,
+                note: None,
+                expiration: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let res: ListBannedAddressesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr,
+            &QueryMsg::ListBannedAddresses {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.bans, vec![]);
+}
+
+#[test]
+pub fn test_suspension() {
+    let (mut app, addr) = instantiate();
+
+    // Assign checkmarks.
+    for (checkmark_id, address) in [(CHECKMARK, USER), ("checkmark2", "user2")] {
+        app.execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: checkmark_id.to_string(),
+                address: address.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Try to suspend as non_owner.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("non_owner"),
+            addr.clone(),
+            &ExecuteMsg::Suspend {
+                address: Some(USER.to_string()),
+                checkmark_id: None,
+                reason: "investigation".to_string(),
+                until: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+
+    // Ensure exactly one of address or checkmark ID is set.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::Suspend {
+                address: Some(USER.to_string()),
+                checkmark_id: Some(CHECKMARK.to_string()),
+                reason: "investigation".to_string(),
+                until: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidTarget);
+
+    // Suspend by address, and another by checkmark ID for a day.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::Suspend {
+            address: Some(USER.to_string()),
+            checkmark_id: None,
+            reason: "investigation".to_string(),
+            until: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let until = cw_utils::DAY.after(&app.block_info());
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::Suspend {
+            address: None,
+            checkmark_id: Some("checkmark2".to_string()),
+            reason: "investigation".to_string(),
+            until: Some(until),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure the checkmark is shown as suspended and not verified.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: Some(Suspension {
+                reason: "investigation".to_string(),
+                suspended_by: Addr::unchecked(OWNER),
+                until: None,
+            }),
+            pending_deletion: None,
+            organization: false,
+        }
+    );
+
+    // Ensure suspended checkmarks are not active and cannot vote.
+    let res: CountResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Count {})
+        .unwrap();
+    assert_eq!(res, CountResponse { count: 2 });
+    let res: CountResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::ActiveCount {})
+        .unwrap();
+    assert_eq!(res, CountResponse { count: 0 });
+
+    let res: ResolveVoterResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ResolveVoter {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ResolveVoterResponse {
+            principal: None,
+            checkmark_id: None,
+        }
+    );
+
+    // Ensure the timed suspension lifts once expired.
+    app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+
+    let res: CountResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::ActiveCount {})
+        .unwrap();
+    assert_eq!(res, CountResponse { count: 1 });
+
+    let res: ResolveVoterResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ResolveVoter {
+                address: "user2".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.checkmark_id, Some("checkmark2".to_string()));
+
+    // Unsuspend, and ensure it can only be done once.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::Unsuspend {
+            address: None,
+            checkmark_id: Some(CHECKMARK.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::Unsuspend {
+                address: Some(USER.to_string()),
+                checkmark_id: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NotSuspended);
+
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.suspension, None);
+
+    // Ensure suspensions are removed with the checkmark.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::Suspend {
+            address: Some(USER.to_string()),
+            checkmark_id: None,
+            reason: "investigation".to_string(),
+            until: None,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::Delete {},
+        &[],
+    )
+    .unwrap();
+
+    let res: CountResponse = app
+        .wrap()
+        .query_wasm_smart(addr, &QueryMsg::ActiveCount {})
+        .unwrap();
+    assert_eq!(res, CountResponse { count: 1 });
+}
+
+#[test]
+pub fn test_revocation_challenge() {
+    let (mut app, addr) = instantiate();
+
+    let evidence_hash = hex::encode(Sha256::digest(b"evidence"));
+
+    // Assign checkmarks.
+    for (checkmark_id, address) in [(CHECKMARK, USER), ("checkmark2", "user2")] {
+        app.execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: checkmark_id.to_string(),
+                address: address.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Enable a one day challenge period.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            revocation_challenge_period: Some(cw_utils::DAY),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Schedule revocations.
+    let finalize_after = cw_utils::DAY.after(&app.block_info());
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::RevokeAddress {
+            address: USER.to_string(),
+            reason: Some("duplicate".to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::RevokeCheckmark {
+            checkmark_id: "checkmark2".to_string(),
+            reason: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::RevokeCheckmark {
+                checkmark_id: CHECKMARK.to_string(),
+                reason: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::RevocationAlreadyScheduled);
+
+    // Ensure checkmarks are kept during the challenge period.
+    let res: CountResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Count {})
+        .unwrap();
+    assert_eq!(res, CountResponse { count: 2 });
+
+    let res: ScheduledRevocationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ScheduledRevocation {
+                checkmark_id: CHECKMARK.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.revocation,
+        Some(ScheduledRevocation {
+            reason: Some("duplicate".to_string()),
+            proposer: Addr::unchecked(OWNER),
+            finalize_after,
+            appeal: None,
+        })
+    );
+
+    let res: ListScheduledRevocationsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListScheduledRevocations {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.revocations
+            .iter()
+            .map(|entry| entry.checkmark_id.as_str())
+            .collect::<Vec<_>>(),
+        vec![CHECKMARK, "checkmark2"]
+    );
+
+    // Ensure revocations cannot be finalized early.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            addr.clone(),
+            &ExecuteMsg::FinalizeRevocation {
+                checkmark_id: CHECKMARK.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ChallengePeriodNotEnded);
+
+    // Both holders appeal.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::Appeal {
+                evidence_hash: "not a hash".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidEvidenceHash);
+
+    for holder in [USER, "user2"] {
+        app.execute_contract(
+            Addr::unchecked(holder),
+            addr.clone(),
+            &ExecuteMsg::Appeal {
+                evidence_hash: evidence_hash.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::Appeal {
+                evidence_hash: evidence_hash.clone(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AlreadyAppealed);
+
+    // Ensure a pending appeal blocks finalization after the period.
+    app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            addr.clone(),
+            &ExecuteMsg::FinalizeRevocation {
+                checkmark_id: CHECKMARK.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AppealPending);
+
+    // Only the owner can resolve appeals.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::ResolveAppeal {
+                checkmark_id: CHECKMARK.to_string(),
+                uphold: true,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+
+    // Uphold the first appeal, and dismiss the second.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::ResolveAppeal {
+            checkmark_id: CHECKMARK.to_string(),
+            uphold: true,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::ResolveAppeal {
+            checkmark_id: "checkmark2".to_string(),
+            uphold: false,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            addr.clone(),
+            &ExecuteMsg::FinalizeRevocation {
+                checkmark_id: CHECKMARK.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoScheduledRevocation);
+
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        addr.clone(),
+        &ExecuteMsg::FinalizeRevocation {
+            checkmark_id: "checkmark2".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure only the dismissed appeal's checkmark was revoked.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.checkmark_id, Some(CHECKMARK.to_string()));
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: "user2".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.checkmark_id, None);
+
+    let res: ListScheduledRevocationsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListScheduledRevocations {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.revocations, vec![]);
+
+    // Ensure an unappealed revocation cannot be appealed after the period.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::RevokeAddress {
+            address: USER.to_string(),
+            reason: None,
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::Appeal { evidence_hash },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ChallengePeriodEnded);
+
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        addr.clone(),
+        &ExecuteMsg::FinalizeRevocation {
+            checkmark_id: CHECKMARK.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: CountResponse = app
+        .wrap()
+        .query_wasm_smart(addr, &QueryMsg::Count {})
+        .unwrap();
+    assert_eq!(res, CountResponse { count: 0 });
+}
+
+#[test]
+pub fn test_reports() {
+    let (mut app, addr) = instantiate();
+
+    let moderator = "moderator";
+    let reporter = "reporter";
+    let evidence_hash = hex::encode(Sha256::digest(b"evidence"));
+
+    app.init_modules(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &Addr::unchecked(reporter), coins(300, "ujuno"))
+    })
+    .unwrap();
+
+    // Assign checkmarks.
+    for (checkmark_id, address) in [(CHECKMARK, USER), ("checkmark2", "user2")] {
+        app.execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: checkmark_id.to_string(),
+                address: address.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Require a bond and pay a reward, and grant the moderator role.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            report_bond: Some(coin(100, "ujuno")),
+            report_reward: Some(coin(50, "ujuno")),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::GrantRole {
+            role: Role::Moderator,
+            address: moderator.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure the bond is required.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(reporter),
+            addr.clone(),
+            &ExecuteMsg::Report {
+                address: USER.to_string(),
+                reason: "duplicate".to_string(),
+                evidence_hash: evidence_hash.clone(),
+            },
+            &coins(50, "ujuno"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::IncorrectBond {
+            bond: "100ujuno".to_string()
+        }
+    );
+
+    // Report both holders, and the first one twice.
+    for address in [USER, "user2", USER] {
+        app.execute_contract(
+            Addr::unchecked(reporter),
+            addr.clone(),
+            &ExecuteMsg::Report {
+                address: address.to_string(),
+                reason: "duplicate".to_string(),
+                evidence_hash: evidence_hash.clone(),
+            },
+            &coins(100, "ujuno"),
+        )
+        .unwrap();
+    }
+
+    let res: ListReportsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListReportsByTarget {
+                address: USER.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.reports.iter().map(|entry| entry.id).collect::<Vec<_>>(),
+        vec![1, 3]
+    );
+    assert_eq!(
+        res.reports[0].report,
+        Report {
+            reporter: Addr::unchecked(reporter),
+            target: Addr::unchecked(USER),
+            reason: "duplicate".to_string(),
+            evidence_hash: evidence_hash.clone(),
+            bond: Some(coin(100, "ujuno")),
+            status: ReportStatus::Open,
+        }
+    );
+
+    // Only the owner or moderator can resolve reports.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(reporter),
+            addr.clone(),
+            &ExecuteMsg::ResolveReport {
+                id: 1,
+                status: ReportStatus::Upheld,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+
+    // Slash the second report, which funds the reward for the first.
+    app.execute_contract(
+        Addr::unchecked(moderator),
+        addr.clone(),
+        &ExecuteMsg::ResolveReport {
+            id: 2,
+            status: ReportStatus::Slashed,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(moderator),
+        addr.clone(),
+        &ExecuteMsg::ResolveReport {
+            id: 1,
+            status: ReportStatus::Upheld,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(moderator),
+            addr.clone(),
+            &ExecuteMsg::ResolveReport {
+                id: 1,
+                status: ReportStatus::Rejected,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ReportResolved);
+
+    // Ensure the target was revoked, and the slashed holder was not.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.checkmark_id, None);
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: "user2".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.checkmark_id, Some("checkmark2".to_string()));
+
+    // Reject the duplicate report, refunding the bond.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::ResolveReport {
+            id: 3,
+            status: ReportStatus::Rejected,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Reporter paid three bonds, lost one, and got one reward.
+    let balance = app.wrap().query_balance(reporter, "ujuno").unwrap();
+    assert_eq!(balance, coin(250, "ujuno"));
+    let balance = app.wrap().query_balance(addr.clone(), "ujuno").unwrap();
+    assert_eq!(balance, coin(50, "ujuno"));
+
+    // Ensure the rest of the slashed bond remains in the report pool.
+    let res: ReportPoolResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::ReportPool {})
+        .unwrap();
+    assert_eq!(res.balances, coins(50, "ujuno"));
+
+    // Ensure reports are listed by status.
+    for (status, ids) in [
+        (ReportStatus::Open, vec![]),
+        (ReportStatus::Upheld, vec![1]),
+        (ReportStatus::Slashed, vec![2]),
+        (ReportStatus::Rejected, vec![3]),
+    ] {
+        let res: ListReportsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                addr.clone(),
+                &QueryMsg::ListReportsByStatus {
+                    status,
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            res.reports.iter().map(|entry| entry.id).collect::<Vec<_>>(),
+            ids
+        );
+    }
+
+    let res: ReportResponse = app
+        .wrap()
+        .query_wasm_smart(addr, &QueryMsg::Report { id: 2 })
+        .unwrap();
+    assert_eq!(res.report.unwrap().status, ReportStatus::Slashed);
+}
+
+#[test]
+pub fn test_report_pool() {
+    let (mut app, addr) = instantiate();
+
+    let reporter = "reporter";
+    let evidence_hash = hex::encode(Sha256::digest(b"evidence"));
+
+    app.init_modules(|router, _, storage| {
+        for (address, amount) in [(reporter, 200), (ANOTHER_USER, 100), (OWNER, 30)] {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked(address), coins(amount, "ujuno"))
+                .unwrap();
+        }
+    });
+
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Charge a fee, require a bond, and pay a reward.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            fees: Some(vec![Fee {
+                denom: Denom::Native("ujuno".to_string()),
+                amount: Uint128::new(100),
+            }]),
+            report_bond: Some(coin(100, "ujuno")),
+            report_reward: Some(coin(50, "ujuno")),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Escrow a verification fee in the contract.
+    app.execute_contract(
+        Addr::unchecked(ANOTHER_USER),
+        addr.clone(),
+        &ExecuteMsg::RequestVerification {
+            session_commitment: SESSION_COMMITMENT.to_string(),
+        },
+        &coins(100, "ujuno"),
+    )
+    .unwrap();
+
+    // Uphold a report while the pool is empty, which pays no reward.
+    app.execute_contract(
+        Addr::unchecked(reporter),
+        addr.clone(),
+        &ExecuteMsg::Report {
+            address: USER.to_string(),
+            reason: "duplicate".to_string(),
+            evidence_hash: evidence_hash.clone(),
+        },
+        &coins(100, "ujuno"),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::ResolveReport {
+            id: 1,
+            status: ReportStatus::Upheld,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let balance = app.wrap().query_balance(reporter, "ujuno").unwrap();
+    assert_eq!(balance, coin(200, "ujuno"));
+
+    // Ensure the escrowed fee can still be refunded in full.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::RejectVerification {
+            address: ANOTHER_USER.to_string(),
+            reason_code: 1,
+            refund: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let balance = app.wrap().query_balance(ANOTHER_USER, "ujuno").unwrap();
+    assert_eq!(balance, coin(100, "ujuno"));
+
+    // Ensure funds are required to fund the pool.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::FundReportPool {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Payment(cw_utils::PaymentError::NoFunds {})
+    );
+
+    // Fund the pool with less than the reward.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::FundReportPool {},
+        &coins(30, "ujuno"),
+    )
+    .unwrap();
+
+    // Assign another checkmark, without a fee.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            fees: Some(vec![]),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: "other".to_string(),
+            address: "user2".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure the reward is capped at the pool's balance.
+    app.execute_contract(
+        Addr::unchecked(reporter),
+        addr.clone(),
+        &ExecuteMsg::Report {
+            address: "user2".to_string(),
+            reason: "duplicate".to_string(),
+            evidence_hash,
+        },
+        &coins(100, "ujuno"),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::ResolveReport {
+            id: 2,
+            status: ReportStatus::Upheld,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let balance = app.wrap().query_balance(reporter, "ujuno").unwrap();
+    assert_eq!(balance, coin(230, "ujuno"));
+
+    let res: ReportPoolResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::ReportPool {})
+        .unwrap();
+    assert_eq!(res.balances, vec![]);
+    let balance = app.wrap().query_balance(addr, "ujuno").unwrap();
+    assert_eq!(balance, coin(0, "ujuno"));
+}
+
+#[test]
+pub fn test_revoke_issued_by() {
+    let (mut app, addr) = instantiate();
+
+    let compromised = "compromised";
+
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::GrantRole {
+            role: Role::Assigner,
+            address: compromised.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Assign one checkmark per block, alternating assigners.
+    let start_height = app.block_info().height;
+    for i in 0..6 {
+        let assigner = if i % 2 == 0 { compromised } else { ASSIGNER };
+        app.execute_contract(
+            Addr::unchecked(assigner),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: format!("checkmark{i}"),
+                address: format!("user{i}"),
+            },
+            &[],
+        )
+        .unwrap();
+        app.update_block(|block| block.height += 1);
+    }
+
+    // Ensure the dry run lists the compromised assigner's checkmarks in range.
+    let res: ListIssuedByResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListIssuedBy {
+                assigner: compromised.to_string(),
+                from_height: start_height + 1,
+                to_height: start_height + 5,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ListIssuedByResponse {
+            checkmarks: vec![
+                IssuedCheckmark {
+                    checkmark_id: "checkmark2".to_string(),
+                    address: Addr::unchecked("user2"),
+                    height: start_height + 2,
+                },
+                IssuedCheckmark {
+                    checkmark_id: "checkmark4".to_string(),
+                    address: Addr::unchecked("user4"),
+                    height: start_height + 4,
+                },
+            ]
+        }
+    );
+
+    let res: ListIssuedByResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListIssuedBy {
+                assigner: compromised.to_string(),
+                from_height: start_height,
+                to_height: start_height + 5,
+                start_after: Some((start_height + 2, "checkmark2".to_string())),
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.checkmarks
+            .iter()
+            .map(|checkmark| checkmark.checkmark_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["checkmark4"]
+    );
+
+    // Ensure pages resume after a checkmark revoked since the previous page.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::RevokeCheckmark {
+            checkmark_id: "checkmark2".to_string(),
+            reason: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: ListIssuedByResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListIssuedBy {
+                assigner: compromised.to_string(),
+                from_height: start_height,
+                to_height: start_height + 5,
+                start_after: Some((start_height + 2, "checkmark2".to_string())),
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.checkmarks.len(), 1);
+
+    // Only the owner can revoke.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::RevokeIssuedBy {
+                assigner: compromised.to_string(),
+                from_height: start_height,
+                to_height: start_height + 5,
+                limit: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+
+    // Revoke the rest in chunks of one.
+    let res = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::RevokeIssuedBy {
+                assigner: compromised.to_string(),
+                from_height: start_height,
+                to_height: start_height + 5,
+                limit: Some(1),
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm")
+            .add_attribute("method", "revoke_issued_by")
+            .add_attribute("assigner", compromised)
+            .add_attribute("revoked", "1")
+            .add_attribute("remaining", "true")
+    ));
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::RevokeIssuedBy {
+                assigner: compromised.to_string(),
+                from_height: start_height,
+                to_height: start_height + 5,
+                limit: Some(1),
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm")
+            .add_attribute("method", "revoke_issued_by")
+            .add_attribute("assigner", compromised)
+            .add_attribute("revoked", "1")
+            .add_attribute("remaining", "false")
+    ));
+
+    // Ensure only the compromised assigner's checkmarks were revoked.
+    let res: CountResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Count {})
+        .unwrap();
+    assert_eq!(res, CountResponse { count: 3 });
+
+    for i in 0..6 {
+        let res: GetCheckmarkResponse = app
+            .wrap()
+            .query_wasm_smart(
+                addr.clone(),
+                &QueryMsg::GetCheckmark {
+                    address: format!("user{i}"),
+                },
+            )
+            .unwrap();
+        assert_eq!(res.checkmark_id.is_some(), i % 2 == 1);
+    }
+
+    // Ensure the range is validated.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::RevokeIssuedBy {
+                assigner: compromised.to_string(),
+                from_height: start_height + 1,
+                to_height: start_height,
+                limit: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidHeightRange);
+
+    // The assigner also made an offer, queued an assignment, and approved
+    // pending assignments.
+    for (checkmark_id, address, update) in [
+        (
+            "checkmark6",
+            "user6",
+            UpdateConfigMsg {
+                require_consent: Some(true),
+                ..Default::default()
+            },
+        ),
+        (
+            "checkmark7",
+            "user7",
+            UpdateConfigMsg {
+                require_consent: Some(false),
+                mixing_epoch: Some(cw_utils::DAY),
+                ..Default::default()
+            },
+        ),
+    ] {
+        app.execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::UpdateConfig(update),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            Addr::unchecked(compromised),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: checkmark_id.to_string(),
+                address: address.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            mixing_epoch: Some(cw_utils::Duration::Time(0)),
+            assign_threshold: Some(3),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+    for (assigner, checkmark_id, address) in [
+        (compromised, "checkmark8", "user8"),
+        (compromised, "checkmark9", "user9"),
+        (ASSIGNER, "checkmark9", "user9"),
+    ] {
+        app.execute_contract(
+            Addr::unchecked(assigner),
+            addr.clone(),
+            &ExecuteMsg::ApproveAssign {
+                checkmark_id: checkmark_id.to_string(),
+                address: address.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Ensure they are withdrawn in chunks too.
+    let height = app.block_info().height;
+    let res = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::RevokeIssuedBy {
+                assigner: compromised.to_string(),
+                from_height: start_height,
+                to_height: height,
+                limit: Some(2),
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm")
+            .add_attribute("method", "revoke_issued_by")
+            .add_attribute("revoked", "0")
+            .add_attribute("offers_withdrawn", "1")
+            .add_attribute("queued_withdrawn", "1")
+            .add_attribute("approvals_withdrawn", "0")
+            .add_attribute("remaining", "true")
+    ));
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::RevokeIssuedBy {
+                assigner: compromised.to_string(),
+                from_height: start_height,
+                to_height: height,
+                limit: None,
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm")
+            .add_attribute("method", "revoke_issued_by")
+            .add_attribute("approvals_withdrawn", "2")
+            .add_attribute("remaining", "false")
+    ));
+
+    let res: OfferResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::Offer {
+                address: "user6".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.offer, None);
+
+    let res: MixingEpochResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::MixingEpoch {})
+        .unwrap();
+    assert_eq!(res.queued, 0);
+
+    let res: ListPendingAssignmentsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListPendingAssignments {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.assignments.len(), 1);
+    assert_eq!(res.assignments[0].checkmark_id, "checkmark9");
+    assert_eq!(
+        res.assignments[0].pending.approvals,
+        vec![Addr::unchecked(ASSIGNER)]
+    );
+
+    // Ensure the timelock and challenge period apply.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            assign_threshold: Some(1),
+            revocation_challenge_period: Some(cw_utils::DAY),
+            timelock_delay: Some(cw_utils::DAY),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(compromised),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: "checkmark10".to_string(),
+            address: "user10".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::RevokeIssuedBy {
+            assigner: compromised.to_string(),
+            from_height: start_height,
+            to_height: app.block_info().height,
+            limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+    let res = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::ExecuteTimelocked { id: 1 },
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm")
+            .add_attribute("method", "revoke_issued_by")
+            .add_attribute("revoked", "0")
+            .add_attribute("scheduled", "1")
+    ));
+
+    let res: ScheduledRevocationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ScheduledRevocation {
+                checkmark_id: "checkmark10".to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.revocation.is_some());
+
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr,
+            &QueryMsg::GetCheckmark {
+                address: "user10".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.checkmark_id, Some("checkmark10".to_string()));
+}
+
+#[test]
+pub fn test_revoke_issued_by_during_epoch() {
+    let (mut app, addr) = instantiate();
+
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            mixing_epoch: Some(cw_utils::DAY),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+    let start_height = app.block_info().height;
+
+    // Queue assignments and start processing the epoch, which moves queued
+    // operations around.
+    for i in 1..=4 {
+        app.execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: format!("checkmark{i}"),
+                address: format!("user{i}"),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        addr.clone(),
+        &ExecuteMsg::ProcessEpoch { limit: Some(1) },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure the assigner's remaining queued assignments are found.
+    let res = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::RevokeIssuedBy {
+                assigner: ASSIGNER.to_string(),
+                from_height: start_height,
+                to_height: app.block_info().height,
+                limit: None,
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm")
+            .add_attribute("method", "revoke_issued_by")
+            .add_attribute("revoked", "1")
+            .add_attribute("queued_withdrawn", "3")
+            .add_attribute("remaining", "false")
+    ));
+
+    let res: MixingEpochResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::MixingEpoch {})
+        .unwrap();
+    assert_eq!(res.queued, 0);
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            addr,
+            &ExecuteMsg::ProcessEpoch { limit: None },
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm")
+            .add_attribute("method", "process_epoch")
+            .add_attribute("applied", "0")
+            .add_attribute("remaining", "false")
+    ));
+}
+
+#[test]
+pub fn test_delayed_deletion() {
+    let (mut app, addr) = instantiate();
+
+    // Assign checkmark.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Enable a one day deletion delay.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            deletion_delay: Some(cw_utils::DAY),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Schedule deletion.
+    let finalize_after = cw_utils::DAY.after(&app.block_info());
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::Delete {},
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::Delete {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::DeletionAlreadyScheduled);
+
+    // Ensure the checkmark is kept and shown as pending deletion.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: Some(CHECKMARK.to_string()),
+            suspension: None,
+            pending_deletion: Some(finalize_after),
+            organization: false,
+        }
+    );
+
+    // Cancel the deletion, and ensure it cannot be finalized.
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::CancelDelete {},
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            addr.clone(),
+            &ExecuteMsg::FinalizeDelete {
+                address: USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoScheduledDeletion);
+
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.pending_deletion, None);
+
+    // Schedule again, and ensure it cannot be finalized early.
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::Delete {},
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            addr.clone(),
+            &ExecuteMsg::FinalizeDelete {
+                address: USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::DeletionNotReady);
+
+    // Anyone finalizes after the delay.
+    app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        addr.clone(),
+        &ExecuteMsg::FinalizeDelete {
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: None,
+            suspension: None,
+            pending_deletion: None,
+            organization: false,
+        }
+    );
+
+    let res: CountResponse = app
+        .wrap()
+        .query_wasm_smart(addr, &QueryMsg::Count {})
+        .unwrap();
+    assert_eq!(res, CountResponse { count: 0 });
+}
+
+#[test]
+pub fn test_consent() {
+    let (mut app, addr) = instantiate();
+
+    // Require consent for assignments.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            require_consent: Some(true),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Assign checkmark, which offers it instead.
+    let expiration = cw_utils::WEEK.after(&app.block_info());
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.checkmark_id, None);
+
+    let res: OfferResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::Offer {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.offer,
+        Some(Offer {
+            checkmark_id: CHECKMARK.to_string(),
+            issuance: Issuance {
+                assigner: Addr::unchecked(ASSIGNER),
+                height: app.block_info().height,
+            },
+            expiration,
+            payment: None,
+            reassign_from: None,
+        })
+    );
+
+    // Ensure the offered checkmark cannot be offered to someone else.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: CHECKMARK.to_string(),
+                address: ANOTHER_USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::CheckmarkOffered);
+
+    // Reject the offer, and ensure it is gone.
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::RejectCheckmark {},
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::AcceptCheckmark {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoOffer);
+
+    // Offer again, and ensure it cannot be accepted after it expires.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(8 * 24 * 60 * 60));
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::AcceptCheckmark {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::OfferExpired);
+
+    // Offer again, replacing the expired offer, and accept it.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::AcceptCheckmark {},
+        &[],
+    )
+    .unwrap();
+
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.checkmark_id, Some(CHECKMARK.to_string()));
+
+    let res: OfferResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::Offer {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.offer, None);
+
+    // Opt out, and ensure ANOTHER_USER cannot be offered a checkmark.
+    app.execute_contract(
+        Addr::unchecked(ANOTHER_USER),
+        addr.clone(),
+        &ExecuteMsg::OptOut {},
+        &[],
+    )
+    .unwrap();
+
+    let res: OptedOutResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::OptedOut {
+                address: ANOTHER_USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.opted_out);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: "other".to_string(),
+                address: ANOTHER_USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::OptedOut);
+
+    // Opt back in, and ensure the offer works.
+    app.execute_contract(
+        Addr::unchecked(ANOTHER_USER),
+        addr.clone(),
+        &ExecuteMsg::OptIn {},
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: "other".to_string(),
+            address: ANOTHER_USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+pub fn test_reassign_cooldown() {
+    let (mut app, addr) = instantiate();
+
+    // Enable a one day re-assignment cooldown.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            reassign_cooldown: Some(cw_utils::DAY),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Assign checkmark, and ensure no cooldown yet.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: ReassignCooldownResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ReassignCooldown {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ReassignCooldownResponse {
+            ends: None,
+            remaining: None,
+        }
+    );
+
+    // Delete checkmark, starting the cooldown.
+    let ends = cw_utils::DAY.after(&app.block_info());
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::Delete {},
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: CHECKMARK.to_string(),
+                address: USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::ReassignCooldown {
+            ends: ends.to_string()
+        }
+    );
+
+    // Ensure the remaining cooldown shrinks as time passes.
+    app.update_block(|block| block.time = block.time.plus_seconds(60 * 60));
+
+    let res: ReassignCooldownResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ReassignCooldown {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ReassignCooldownResponse {
+            ends: Some(ends),
+            remaining: Some(cw_utils::Duration::Time(23 * 60 * 60)),
+        }
+    );
+
+    // Ensure another address is unaffected.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: "other".to_string(),
+            address: ANOTHER_USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Revoke ANOTHER_USER's checkmark, and ensure it is in cooldown too.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::RevokeAddress {
+            address: ANOTHER_USER.to_string(),
+            reason: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: "other".to_string(),
+                address: ANOTHER_USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::ReassignCooldown { .. }));
+
+    // Ensure USER can be assigned once the cooldown ends.
+    app.update_block(|block| block.time = block.time.plus_seconds(23 * 60 * 60));
+
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+pub fn test_mixing_epoch() {
+    let (mut app, addr) = instantiate();
+
+    // Enable mixing mode with one day epochs.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            mixing_epoch: Some(cw_utils::DAY),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+    let next_epoch = cw_utils::DAY.after(&app.block_info());
+
+    // Queue assignments.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: "other".to_string(),
+            address: ANOTHER_USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: CHECKMARK.to_string(),
+                address: "third_user".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AlreadyQueued);
+
+    // Ensure nothing is assigned until the epoch is processed.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.checkmark_id, None);
+
+    let res: MixingEpochResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::MixingEpoch {})
+        .unwrap();
+    assert_eq!(
+        res,
+        MixingEpochResponse {
+            queued: 2,
+            next_epoch: Some(next_epoch),
+            processing: false,
+        }
+    );
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            addr.clone(),
+            &ExecuteMsg::ProcessEpoch { limit: None },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::EpochNotEnded);
+
+    // Process the epoch in batches once it ends.
+    app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+    let res = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            addr.clone(),
+            &ExecuteMsg::ProcessEpoch { limit: Some(1) },
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm")
+            .add_attribute("method", "process_epoch")
+            .add_attribute("applied", "1")
+            .add_attribute("remaining", "true")
+    ));
+
+    // Ensure assignments queued meanwhile wait for the next epoch.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: "third".to_string(),
+            address: "third_user".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: MixingEpochResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::MixingEpoch {})
+        .unwrap();
+    assert_eq!(
+        res,
+        MixingEpochResponse {
+            queued: 2,
+            next_epoch: Some(cw_utils::DAY.after(&app.block_info())),
+            processing: true,
+        }
+    );
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            addr.clone(),
+            &ExecuteMsg::ProcessEpoch { limit: None },
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm")
+            .add_attribute("method", "process_epoch")
+            .add_attribute("applied", "1")
+            .add_attribute("remaining", "false")
+    ));
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            addr.clone(),
+            &ExecuteMsg::ProcessEpoch { limit: None },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::EpochNotEnded);
+
+    for (address, checkmark_id) in [(USER, CHECKMARK), (ANOTHER_USER, "other")] {
+        let res: GetCheckmarkResponse = app
+            .wrap()
+            .query_wasm_smart(
+                addr.clone(),
+                &QueryMsg::GetCheckmark {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(res.checkmark_id, Some(checkmark_id.to_string()));
+    }
+
+    let res: MixingEpochResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::MixingEpoch {})
+        .unwrap();
+    assert_eq!(
+        res,
+        MixingEpochResponse {
+            queued: 1,
+            next_epoch: Some(cw_utils::DAY.after(&app.block_info())),
+            processing: false,
+        }
+    );
+
+    // Queue deletion, and ensure it is applied at the end of the epoch.
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::Delete {},
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::Delete {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AlreadyQueued);
+
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.checkmark_id, Some(CHECKMARK.to_string()));
+
+    app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        addr.clone(),
+        &ExecuteMsg::ProcessEpoch { limit: None },
+        &[],
+    )
+    .unwrap();
+
+    for (address, checkmark_id) in [(USER, None), ("third_user", Some("third"))] {
+        let res: GetCheckmarkResponse = app
+            .wrap()
+            .query_wasm_smart(
+                addr.clone(),
+                &QueryMsg::GetCheckmark {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(res.checkmark_id.as_deref(), checkmark_id);
+    }
+}
+
+#[test]
+pub fn test_checkmark_id_tombstones() {
+    let (mut app, addr) = instantiate();
+
+    // Ensure the checkmark ID was never issued.
+    let res: CheckmarkIssuedResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::CheckmarkIssued {
+                checkmark_id: CHECKMARK.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(!res.issued);
+
+    // Prevent reuse of checkmark IDs.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            prevent_id_reuse: Some(true),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Assign and delete checkmark.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::Delete {},
+        &[],
+    )
+    .unwrap();
+
+    // Ensure the checkmark ID is tombstoned and cannot be assigned again.
+    let res: CheckmarkIssuedResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::CheckmarkIssued {
+                checkmark_id: CHECKMARK.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.issued);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: CHECKMARK.to_string(),
+                address: ANOTHER_USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::CheckmarkIdReused);
+
+    // Allow reuse, and ensure the checkmark ID can be assigned again.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            prevent_id_reuse: Some(false),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: ANOTHER_USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+pub fn test_rotate_checkmark() {
+    let (mut app, addr) = instantiate();
+    let fresh_checkmark = "fresh_checkmark";
+
+    // Assign checkmarks.
+    let height = app.block_info().height;
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: "other".to_string(),
+            address: ANOTHER_USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Only the owner or an assigner can rotate.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::RotateCheckmark {
+                address: USER.to_string(),
+                new_checkmark_id: fresh_checkmark.to_string(),
+                reissue: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized);
+
+    // Cannot rotate to an assigned checkmark ID.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::RotateCheckmark {
+                address: USER.to_string(),
+                new_checkmark_id: "other".to_string(),
+                reissue: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AlreadyAssigned);
+
+    // Ensure an assigner cannot rotate alone when approvals are required.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            assign_threshold: Some(2),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::RotateCheckmark {
+                address: USER.to_string(),
+                new_checkmark_id: fresh_checkmark.to_string(),
+                reissue: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ApprovalRequired);
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            assign_threshold: Some(1),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Rotate.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::RotateCheckmark {
+            address: USER.to_string(),
+            new_checkmark_id: fresh_checkmark.to_string(),
+            reissue: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure the new checkmark ID replaced the old one.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.checkmark_id, Some(fresh_checkmark.to_string()));
+
+    let res: GetAddressResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetAddress {
+                checkmark_id: CHECKMARK.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.address, None);
+
+    let res: GetAddressResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetAddress {
+                checkmark_id: fresh_checkmark.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.address, Some(Addr::unchecked(USER)));
+
+    // Ensure the count is unchanged and the old ID is tombstoned.
+    let res: CountResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Count {})
+        .unwrap();
+    assert_eq!(res, CountResponse { count: 2 });
+
+    let res: CheckmarkIssuedResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::CheckmarkIssued {
+                checkmark_id: CHECKMARK.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.issued);
+
+    // Ensure the issuance moved to the new ID.
+    let res: ListIssuedByResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListIssuedBy {
+                assigner: ASSIGNER.to_string(),
+                from_height: height,
+                to_height: height,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.checkmarks,
+        vec![
+            IssuedCheckmark {
+                checkmark_id: fresh_checkmark.to_string(),
+                address: Addr::unchecked(USER),
+                height,
+            },
+            IssuedCheckmark {
+                checkmark_id: "other".to_string(),
+                address: Addr::unchecked(ANOTHER_USER),
+                height,
+            },
+        ]
+    );
+
+    // Ensure the holder still resolves as a voter.
+    let res: ResolveVoterResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ResolveVoter {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ResolveVoterResponse {
+            principal: Some(Addr::unchecked(USER)),
+            checkmark_id: Some(fresh_checkmark.to_string()),
+        }
+    );
+}
+
+#[test]
+pub fn test_reassign() {
+    let (mut app, addr) = instantiate();
+
+    // Assign checkmarks.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: "other".to_string(),
+            address: "third_user".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Only the owner or an assigner can reassign.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::ReassignCheckmark {
+                checkmark_id: CHECKMARK.to_string(),
+                address: ANOTHER_USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized);
+
+    // Cannot move a checkmark to an address that has one.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::ReassignCheckmark {
+                checkmark_id: CHECKMARK.to_string(),
+                address: "third_user".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AlreadyHasCheckmark);
+
+    // USER schedules the deletion of its checkmark, and the owner schedules
+    // its revocation.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            deletion_delay: Some(cw_utils::DAY),
+            revocation_challenge_period: Some(cw_utils::DAY),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::Delete {},
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::RevokeCheckmark {
+            checkmark_id: CHECKMARK.to_string(),
+            reason: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Move the checkmark to ANOTHER_USER.
+    let res = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::ReassignCheckmark {
+                checkmark_id: CHECKMARK.to_string(),
+                address: ANOTHER_USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm")
+            .add_attribute("method", "reassign_checkmark")
+            .add_attribute("checkmark_id", CHECKMARK)
+            .add_attribute("old_address", USER)
+            .add_attribute("address", ANOTHER_USER)
+    ));
+
+    for (address, checkmark_id) in [(USER, None), (ANOTHER_USER, Some(CHECKMARK))] {
+        let res: GetCheckmarkResponse = app
+            .wrap()
+            .query_wasm_smart(
+                addr.clone(),
+                &QueryMsg::GetCheckmark {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(res.checkmark_id, checkmark_id.map(ToString::to_string));
+    }
+
+    // Ensure the old holder's scheduled deletion and revocation did not move
+    // with the checkmark.
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: ANOTHER_USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.pending_deletion, None);
+
+    let res: ScheduledRevocationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ScheduledRevocation {
+                checkmark_id: CHECKMARK.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.revocation, None);
+
+    app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            addr.clone(),
+            &ExecuteMsg::FinalizeDelete {
+                address: ANOTHER_USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoScheduledDeletion);
+
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            deletion_delay: Some(cw_utils::Duration::Time(0)),
+            revocation_challenge_period: Some(cw_utils::Duration::Time(0)),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Ensure reassignments cannot bypass mixing mode.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            mixing_epoch: Some(cw_utils::DAY),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::ReassignCheckmark {
+                checkmark_id: CHECKMARK.to_string(),
+                address: "fourth_user".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::MixingEnabled);
+
+    // Ensure reassignments are offered when consent is required, and the old
+    // address keeps the checkmark until the offer is accepted.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            mixing_epoch: Some(cw_utils::Duration::Time(0)),
+            require_consent: Some(true),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+    for (recipient, accept) in [("fourth_user", false), ("fourth_user", true)] {
+        let res = app
+            .execute_contract(
+                Addr::unchecked(ASSIGNER),
+                addr.clone(),
+                &ExecuteMsg::ReassignCheckmark {
+                    checkmark_id: CHECKMARK.to_string(),
+                    address: recipient.to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+        assert!(res.has_event(
+            &cosmwasm_std::Event::new("wasm")
+                .add_attribute("method", "reassign_checkmark")
+                .add_attribute("offered", "true")
+        ));
+
+        let res: GetCheckmarkResponse = app
+            .wrap()
+            .query_wasm_smart(
+                addr.clone(),
+                &QueryMsg::GetCheckmark {
+                    address: ANOTHER_USER.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(res.checkmark_id, Some(CHECKMARK.to_string()));
+
+        let msg = if accept {
+            ExecuteMsg::AcceptCheckmark {}
+        } else {
+            ExecuteMsg::RejectCheckmark {}
+        };
+        app.execute_contract(Addr::unchecked(recipient), addr.clone(), &msg, &[])
+            .unwrap();
+    }
+
+    for (address, checkmark_id) in [(ANOTHER_USER, None), ("fourth_user", Some(CHECKMARK))] {
+        let res: GetCheckmarkResponse = app
+            .wrap()
+            .query_wasm_smart(
+                addr.clone(),
+                &QueryMsg::GetCheckmark {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(res.checkmark_id, checkmark_id.map(ToString::to_string));
+    }
+
+    // Move it back to ANOTHER_USER.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            require_consent: Some(false),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::ReassignCheckmark {
+            checkmark_id: CHECKMARK.to_string(),
+            address: ANOTHER_USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Cannot replace an address's checkmark ID with an assigned one.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::RotateCheckmark {
+                address: ANOTHER_USER.to_string(),
+                new_checkmark_id: "other".to_string(),
+                reissue: Some(true),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AlreadyAssigned);
+
+    // Replace ANOTHER_USER's checkmark ID, recording the owner as its issuer.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::RotateCheckmark {
+            address: ANOTHER_USER.to_string(),
+            new_checkmark_id: "corrected".to_string(),
+            reissue: Some(true),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: ListIssuedByResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListIssuedBy {
+                assigner: OWNER.to_string(),
+                from_height: 0,
+                to_height: app.block_info().height,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.checkmarks
+            .iter()
+            .map(|checkmark| checkmark.checkmark_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["corrected"]
+    );
+
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: ANOTHER_USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.checkmark_id, Some("corrected".to_string()));
+
+    // Ensure the mistaken checkmark ID is unassigned but still tombstoned, and
+    // the count is unchanged.
+    let res: CheckmarkIssuedResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::CheckmarkIssued {
+                checkmark_id: CHECKMARK.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.issued);
+
+    let res: CountResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Count {})
+        .unwrap();
+    assert_eq!(res, CountResponse { count: 2 });
+
+    // Ensure reassigning cannot launder an ID for reuse.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            prevent_id_reuse: Some(true),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr,
+            &ExecuteMsg::Assign {
+                checkmark_id: CHECKMARK.to_string(),
+                address: "third_user".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::CheckmarkIdReused);
+}
+
+#[test]
+pub fn test_contract_policy() {
+    let (mut app, addr) = instantiate();
+
+    // Instantiate another contract to checkmark.
+    let code_id = app.store_code(setup_contract());
+    let dao = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(OWNER),
+            &InstantiateMsg {
+                owner: Some(OWNER.to_string()),
+                assigner: ASSIGNER.to_string(),
+            },
+            &[],
+            "dao",
+            None,
+        )
+        .unwrap();
+
+    // Reject contracts.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            reject_contracts: Some(true),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: CHECKMARK.to_string(),
+                address: dao.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ContractAddress);
+
+    // Ensure non-contract addresses are unaffected.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Ensure organization checkmarks can only be assigned to contracts.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::AssignOrganization {
+                checkmark_id: "dao".to_string(),
+                address: ANOTHER_USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NotContract);
+
+    // Assign an organization checkmark to the contract.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::AssignOrganization {
+            checkmark_id: "dao".to_string(),
+            address: dao.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: dao.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: Some("dao".to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: true,
+        }
+    );
+
+    let res: ListOrganizationsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListOrganizations {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.organizations,
+        vec![OrganizationEntry {
+            checkmark_id: "dao".to_string(),
+            address: dao.clone(),
+        }]
+    );
+
+    // Ensure organization checkmarks cannot vote.
+    let res: ResolveVoterResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ResolveVoter {
+                address: dao.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ResolveVoterResponse {
+            principal: None,
+            checkmark_id: None,
+        }
+    );
+
+    // Organization checkmarks are left out of the counts.
+    let res: CountResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Count {})
+        .unwrap();
+    assert_eq!(res, CountResponse { count: 1 });
+
+    let res: CountResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::ActiveCount {})
+        .unwrap();
+    assert_eq!(res, CountResponse { count: 1 });
+
+    // Ensure the organization checkmark cannot be reassigned to a
+    // non-contract.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::ReassignCheckmark {
+                checkmark_id: "dao".to_string(),
+                address: ANOTHER_USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NotContract);
+
+    // Reassign the organization checkmark to another contract, and ensure it
+    // stays an organization.
+    let new_dao = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(OWNER),
+            &InstantiateMsg {
+                owner: Some(OWNER.to_string()),
+                assigner: ASSIGNER.to_string(),
+            },
+            &[],
+            "new dao",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::ReassignCheckmark {
+            checkmark_id: "dao".to_string(),
+            address: new_dao.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: GetCheckmarkResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::GetCheckmark {
+                address: new_dao.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        GetCheckmarkResponse {
+            checkmark_id: Some("dao".to_string()),
+            suspension: None,
+            pending_deletion: None,
+            organization: true,
+        }
+    );
+
+    let res: ResolveVoterResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ResolveVoter {
+                address: new_dao.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ResolveVoterResponse {
+            principal: None,
+            checkmark_id: None,
+        }
+    );
+
+    let res: CountResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::Count {})
+        .unwrap();
+    assert_eq!(res, CountResponse { count: 1 });
+
+    // Allow-list this contract, and ensure it can be assigned an individual
+    // checkmark.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateAllowedContracts {
+            add: Some(vec![addr.to_string()]),
+            remove: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: ListAllowedContractsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListAllowedContracts {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.contracts, vec![addr.clone()]);
+
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: "allowed".to_string(),
+            address: addr.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Only the owner or config admin can update the allow-list.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::UpdateAllowedContracts {
+                add: None,
+                remove: Some(vec![addr.to_string()]),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+}
+
+#[test]
+pub fn test_fees() {
+    let (mut app, addr) = instantiate();
+    let token = Addr::unchecked("token");
+
+    app.init_modules(|router, _, storage| {
+        for (address, amount) in [(ASSIGNER, 300), (ANOTHER_USER, 100)] {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked(address), coins(amount, "ujuno"))
+                .unwrap();
+        }
+    });
+
+    // Charge fees in ujuno or cw20 tokens, split between the provider,
+    // operator, and community pool.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            fees: Some(vec![
+                Fee {
+                    denom: Denom::Native("ujuno".to_string()),
+                    amount: Uint128::new(100),
+                },
+                Fee {
+                    denom: Denom::Cw20(token.clone()),
+                    amount: Uint128::new(5),
+                },
+            ]),
+            fee_split: Some(FeeSplit {
+                provider: Addr::unchecked("provider"),
+                provider_share: Decimal::percent(50),
+                operator: Addr::unchecked("operator"),
+                operator_share: Decimal::percent(30),
+                community_pool: Addr::unchecked("community_pool"),
+            }),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Ensure a fee is required.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: CHECKMARK.to_string(),
+                address: USER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::IncorrectFee {
+            fees: "100ujuno, 5 token".to_string()
+        }
+    );
+
+    // Assign, paying the fee.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &coins(100, "ujuno"),
+    )
+    .unwrap();
+
+    // ANOTHER_USER pays in advance by requesting verification.
+    app.execute_contract(
+        Addr::unchecked(ANOTHER_USER),
+        addr.clone(),
+        &ExecuteMsg::RequestVerification {
+            session_commitment: SESSION_COMMITMENT.to_string(),
+        },
+        &coins(100, "ujuno"),
+    )
+    .unwrap();
+
+    let res: VerificationRequestResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::VerificationRequest {
+                address: ANOTHER_USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.request,
+        Some(VerificationRequest {
+            session_commitment: SESSION_COMMITMENT.to_string(),
+            fee: Some(Fee {
+                denom: Denom::Native("ujuno".to_string()),
+                amount: Uint128::new(100),
+            }),
+            status: VerificationStatus::Pending,
+        })
+    );
+
+    // Ensure the assigner cannot pay again, or pay the wrong amount.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: "other".to_string(),
+                address: ANOTHER_USER.to_string(),
+            },
+            &coins(100, "ujuno"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::FeeAlreadyPaid);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: "other".to_string(),
+                address: "user2".to_string(),
+            },
+            &coins(50, "ujuno"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::IncorrectFee { .. }));
+
+    // Assign, collecting the fee from the request.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: "other".to_string(),
+            address: ANOTHER_USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: VerificationRequestResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::VerificationRequest {
+                address: ANOTHER_USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.request.map(|request| request.status),
+        Some(VerificationStatus::Fulfilled)
+    );
+
+    // Assign, paying with cw20 tokens.
+    app.execute_contract(
+        token.clone(),
+        addr.clone(),
+        &ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: ASSIGNER.to_string(),
+            amount: Uint128::new(5),
+            msg: to_binary(&ReceiveMsg::Assign {
+                checkmark_id: "third".to_string(),
+                address: "third_user".to_string(),
+            })
+            .unwrap(),
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Ensure organization checkmarks are charged too.
+    let code_id = app.store_code(setup_contract());
+    let dao = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(OWNER),
+            &InstantiateMsg {
+                owner: Some(OWNER.to_string()),
+                assigner: ASSIGNER.to_string(),
+            },
+            &[],
+            "dao",
+            None,
+        )
+        .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::AssignOrganization {
+                checkmark_id: "dao".to_string(),
+                address: dao.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::IncorrectFee { .. }));
+
+    app.execute_contract(
+        token.clone(),
+        addr.clone(),
+        &ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: ASSIGNER.to_string(),
+            amount: Uint128::new(5),
+            msg: to_binary(&ReceiveMsg::AssignOrganization {
+                checkmark_id: "dao".to_string(),
+                address: dao.to_string(),
+            })
+            .unwrap(),
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let res: FeeBalancesResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::FeeBalances {})
+        .unwrap();
+    assert_eq!(
+        res.balances,
+        vec![
+            Fee {
+                denom: Denom::Cw20(token),
+                amount: Uint128::new(10),
+            },
+            Fee {
+                denom: Denom::Native("ujuno".to_string()),
+                amount: Uint128::new(200),
+            },
+        ]
+    );
+
+    // Only the owner can withdraw.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::WithdrawFees {
+                denom: Denom::Native("ujuno".to_string()),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+
+    // Withdraw, and ensure the fees are split.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::WithdrawFees {
+            denom: Denom::Native("ujuno".to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+
+    for (address, amount) in [
+        ("provider", 100),
+        ("operator", 60),
+        ("community_pool", 40),
+        (addr.as_str(), 0),
+    ] {
+        let balance = app.wrap().query_balance(address, "ujuno").unwrap();
+        assert_eq!(balance, coin(amount, "ujuno"));
+    }
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            addr.clone(),
+            &ExecuteMsg::WithdrawFees {
+                denom: Denom::Native("ujuno".to_string()),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoFeeBalance);
+}
+
+#[test]
+pub fn test_held_fees() {
+    let (mut app, addr) = instantiate();
+
+    app.init_modules(|router, _, storage| {
+        for (address, amount) in [(ASSIGNER, 300), (ANOTHER_USER, 100), ("requester", 100)] {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked(address), coins(amount, "ujuno"))
+                .unwrap();
+        }
+    });
+
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            fees: Some(vec![Fee {
+                denom: Denom::Native("ujuno".to_string()),
+                amount: Uint128::new(100),
+            }]),
+            require_consent: Some(true),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let request_status = |app: &App, address: &str| {
+        let res: VerificationRequestResponse = app
+            .wrap()
+            .query_wasm_smart(
+                addr.clone(),
+                &QueryMsg::VerificationRequest {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+        res.request.map(|request| request.status)
+    };
+    let collected = |app: &App| {
+        let res: FeeBalancesResponse = app
+            .wrap()
+            .query_wasm_smart(addr.clone(), &QueryMsg::FeeBalances {})
+            .unwrap();
+        res.balances
+            .into_iter()
+            .map(|balance| balance.amount.u128())
+            .sum::<u128>()
+    };
+    let assigner_balance = |app: &App| {
+        app.wrap()
+            .query_balance(ASSIGNER, "ujuno")
+            .unwrap()
+            .amount
+            .u128()
+    };
+
+    // Offer a checkmark to an address that requested verification, and ensure
+    // its escrowed fee is held rather than collected.
+    app.execute_contract(
+        Addr::unchecked(ANOTHER_USER),
+        addr.clone(),
+        &ExecuteMsg::RequestVerification {
+            session_commitment: SESSION_COMMITMENT.to_string(),
+        },
+        &coins(100, "ujuno"),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: ANOTHER_USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        request_status(&app, ANOTHER_USER),
+        Some(VerificationStatus::Assigning)
+    );
+    assert_eq!(collected(&app), 0);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ANOTHER_USER),
+            addr.clone(),
+            &ExecuteMsg::RequestVerification {
+                session_commitment: SESSION_COMMITMENT.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AlreadyRequested);
+
+    // Ensure the request cannot be cancelled while its fee is held.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ANOTHER_USER),
+            addr.clone(),
+            &ExecuteMsg::CancelVerification {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoVerificationRequest);
+
+    // Ensure rejecting the offer makes the request pending again.
+    app.execute_contract(
+        Addr::unchecked(ANOTHER_USER),
+        addr.clone(),
+        &ExecuteMsg::RejectCheckmark {},
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        request_status(&app, ANOTHER_USER),
+        Some(VerificationStatus::Pending)
+    );
+    assert_eq!(collected(&app), 0);
+
+    // Ensure the assigner is refunded if an offer they paid for is rejected.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &coins(100, "ujuno"),
+    )
+    .unwrap();
+    assert_eq!(assigner_balance(&app), 200);
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::RejectCheckmark {},
+        &[],
+    )
+    .unwrap();
+    assert_eq!(assigner_balance(&app), 300);
+    assert_eq!(collected(&app), 0);
+
+    // Ensure the assigner is refunded if an offer they paid for expires and is
+    // replaced.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: USER.to_string(),
+        },
+        &coins(100, "ujuno"),
+    )
+    .unwrap();
+    app.update_block(|block| block.time = block.time.plus_seconds(8 * 24 * 60 * 60));
+
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: "other".to_string(),
+            address: USER.to_string(),
+        },
+        &coins(100, "ujuno"),
+    )
+    .unwrap();
+    assert_eq!(assigner_balance(&app), 200);
+    assert_eq!(collected(&app), 0);
+
+    // Ensure fees are collected once offers are accepted.
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::AcceptCheckmark {},
+        &[],
+    )
+    .unwrap();
+    assert_eq!(collected(&app), 100);
+
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: CHECKMARK.to_string(),
+            address: ANOTHER_USER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ANOTHER_USER),
+        addr.clone(),
+        &ExecuteMsg::AcceptCheckmark {},
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        request_status(&app, ANOTHER_USER),
+        Some(VerificationStatus::Fulfilled)
+    );
+    assert_eq!(collected(&app), 200);
+
+    // Queue assignments in mixing mode, paid by the assigner and by a request.
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            require_consent: Some(false),
+            mixing_epoch: Some(cw_utils::DAY),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("requester"),
+        addr.clone(),
+        &ExecuteMsg::RequestVerification {
+            session_commitment: SESSION_COMMITMENT.to_string(),
+        },
+        &coins(100, "ujuno"),
+    )
+    .unwrap();
+    for (checkmark_id, address, funds) in [
+        ("third", "third_user", coins(100, "ujuno")),
+        ("fourth", "requester", vec![]),
+    ] {
+        app.execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::Assign {
+                checkmark_id: checkmark_id.to_string(),
+                address: address.to_string(),
+            },
+            &funds,
+        )
+        .unwrap();
+    }
+    assert_eq!(assigner_balance(&app), 100);
+    assert_eq!(
+        request_status(&app, "requester"),
+        Some(VerificationStatus::Assigning)
+    );
+
+    // Ensure the fees are released if the assignments are skipped.
+    for address in ["third_user", "requester"] {
+        app.execute_contract(
+            Addr::unchecked(address),
+            addr.clone(),
+            &ExecuteMsg::OptOut {},
+            &[],
+        )
+        .unwrap();
+    }
+
+    app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+    let res = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            addr.clone(),
+            &ExecuteMsg::ProcessEpoch { limit: None },
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm")
+            .add_attribute("method", "process_epoch")
+            .add_attribute("skipped", "2")
+    ));
+    assert_eq!(assigner_balance(&app), 200);
+    assert_eq!(
+        request_status(&app, "requester"),
+        Some(VerificationStatus::Pending)
+    );
+    assert_eq!(collected(&app), 200);
+
+    // Ensure the fee is collected once a queued assignment is applied.
+    app.execute_contract(
+        Addr::unchecked("requester"),
+        addr.clone(),
+        &ExecuteMsg::OptIn {},
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::Assign {
+            checkmark_id: "fourth".to_string(),
+            address: "requester".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(24 * 60 * 60));
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        addr.clone(),
+        &ExecuteMsg::ProcessEpoch { limit: None },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        request_status(&app, "requester"),
+        Some(VerificationStatus::Fulfilled)
+    );
+    assert_eq!(collected(&app), 300);
+}
+
+#[test]
+pub fn test_verification_requests() {
+    let (mut app, addr) = instantiate();
+
+    app.init_modules(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &Addr::unchecked(USER), coins(100, "ujuno"))
+            .unwrap();
+    });
+
+    // Requests are free when no fees are configured.
+    app.execute_contract(
+        Addr::unchecked(ANOTHER_USER),
+        addr.clone(),
+        &ExecuteMsg::RequestVerification {
+            session_commitment: SESSION_COMMITMENT.to_uppercase(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: VerificationRequestResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::VerificationRequest {
+                address: ANOTHER_USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.request,
+        Some(VerificationRequest {
+            session_commitment: SESSION_COMMITMENT.to_string(),
+            fee: None,
+            status: VerificationStatus::Pending,
+        })
+    );
+
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            fees: Some(vec![Fee {
+                denom: Denom::Native("ujuno".to_string()),
+                amount: Uint128::new(100),
+            }]),
+            ..Default::default()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Ensure the session commitment is a SHA-256 hash.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::RequestVerification {
+                session_commitment: "session".to_string(),
+            },
+            &coins(100, "ujuno"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidSessionCommitment);
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::RequestVerification {
+            session_commitment: SESSION_COMMITMENT.to_string(),
+        },
+        &coins(100, "ujuno"),
+    )
+    .unwrap();
+
+    // Ensure only one request can be pending at a time.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::RequestVerification {
+                session_commitment: SESSION_COMMITMENT.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AlreadyRequested);
+
+    let res: ListVerificationRequestsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListPendingVerificationRequests {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.requests
+            .iter()
+            .map(|entry| entry.address.as_str())
+            .collect::<Vec<_>>(),
+        vec![ANOTHER_USER, USER]
+    );
+
+    // Only the owner or an assigner can reject.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ANOTHER_USER),
+            addr.clone(),
+            &ExecuteMsg::RejectVerification {
+                address: USER.to_string(),
+                reason_code: 1,
+                refund: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized);
+
+    // Ensure the refund cannot exceed the fee.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::RejectVerification {
+                address: USER.to_string(),
+                reason_code: 1,
+                refund: Some(Uint128::new(101)),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidRefund);
+
+    // Reject, refunding part of the fee and collecting the rest.
+    app.execute_contract(
+        Addr::unchecked(ASSIGNER),
+        addr.clone(),
+        &ExecuteMsg::RejectVerification {
+            address: USER.to_string(),
+            reason_code: 1,
+            refund: Some(Uint128::new(70)),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let balance = app.wrap().query_balance(USER, "ujuno").unwrap();
+    assert_eq!(balance, coin(70, "ujuno"));
+
+    let res: FeeBalancesResponse = app
+        .wrap()
+        .query_wasm_smart(addr.clone(), &QueryMsg::FeeBalances {})
+        .unwrap();
+    assert_eq!(
+        res.balances,
+        vec![Fee {
+            denom: Denom::Native("ujuno".to_string()),
+            amount: Uint128::new(30),
+        }]
+    );
+
+    let res: VerificationRequestResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::VerificationRequest {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.request.map(|request| request.status),
+        Some(VerificationStatus::Rejected {
+            reason_code: 1,
+            refunded: Uint128::new(70),
+        })
+    );
+
+    // Ensure a rejected request cannot be rejected again.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ASSIGNER),
+            addr.clone(),
+            &ExecuteMsg::RejectVerification {
+                address: USER.to_string(),
+                reason_code: 1,
+                refund: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoVerificationRequest);
+
+    let res: ListVerificationRequestsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListPendingVerificationRequests {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.requests.len(), 1);
+
+    // Request again, then cancel for a full refund.
+    app.init_modules(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &Addr::unchecked(USER), coins(100, "ujuno"))
+            .unwrap();
+    });
+    app.execute_contract(
+        Addr::unchecked(USER),
+        addr.clone(),
+        &ExecuteMsg::RequestVerification {
+            session_commitment: SESSION_COMMITMENT.to_string(),
+        },
+        &coins(100, "ujuno"),
+    )
+    .unwrap();
+    let balance = app.wrap().query_balance(USER, "ujuno").unwrap();
+    assert_eq!(balance, coin(0, "ujuno"));
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::CancelVerification {},
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &cosmwasm_std::Event::new("wasm")
+            .add_attribute("method", "cancel_verification")
+            .add_attribute("address", USER)
+            .add_attribute("refunded", "100")
+    ));
+
+    let balance = app.wrap().query_balance(USER, "ujuno").unwrap();
+    assert_eq!(balance, coin(100, "ujuno"));
+
+    let res: VerificationRequestResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::VerificationRequest {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.request.map(|request| request.status),
+        Some(VerificationStatus::Cancelled)
+    );
+
+    // Ensure a cancelled request cannot be cancelled again.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            addr.clone(),
+            &ExecuteMsg::CancelVerification {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoVerificationRequest);
+
+    // Free requests can be cancelled too.
+    app.execute_contract(
+        Addr::unchecked(ANOTHER_USER),
+        addr.clone(),
+        &ExecuteMsg::CancelVerification {},
+        &[],
+    )
+    .unwrap();
+
+    let res: ListVerificationRequestsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr,
+            &QueryMsg::ListPendingVerificationRequests {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(res.requests.is_empty());
+}
@@ -6,7 +6,7 @@
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { Coin, StdFee } from "@cosmjs/amino";
-import { InstantiateMsg, ExecuteMsg, Action, Expiration, Timestamp, Uint64, QueryMsg, Addr, AssignerResponse, CheckmarkBannedResponse, CountResponse, GetAddressResponse, GetCheckmarkResponse, OwnershipForString } from "./CwCheckmark.types";
+import { InstantiateMsg, ExecuteMsg, Uint128, Binary, Denom, Addr, ReportStatus, Expiration, Timestamp, Uint64, BanReason, Duration, Decimal, Role, Action, Cw20ReceiveMsg, UpdateConfigMsg, FeeSplit, Fee, QueryMsg, SudoMsg, CountResponse, AddressBannedResponse, Ban, AssignerResponse, CheckmarkBannedResponse, CheckmarkIssuedResponse, Config, FeeBalancesResponse, GetAddressResponse, GetCheckmarkResponse, Suspension, GetDelegationResponse, GuardiansResponse, GuardianChange, HasRoleResponse, ListAllowedContractsResponse, ListBannedAddressesResponse, BannedAddressEntry, ListBannedCheckmarksResponse, BannedCheckmarkEntry, ListIssuedByResponse, IssuedCheckmark, ListOrganizationsResponse, OrganizationEntry, ListPendingAssignmentsResponse, PendingAssignmentEntry, PendingAssignment, ListPendingRecoveriesResponse, PendingRecovery, Recovery, RecoveryProposal, VerificationStatus, ListVerificationRequestsResponse, VerificationRequestEntry, VerificationRequest, ListReportsResponse, ReportEntry, Report, ListRoleMembersResponse, ListScheduledRevocationsResponse, ScheduledRevocationEntry, ScheduledRevocation, Appeal, TimelockedMsg, ListTimelockedActionsResponse, TimelockedActionEntry, TimelockedAction, MixingEpochResponse, OfferResponse, Offer, Issuance, OptedOutResponse, OwnershipForString, PausedResponse, PendingRecoveryResponse, ReassignCooldownResponse, ReportResponse, ReportPoolResponse, ResolveVoterResponse, ScheduledRevocationResponse, SecondaryAddressesResponse, TimelockedActionResponse, VerificationRequestResponse } from "./CwCheckmark.types";
 export interface CwCheckmarkReadOnlyInterface {
   contractAddress: string;
   getCheckmark: ({
@@ -19,13 +19,214 @@ export interface CwCheckmarkReadOnlyInterface {
   }: {
     checkmarkId: string;
   }) => Promise<GetAddressResponse>;
+  getCheckmarkByEvmAddress: ({
+    ethAddress
+  }: {
+    ethAddress: string;
+  }) => Promise<GetCheckmarkResponse>;
+  getCheckmarkByCanonicalAddress: ({
+    canonicalAddress
+  }: {
+    canonicalAddress: Binary;
+  }) => Promise<GetCheckmarkResponse>;
+  getCheckmarkByPubkey: ({
+    pubkey
+  }: {
+    pubkey: Binary;
+  }) => Promise<GetCheckmarkResponse>;
   count: () => Promise<CountResponse>;
+  checkmarkIssued: ({
+    checkmarkId
+  }: {
+    checkmarkId: string;
+  }) => Promise<CheckmarkIssuedResponse>;
   checkmarkBanned: ({
     checkmarkId
   }: {
     checkmarkId: string;
   }) => Promise<CheckmarkBannedResponse>;
+  listBannedCheckmarks: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ListBannedCheckmarksResponse>;
+  addressBanned: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<AddressBannedResponse>;
+  listPendingVerificationRequests: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ListVerificationRequestsResponse>;
+  feeBalances: () => Promise<FeeBalancesResponse>;
+  verificationRequest: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<VerificationRequestResponse>;
+  listAllowedContracts: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ListAllowedContractsResponse>;
+  listOrganizations: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ListOrganizationsResponse>;
+  listBannedAddresses: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ListBannedAddressesResponse>;
   assigner: () => Promise<AssignerResponse>;
+  paused: () => Promise<PausedResponse>;
+  mixingEpoch: () => Promise<MixingEpochResponse>;
+  secondaryAddresses: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<SecondaryAddressesResponse>;
+  getDelegation: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<GetDelegationResponse>;
+  activeCount: () => Promise<CountResponse>;
+  resolveVoter: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<ResolveVoterResponse>;
+  guardians: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<GuardiansResponse>;
+  pendingRecovery: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<PendingRecoveryResponse>;
+  listPendingRecoveries: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ListPendingRecoveriesResponse>;
+  scheduledRevocation: ({
+    checkmarkId
+  }: {
+    checkmarkId: string;
+  }) => Promise<ScheduledRevocationResponse>;
+  listScheduledRevocations: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ListScheduledRevocationsResponse>;
+  report: ({
+    id
+  }: {
+    id: number;
+  }) => Promise<ReportResponse>;
+  listReportsByStatus: ({
+    limit,
+    startAfter,
+    status
+  }: {
+    limit?: number;
+    startAfter?: number;
+    status: ReportStatus;
+  }) => Promise<ListReportsResponse>;
+  listReportsByTarget: ({
+    address,
+    limit,
+    startAfter
+  }: {
+    address: string;
+    limit?: number;
+    startAfter?: number;
+  }) => Promise<ListReportsResponse>;
+  reportPool: () => Promise<ReportPoolResponse>;
+  listIssuedBy: ({
+    assigner,
+    fromHeight,
+    limit,
+    startAfter,
+    toHeight
+  }: {
+    assigner: string;
+    fromHeight: number;
+    limit?: number;
+    startAfter?: [number, string];
+    toHeight: number;
+  }) => Promise<ListIssuedByResponse>;
+  offer: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<OfferResponse>;
+  optedOut: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<OptedOutResponse>;
+  reassignCooldown: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<ReassignCooldownResponse>;
+  listRoleMembers: ({
+    limit,
+    role,
+    startAfter
+  }: {
+    limit?: number;
+    role: Role;
+    startAfter?: string;
+  }) => Promise<ListRoleMembersResponse>;
+  hasRole: ({
+    address,
+    role
+  }: {
+    address: string;
+    role: Role;
+  }) => Promise<HasRoleResponse>;
+  listPendingAssignments: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ListPendingAssignmentsResponse>;
+  timelockedAction: ({
+    id
+  }: {
+    id: number;
+  }) => Promise<TimelockedActionResponse>;
+  listTimelockedActions: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: number;
+  }) => Promise<ListTimelockedActionsResponse>;
+  config: () => Promise<Config>;
   ownership: () => Promise<OwnershipForString>;
 }
 export class CwCheckmarkQueryClient implements CwCheckmarkReadOnlyInterface {
@@ -37,9 +238,46 @@ export class CwCheckmarkQueryClient implements CwCheckmarkReadOnlyInterface {
     this.contractAddress = contractAddress;
     this.getCheckmark = this.getCheckmark.bind(this);
     this.getAddress = this.getAddress.bind(this);
+    this.getCheckmarkByEvmAddress = this.getCheckmarkByEvmAddress.bind(this);
+    this.getCheckmarkByCanonicalAddress = this.getCheckmarkByCanonicalAddress.bind(this);
+    this.getCheckmarkByPubkey = this.getCheckmarkByPubkey.bind(this);
     this.count = this.count.bind(this);
+    this.checkmarkIssued = this.checkmarkIssued.bind(this);
     this.checkmarkBanned = this.checkmarkBanned.bind(this);
+    this.listBannedCheckmarks = this.listBannedCheckmarks.bind(this);
+    this.addressBanned = this.addressBanned.bind(this);
+    this.listPendingVerificationRequests = this.listPendingVerificationRequests.bind(this);
+    this.feeBalances = this.feeBalances.bind(this);
+    this.verificationRequest = this.verificationRequest.bind(this);
+    this.listAllowedContracts = this.listAllowedContracts.bind(this);
+    this.listOrganizations = this.listOrganizations.bind(this);
+    this.listBannedAddresses = this.listBannedAddresses.bind(this);
     this.assigner = this.assigner.bind(this);
+    this.paused = this.paused.bind(this);
+    this.mixingEpoch = this.mixingEpoch.bind(this);
+    this.secondaryAddresses = this.secondaryAddresses.bind(this);
+    this.getDelegation = this.getDelegation.bind(this);
+    this.activeCount = this.activeCount.bind(this);
+    this.resolveVoter = this.resolveVoter.bind(this);
+    this.guardians = this.guardians.bind(this);
+    this.pendingRecovery = this.pendingRecovery.bind(this);
+    this.listPendingRecoveries = this.listPendingRecoveries.bind(this);
+    this.scheduledRevocation = this.scheduledRevocation.bind(this);
+    this.listScheduledRevocations = this.listScheduledRevocations.bind(this);
+    this.report = this.report.bind(this);
+    this.listReportsByStatus = this.listReportsByStatus.bind(this);
+    this.listReportsByTarget = this.listReportsByTarget.bind(this);
+    this.reportPool = this.reportPool.bind(this);
+    this.listIssuedBy = this.listIssuedBy.bind(this);
+    this.offer = this.offer.bind(this);
+    this.optedOut = this.optedOut.bind(this);
+    this.reassignCooldown = this.reassignCooldown.bind(this);
+    this.listRoleMembers = this.listRoleMembers.bind(this);
+    this.hasRole = this.hasRole.bind(this);
+    this.listPendingAssignments = this.listPendingAssignments.bind(this);
+    this.timelockedAction = this.timelockedAction.bind(this);
+    this.listTimelockedActions = this.listTimelockedActions.bind(this);
+    this.config = this.config.bind(this);
     this.ownership = this.ownership.bind(this);
   }
 
@@ -65,11 +303,55 @@ export class CwCheckmarkQueryClient implements CwCheckmarkReadOnlyInterface {
       }
     });
   };
+  getCheckmarkByEvmAddress = async ({
+    ethAddress
+  }: {
+    ethAddress: string;
+  }): Promise<GetCheckmarkResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_checkmark_by_evm_address: {
+        eth_address: ethAddress
+      }
+    });
+  };
+  getCheckmarkByCanonicalAddress = async ({
+    canonicalAddress
+  }: {
+    canonicalAddress: Binary;
+  }): Promise<GetCheckmarkResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_checkmark_by_canonical_address: {
+        canonical_address: canonicalAddress
+      }
+    });
+  };
+  getCheckmarkByPubkey = async ({
+    pubkey
+  }: {
+    pubkey: Binary;
+  }): Promise<GetCheckmarkResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_checkmark_by_pubkey: {
+        pubkey
+      }
+    });
+  };
   count = async (): Promise<CountResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
       count: {}
     });
   };
+  checkmarkIssued = async ({
+    checkmarkId
+  }: {
+    checkmarkId: string;
+  }): Promise<CheckmarkIssuedResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      checkmark_issued: {
+        checkmark_id: checkmarkId
+      }
+    });
+  };
   checkmarkBanned = async ({
     checkmarkId
   }: {
@@ -81,134 +363,1334 @@ export class CwCheckmarkQueryClient implements CwCheckmarkReadOnlyInterface {
       }
     });
   };
-  assigner = async (): Promise<AssignerResponse> => {
+  listBannedCheckmarks = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ListBannedCheckmarksResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      assigner: {}
+      list_banned_checkmarks: {
+        limit,
+        start_after: startAfter
+      }
     });
   };
-  ownership = async (): Promise<OwnershipForString> => {
+  addressBanned = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<AddressBannedResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      ownership: {}
+      address_banned: {
+        address
+      }
     });
   };
-}
-export interface CwCheckmarkInterface extends CwCheckmarkReadOnlyInterface {
-  contractAddress: string;
-  sender: string;
-  assign: ({
-    address,
-    checkmarkId
-  }: {
-    address: string;
-    checkmarkId: string;
-  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
-  delete: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
-  revokeCheckmark: ({
-    checkmarkId
+  listPendingVerificationRequests = async ({
+    limit,
+    startAfter
   }: {
-    checkmarkId: string;
-  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
-  revokeAddress: ({
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ListVerificationRequestsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_pending_verification_requests: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  feeBalances = async (): Promise<FeeBalancesResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      fee_balances: {}
+    });
+  };
+  verificationRequest = async ({
     address
   }: {
     address: string;
-  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
-  updateCheckmarkBan: ({
-    banIds,
-    unbanIds
+  }): Promise<VerificationRequestResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      verification_request: {
+        address
+      }
+    });
+  };
+  listAllowedContracts = async ({
+    limit,
+    startAfter
   }: {
-    banIds?: string[];
-    unbanIds?: string[];
-  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
-  updateAssigner: ({
-    assigner
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ListAllowedContractsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_allowed_contracts: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  listOrganizations = async ({
+    limit,
+    startAfter
   }: {
-    assigner: string;
-  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
-  updateOwnership: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
-}
-export class CwCheckmarkClient extends CwCheckmarkQueryClient implements CwCheckmarkInterface {
-  client: SigningCosmWasmClient;
-  sender: string;
-  contractAddress: string;
-
-  constructor(client: SigningCosmWasmClient, sender: string, contractAddress: string) {
-    super(client, contractAddress);
-    this.client = client;
-    this.sender = sender;
-    this.contractAddress = contractAddress;
-    this.assign = this.assign.bind(this);
-    this.delete = this.delete.bind(this);
-    this.revokeCheckmark = this.revokeCheckmark.bind(this);
-    this.revokeAddress = this.revokeAddress.bind(this);
-    this.updateCheckmarkBan = this.updateCheckmarkBan.bind(this);
-    this.updateAssigner = this.updateAssigner.bind(this);
-    this.updateOwnership = this.updateOwnership.bind(this);
-  }
-
-  assign = async ({
-    address,
-    checkmarkId
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ListOrganizationsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_organizations: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  listBannedAddresses = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ListBannedAddressesResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_banned_addresses: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  assigner = async (): Promise<AssignerResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      assigner: {}
+    });
+  };
+  paused = async (): Promise<PausedResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      paused: {}
+    });
+  };
+  mixingEpoch = async (): Promise<MixingEpochResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      mixing_epoch: {}
+    });
+  };
+  secondaryAddresses = async ({
+    address
   }: {
     address: string;
-    checkmarkId: string;
-  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
-    return await this.client.execute(this.sender, this.contractAddress, {
-      assign: {
-        address,
-        checkmark_id: checkmarkId
+  }): Promise<SecondaryAddressesResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      secondary_addresses: {
+        address
       }
-    }, fee, memo, funds);
+    });
   };
-  delete = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
-    return await this.client.execute(this.sender, this.contractAddress, {
-      delete: {}
-    }, fee, memo, funds);
+  getDelegation = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<GetDelegationResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_delegation: {
+        address
+      }
+    });
   };
-  revokeCheckmark = async ({
-    checkmarkId
+  activeCount = async (): Promise<CountResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      active_count: {}
+    });
+  };
+  resolveVoter = async ({
+    address
   }: {
-    checkmarkId: string;
-  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
-    return await this.client.execute(this.sender, this.contractAddress, {
-      revoke_checkmark: {
-        checkmark_id: checkmarkId
+    address: string;
+  }): Promise<ResolveVoterResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      resolve_voter: {
+        address
       }
-    }, fee, memo, funds);
+    });
   };
-  revokeAddress = async ({
+  guardians = async ({
     address
   }: {
     address: string;
-  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
-    return await this.client.execute(this.sender, this.contractAddress, {
-      revoke_address: {
+  }): Promise<GuardiansResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      guardians: {
         address
       }
-    }, fee, memo, funds);
+    });
   };
-  updateCheckmarkBan = async ({
-    banIds,
-    unbanIds
+  pendingRecovery = async ({
+    address
   }: {
-    banIds?: string[];
-    unbanIds?: string[];
-  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
-    return await this.client.execute(this.sender, this.contractAddress, {
-      update_checkmark_ban: {
-        ban_ids: banIds,
-        unban_ids: unbanIds
+    address: string;
+  }): Promise<PendingRecoveryResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      pending_recovery: {
+        address
       }
-    }, fee, memo, funds);
+    });
   };
-  updateAssigner = async ({
-    assigner
+  listPendingRecoveries = async ({
+    limit,
+    startAfter
   }: {
-    assigner: string;
-  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
-    return await this.client.execute(this.sender, this.contractAddress, {
-      update_assigner: {
-        assigner
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ListPendingRecoveriesResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_pending_recoveries: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  scheduledRevocation = async ({
+    checkmarkId
+  }: {
+    checkmarkId: string;
+  }): Promise<ScheduledRevocationResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      scheduled_revocation: {
+        checkmark_id: checkmarkId
+      }
+    });
+  };
+  listScheduledRevocations = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ListScheduledRevocationsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_scheduled_revocations: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  report = async ({
+    id
+  }: {
+    id: number;
+  }): Promise<ReportResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      report: {
+        id
+      }
+    });
+  };
+  listReportsByStatus = async ({
+    limit,
+    startAfter,
+    status
+  }: {
+    limit?: number;
+    startAfter?: number;
+    status: ReportStatus;
+  }): Promise<ListReportsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_reports_by_status: {
+        limit,
+        start_after: startAfter,
+        status
+      }
+    });
+  };
+  listReportsByTarget = async ({
+    address,
+    limit,
+    startAfter
+  }: {
+    address: string;
+    limit?: number;
+    startAfter?: number;
+  }): Promise<ListReportsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_reports_by_target: {
+        address,
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  reportPool = async (): Promise<ReportPoolResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      report_pool: {}
+    });
+  };
+  listIssuedBy = async ({
+    assigner,
+    fromHeight,
+    limit,
+    startAfter,
+    toHeight
+  }: {
+    assigner: string;
+    fromHeight: number;
+    limit?: number;
+    startAfter?: [number, string];
+    toHeight: number;
+  }): Promise<ListIssuedByResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_issued_by: {
+        assigner,
+        from_height: fromHeight,
+        limit,
+        start_after: startAfter,
+        to_height: toHeight
+      }
+    });
+  };
+  offer = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<OfferResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      offer: {
+        address
+      }
+    });
+  };
+  optedOut = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<OptedOutResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      opted_out: {
+        address
+      }
+    });
+  };
+  reassignCooldown = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<ReassignCooldownResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      reassign_cooldown: {
+        address
+      }
+    });
+  };
+  listRoleMembers = async ({
+    limit,
+    role,
+    startAfter
+  }: {
+    limit?: number;
+    role: Role;
+    startAfter?: string;
+  }): Promise<ListRoleMembersResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_role_members: {
+        limit,
+        role,
+        start_after: startAfter
+      }
+    });
+  };
+  hasRole = async ({
+    address,
+    role
+  }: {
+    address: string;
+    role: Role;
+  }): Promise<HasRoleResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      has_role: {
+        address,
+        role
+      }
+    });
+  };
+  listPendingAssignments = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ListPendingAssignmentsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_pending_assignments: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  timelockedAction = async ({
+    id
+  }: {
+    id: number;
+  }): Promise<TimelockedActionResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      timelocked_action: {
+        id
+      }
+    });
+  };
+  listTimelockedActions = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: number;
+  }): Promise<ListTimelockedActionsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_timelocked_actions: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  config = async (): Promise<Config> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      config: {}
+    });
+  };
+  ownership = async (): Promise<OwnershipForString> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      ownership: {}
+    });
+  };
+}
+export interface CwCheckmarkInterface extends CwCheckmarkReadOnlyInterface {
+  contractAddress: string;
+  sender: string;
+  assign: ({
+    address,
+    checkmarkId
+  }: {
+    address: string;
+    checkmarkId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  receive: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  requestVerification: ({
+    sessionCommitment
+  }: {
+    sessionCommitment: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  rejectVerification: ({
+    address,
+    reasonCode,
+    refund
+  }: {
+    address: string;
+    reasonCode: number;
+    refund?: Uint128;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  cancelVerification: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  withdrawFees: ({
+    denom
+  }: {
+    denom: Denom;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  approveAssign: ({
+    address,
+    checkmarkId
+  }: {
+    address: string;
+    checkmarkId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  assignOrganization: ({
+    address,
+    checkmarkId
+  }: {
+    address: string;
+    checkmarkId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  reassignCheckmark: ({
+    address,
+    checkmarkId
+  }: {
+    address: string;
+    checkmarkId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  rotateCheckmark: ({
+    address,
+    newCheckmarkId,
+    reissue
+  }: {
+    address: string;
+    newCheckmarkId: string;
+    reissue?: boolean;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  processEpoch: ({
+    limit
+  }: {
+    limit?: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  prunePendingAssignments: ({
+    limit
+  }: {
+    limit?: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  delete: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  acceptCheckmark: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  rejectCheckmark: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  optOut: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  optIn: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  cancelDelete: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  finalizeDelete: ({
+    address
+  }: {
+    address: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  revokeCheckmark: ({
+    checkmarkId,
+    reason
+  }: {
+    checkmarkId: string;
+    reason?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  revokeAddress: ({
+    address,
+    reason
+  }: {
+    address: string;
+    reason?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  appeal: ({
+    evidenceHash
+  }: {
+    evidenceHash: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  resolveAppeal: ({
+    checkmarkId,
+    uphold
+  }: {
+    checkmarkId: string;
+    uphold: boolean;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  report: ({
+    address,
+    evidenceHash,
+    reason
+  }: {
+    address: string;
+    evidenceHash: string;
+    reason: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  resolveReport: ({
+    id,
+    status
+  }: {
+    id: number;
+    status: ReportStatus;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  fundReportPool: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  revokeIssuedBy: ({
+    assigner,
+    fromHeight,
+    limit,
+    toHeight
+  }: {
+    assigner: string;
+    fromHeight: number;
+    limit?: number;
+    toHeight: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  finalizeRevocation: ({
+    checkmarkId
+  }: {
+    checkmarkId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  suspend: ({
+    address,
+    checkmarkId,
+    reason,
+    until
+  }: {
+    address?: string;
+    checkmarkId?: string;
+    reason: string;
+    until?: Expiration;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  unsuspend: ({
+    address,
+    checkmarkId
+  }: {
+    address?: string;
+    checkmarkId?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateCheckmarkBan: ({
+    banIds,
+    expiration,
+    note,
+    reason,
+    unbanIds
+  }: {
+    banIds?: string[];
+    expiration?: Expiration;
+    note?: string;
+    reason?: BanReason;
+    unbanIds?: string[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateAddressBan: ({
+    banAddresses,
+    expiration,
+    note,
+    reason,
+    unbanAddresses
+  }: {
+    banAddresses?: string[];
+    expiration?: Expiration;
+    note?: string;
+    reason?: BanReason;
+    unbanAddresses?: string[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateAssigner: ({
+    assigner
+  }: {
+    assigner: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  executeTimelocked: ({
+    id
+  }: {
+    id: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  cancelTimelocked: ({
+    id
+  }: {
+    id: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  linkEvmAddress: ({
+    ethAddress,
+    signature
+  }: {
+    ethAddress: string;
+    signature: Binary;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  registerPubkey: ({
+    pubkey
+  }: {
+    pubkey: Binary;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  addSecondaryAddress: ({
+    address
+  }: {
+    address: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  acceptSecondaryAddress: ({
+    primary
+  }: {
+    primary: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  removeSecondaryAddress: ({
+    address
+  }: {
+    address: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  delegate: ({
+    to
+  }: {
+    to: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  undelegate: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setGuardians: ({
+    guardians,
+    threshold
+  }: {
+    guardians: string[];
+    threshold: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  approveRecovery: ({
+    holder,
+    newAddress
+  }: {
+    holder: string;
+    newAddress: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  cancelRecovery: ({
+    holder
+  }: {
+    holder?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  executeRecovery: ({
+    holder
+  }: {
+    holder: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateConfig: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateAllowedContracts: ({
+    add,
+    remove
+  }: {
+    add?: string[];
+    remove?: string[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  grantRole: ({
+    address,
+    role
+  }: {
+    address: string;
+    role: Role;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  revokeRole: ({
+    address,
+    role
+  }: {
+    address: string;
+    role: Role;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateOwnership: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+}
+export class CwCheckmarkClient extends CwCheckmarkQueryClient implements CwCheckmarkInterface {
+  client: SigningCosmWasmClient;
+  sender: string;
+  contractAddress: string;
+
+  constructor(client: SigningCosmWasmClient, sender: string, contractAddress: string) {
+    super(client, contractAddress);
+    this.client = client;
+    this.sender = sender;
+    this.contractAddress = contractAddress;
+    this.assign = this.assign.bind(this);
+    this.receive = this.receive.bind(this);
+    this.requestVerification = this.requestVerification.bind(this);
+    this.rejectVerification = this.rejectVerification.bind(this);
+    this.cancelVerification = this.cancelVerification.bind(this);
+    this.withdrawFees = this.withdrawFees.bind(this);
+    this.approveAssign = this.approveAssign.bind(this);
+    this.assignOrganization = this.assignOrganization.bind(this);
+    this.reassignCheckmark = this.reassignCheckmark.bind(this);
+    this.rotateCheckmark = this.rotateCheckmark.bind(this);
+    this.processEpoch = this.processEpoch.bind(this);
+    this.prunePendingAssignments = this.prunePendingAssignments.bind(this);
+    this.delete = this.delete.bind(this);
+    this.acceptCheckmark = this.acceptCheckmark.bind(this);
+    this.rejectCheckmark = this.rejectCheckmark.bind(this);
+    this.optOut = this.optOut.bind(this);
+    this.optIn = this.optIn.bind(this);
+    this.cancelDelete = this.cancelDelete.bind(this);
+    this.finalizeDelete = this.finalizeDelete.bind(this);
+    this.revokeCheckmark = this.revokeCheckmark.bind(this);
+    this.revokeAddress = this.revokeAddress.bind(this);
+    this.appeal = this.appeal.bind(this);
+    this.resolveAppeal = this.resolveAppeal.bind(this);
+    this.report = this.report.bind(this);
+    this.resolveReport = this.resolveReport.bind(this);
+    this.fundReportPool = this.fundReportPool.bind(this);
+    this.revokeIssuedBy = this.revokeIssuedBy.bind(this);
+    this.finalizeRevocation = this.finalizeRevocation.bind(this);
+    this.suspend = this.suspend.bind(this);
+    this.unsuspend = this.unsuspend.bind(this);
+    this.updateCheckmarkBan = this.updateCheckmarkBan.bind(this);
+    this.updateAddressBan = this.updateAddressBan.bind(this);
+    this.updateAssigner = this.updateAssigner.bind(this);
+    this.executeTimelocked = this.executeTimelocked.bind(this);
+    this.cancelTimelocked = this.cancelTimelocked.bind(this);
+    this.linkEvmAddress = this.linkEvmAddress.bind(this);
+    this.registerPubkey = this.registerPubkey.bind(this);
+    this.addSecondaryAddress = this.addSecondaryAddress.bind(this);
+    this.acceptSecondaryAddress = this.acceptSecondaryAddress.bind(this);
+    this.removeSecondaryAddress = this.removeSecondaryAddress.bind(this);
+    this.delegate = this.delegate.bind(this);
+    this.undelegate = this.undelegate.bind(this);
+    this.setGuardians = this.setGuardians.bind(this);
+    this.approveRecovery = this.approveRecovery.bind(this);
+    this.cancelRecovery = this.cancelRecovery.bind(this);
+    this.executeRecovery = this.executeRecovery.bind(this);
+    this.updateConfig = this.updateConfig.bind(this);
+    this.updateAllowedContracts = this.updateAllowedContracts.bind(this);
+    this.grantRole = this.grantRole.bind(this);
+    this.revokeRole = this.revokeRole.bind(this);
+    this.updateOwnership = this.updateOwnership.bind(this);
+  }
+
+  assign = async ({
+    address,
+    checkmarkId
+  }: {
+    address: string;
+    checkmarkId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      assign: {
+        address,
+        checkmark_id: checkmarkId
+      }
+    }, fee, memo, funds);
+  };
+  receive = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      receive: {}
+    }, fee, memo, funds);
+  };
+  requestVerification = async ({
+    sessionCommitment
+  }: {
+    sessionCommitment: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      request_verification: {
+        session_commitment: sessionCommitment
+      }
+    }, fee, memo, funds);
+  };
+  rejectVerification = async ({
+    address,
+    reasonCode,
+    refund
+  }: {
+    address: string;
+    reasonCode: number;
+    refund?: Uint128;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      reject_verification: {
+        address,
+        reason_code: reasonCode,
+        refund
+      }
+    }, fee, memo, funds);
+  };
+  cancelVerification = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      cancel_verification: {}
+    }, fee, memo, funds);
+  };
+  withdrawFees = async ({
+    denom
+  }: {
+    denom: Denom;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      withdraw_fees: {
+        denom
+      }
+    }, fee, memo, funds);
+  };
+  approveAssign = async ({
+    address,
+    checkmarkId
+  }: {
+    address: string;
+    checkmarkId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      approve_assign: {
+        address,
+        checkmark_id: checkmarkId
+      }
+    }, fee, memo, funds);
+  };
+  assignOrganization = async ({
+    address,
+    checkmarkId
+  }: {
+    address: string;
+    checkmarkId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      assign_organization: {
+        address,
+        checkmark_id: checkmarkId
+      }
+    }, fee, memo, funds);
+  };
+  reassignCheckmark = async ({
+    address,
+    checkmarkId
+  }: {
+    address: string;
+    checkmarkId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      reassign_checkmark: {
+        address,
+        checkmark_id: checkmarkId
+      }
+    }, fee, memo, funds);
+  };
+  rotateCheckmark = async ({
+    address,
+    newCheckmarkId,
+    reissue
+  }: {
+    address: string;
+    newCheckmarkId: string;
+    reissue?: boolean;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      rotate_checkmark: {
+        address,
+        new_checkmark_id: newCheckmarkId,
+        reissue
+      }
+    }, fee, memo, funds);
+  };
+  processEpoch = async ({
+    limit
+  }: {
+    limit?: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      process_epoch: {
+        limit
+      }
+    }, fee, memo, funds);
+  };
+  prunePendingAssignments = async ({
+    limit
+  }: {
+    limit?: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      prune_pending_assignments: {
+        limit
+      }
+    }, fee, memo, funds);
+  };
+  delete = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      delete: {}
+    }, fee, memo, funds);
+  };
+  acceptCheckmark = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      accept_checkmark: {}
+    }, fee, memo, funds);
+  };
+  rejectCheckmark = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      reject_checkmark: {}
+    }, fee, memo, funds);
+  };
+  optOut = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      opt_out: {}
+    }, fee, memo, funds);
+  };
+  optIn = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      opt_in: {}
+    }, fee, memo, funds);
+  };
+  cancelDelete = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      cancel_delete: {}
+    }, fee, memo, funds);
+  };
+  finalizeDelete = async ({
+    address
+  }: {
+    address: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      finalize_delete: {
+        address
+      }
+    }, fee, memo, funds);
+  };
+  revokeCheckmark = async ({
+    checkmarkId,
+    reason
+  }: {
+    checkmarkId: string;
+    reason?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      revoke_checkmark: {
+        checkmark_id: checkmarkId,
+        reason
+      }
+    }, fee, memo, funds);
+  };
+  revokeAddress = async ({
+    address,
+    reason
+  }: {
+    address: string;
+    reason?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      revoke_address: {
+        address,
+        reason
+      }
+    }, fee, memo, funds);
+  };
+  appeal = async ({
+    evidenceHash
+  }: {
+    evidenceHash: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      appeal: {
+        evidence_hash: evidenceHash
+      }
+    }, fee, memo, funds);
+  };
+  resolveAppeal = async ({
+    checkmarkId,
+    uphold
+  }: {
+    checkmarkId: string;
+    uphold: boolean;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      resolve_appeal: {
+        checkmark_id: checkmarkId,
+        uphold
+      }
+    }, fee, memo, funds);
+  };
+  report = async ({
+    address,
+    evidenceHash,
+    reason
+  }: {
+    address: string;
+    evidenceHash: string;
+    reason: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      report: {
+        address,
+        evidence_hash: evidenceHash,
+        reason
+      }
+    }, fee, memo, funds);
+  };
+  resolveReport = async ({
+    id,
+    status
+  }: {
+    id: number;
+    status: ReportStatus;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      resolve_report: {
+        id,
+        status
+      }
+    }, fee, memo, funds);
+  };
+  fundReportPool = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      fund_report_pool: {}
+    }, fee, memo, funds);
+  };
+  revokeIssuedBy = async ({
+    assigner,
+    fromHeight,
+    limit,
+    toHeight
+  }: {
+    assigner: string;
+    fromHeight: number;
+    limit?: number;
+    toHeight: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      revoke_issued_by: {
+        assigner,
+        from_height: fromHeight,
+        limit,
+        to_height: toHeight
+      }
+    }, fee, memo, funds);
+  };
+  finalizeRevocation = async ({
+    checkmarkId
+  }: {
+    checkmarkId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      finalize_revocation: {
+        checkmark_id: checkmarkId
+      }
+    }, fee, memo, funds);
+  };
+  suspend = async ({
+    address,
+    checkmarkId,
+    reason,
+    until
+  }: {
+    address?: string;
+    checkmarkId?: string;
+    reason: string;
+    until?: Expiration;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      suspend: {
+        address,
+        checkmark_id: checkmarkId,
+        reason,
+        until
+      }
+    }, fee, memo, funds);
+  };
+  unsuspend = async ({
+    address,
+    checkmarkId
+  }: {
+    address?: string;
+    checkmarkId?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      unsuspend: {
+        address,
+        checkmark_id: checkmarkId
+      }
+    }, fee, memo, funds);
+  };
+  updateCheckmarkBan = async ({
+    banIds,
+    expiration,
+    note,
+    reason,
+    unbanIds
+  }: {
+    banIds?: string[];
+    expiration?: Expiration;
+    note?: string;
+    reason?: BanReason;
+    unbanIds?: string[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_checkmark_ban: {
+        ban_ids: banIds,
+        expiration,
+        note,
+        reason,
+        unban_ids: unbanIds
+      }
+    }, fee, memo, funds);
+  };
+  updateAddressBan = async ({
+    banAddresses,
+    expiration,
+    note,
+    reason,
+    unbanAddresses
+  }: {
+    banAddresses?: string[];
+    expiration?: Expiration;
+    note?: string;
+    reason?: BanReason;
+    unbanAddresses?: string[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_address_ban: {
+        ban_addresses: banAddresses,
+        expiration,
+        note,
+        reason,
+        unban_addresses: unbanAddresses
+      }
+    }, fee, memo, funds);
+  };
+  updateAssigner = async ({
+    assigner
+  }: {
+    assigner: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_assigner: {
+        assigner
+      }
+    }, fee, memo, funds);
+  };
+  executeTimelocked = async ({
+    id
+  }: {
+    id: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      execute_timelocked: {
+        id
+      }
+    }, fee, memo, funds);
+  };
+  cancelTimelocked = async ({
+    id
+  }: {
+    id: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      cancel_timelocked: {
+        id
+      }
+    }, fee, memo, funds);
+  };
+  linkEvmAddress = async ({
+    ethAddress,
+    signature
+  }: {
+    ethAddress: string;
+    signature: Binary;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      link_evm_address: {
+        eth_address: ethAddress,
+        signature
+      }
+    }, fee, memo, funds);
+  };
+  registerPubkey = async ({
+    pubkey
+  }: {
+    pubkey: Binary;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      register_pubkey: {
+        pubkey
+      }
+    }, fee, memo, funds);
+  };
+  addSecondaryAddress = async ({
+    address
+  }: {
+    address: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      add_secondary_address: {
+        address
+      }
+    }, fee, memo, funds);
+  };
+  acceptSecondaryAddress = async ({
+    primary
+  }: {
+    primary: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      accept_secondary_address: {
+        primary
+      }
+    }, fee, memo, funds);
+  };
+  removeSecondaryAddress = async ({
+    address
+  }: {
+    address: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_secondary_address: {
+        address
+      }
+    }, fee, memo, funds);
+  };
+  delegate = async ({
+    to
+  }: {
+    to: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      delegate: {
+        to
+      }
+    }, fee, memo, funds);
+  };
+  undelegate = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      undelegate: {}
+    }, fee, memo, funds);
+  };
+  setGuardians = async ({
+    guardians,
+    threshold
+  }: {
+    guardians: string[];
+    threshold: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_guardians: {
+        guardians,
+        threshold
+      }
+    }, fee, memo, funds);
+  };
+  approveRecovery = async ({
+    holder,
+    newAddress
+  }: {
+    holder: string;
+    newAddress: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      approve_recovery: {
+        holder,
+        new_address: newAddress
+      }
+    }, fee, memo, funds);
+  };
+  cancelRecovery = async ({
+    holder
+  }: {
+    holder?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      cancel_recovery: {
+        holder
+      }
+    }, fee, memo, funds);
+  };
+  executeRecovery = async ({
+    holder
+  }: {
+    holder: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      execute_recovery: {
+        holder
+      }
+    }, fee, memo, funds);
+  };
+  updateConfig = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_config: {}
+    }, fee, memo, funds);
+  };
+  updateAllowedContracts = async ({
+    add,
+    remove
+  }: {
+    add?: string[];
+    remove?: string[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_allowed_contracts: {
+        add,
+        remove
+      }
+    }, fee, memo, funds);
+  };
+  grantRole = async ({
+    address,
+    role
+  }: {
+    address: string;
+    role: Role;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      grant_role: {
+        address,
+        role
+      }
+    }, fee, memo, funds);
+  };
+  revokeRole = async ({
+    address,
+    role
+  }: {
+    address: string;
+    role: Role;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      revoke_role: {
+        address,
+        role
       }
     }, fee, memo, funds);
   };
@@ -8,7 +8,7 @@ import { Coin } from "@cosmjs/amino";
 import { MsgExecuteContractEncodeObject } from "cosmwasm";
 import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
 import { toUtf8 } from "@cosmjs/encoding";
-import { InstantiateMsg, ExecuteMsg, Action, Expiration, Timestamp, Uint64, QueryMsg, Addr, AssignerResponse, CheckmarkBannedResponse, CountResponse, GetAddressResponse, GetCheckmarkResponse, OwnershipForString } from "./CwCheckmark.types";
+import { InstantiateMsg, ExecuteMsg, Uint128, Binary, Denom, Addr, ReportStatus, Expiration, Timestamp, Uint64, BanReason, Duration, Decimal, Role, Action, Cw20ReceiveMsg, UpdateConfigMsg, FeeSplit, Fee, QueryMsg, SudoMsg, CountResponse, AddressBannedResponse, Ban, AssignerResponse, CheckmarkBannedResponse, CheckmarkIssuedResponse, Config, FeeBalancesResponse, GetAddressResponse, GetCheckmarkResponse, Suspension, GetDelegationResponse, GuardiansResponse, GuardianChange, HasRoleResponse, ListAllowedContractsResponse, ListBannedAddressesResponse, BannedAddressEntry, ListBannedCheckmarksResponse, BannedCheckmarkEntry, ListIssuedByResponse, IssuedCheckmark, ListOrganizationsResponse, OrganizationEntry, ListPendingAssignmentsResponse, PendingAssignmentEntry, PendingAssignment, ListPendingRecoveriesResponse, PendingRecovery, Recovery, RecoveryProposal, VerificationStatus, ListVerificationRequestsResponse, VerificationRequestEntry, VerificationRequest, ListReportsResponse, ReportEntry, Report, ListRoleMembersResponse, ListScheduledRevocationsResponse, ScheduledRevocationEntry, ScheduledRevocation, Appeal, TimelockedMsg, ListTimelockedActionsResponse, TimelockedActionEntry, TimelockedAction, MixingEpochResponse, OfferResponse, Offer, Issuance, OptedOutResponse, OwnershipForString, PausedResponse, PendingRecoveryResponse, ReassignCooldownResponse, ReportResponse, ReportPoolResponse, ResolveVoterResponse, ScheduledRevocationResponse, SecondaryAddressesResponse, TimelockedActionResponse, VerificationRequestResponse } from "./CwCheckmark.types";
 export interface CwCheckmarkMessage {
   contractAddress: string;
   sender: string;
@@ -19,29 +19,275 @@ export interface CwCheckmarkMessage {
     address: string;
     checkmarkId: string;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  receive: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  requestVerification: ({
+    sessionCommitment
+  }: {
+    sessionCommitment: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  rejectVerification: ({
+    address,
+    reasonCode,
+    refund
+  }: {
+    address: string;
+    reasonCode: number;
+    refund?: Uint128;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  cancelVerification: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  withdrawFees: ({
+    denom
+  }: {
+    denom: Denom;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  approveAssign: ({
+    address,
+    checkmarkId
+  }: {
+    address: string;
+    checkmarkId: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  assignOrganization: ({
+    address,
+    checkmarkId
+  }: {
+    address: string;
+    checkmarkId: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  reassignCheckmark: ({
+    address,
+    checkmarkId
+  }: {
+    address: string;
+    checkmarkId: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  rotateCheckmark: ({
+    address,
+    newCheckmarkId,
+    reissue
+  }: {
+    address: string;
+    newCheckmarkId: string;
+    reissue?: boolean;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  processEpoch: ({
+    limit
+  }: {
+    limit?: number;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  prunePendingAssignments: ({
+    limit
+  }: {
+    limit?: number;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   delete: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  acceptCheckmark: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  rejectCheckmark: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  optOut: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  optIn: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  cancelDelete: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  finalizeDelete: ({
+    address
+  }: {
+    address: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   revokeCheckmark: ({
-    checkmarkId
+    checkmarkId,
+    reason
   }: {
     checkmarkId: string;
+    reason?: string;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   revokeAddress: ({
-    address
+    address,
+    reason
+  }: {
+    address: string;
+    reason?: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  appeal: ({
+    evidenceHash
+  }: {
+    evidenceHash: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  resolveAppeal: ({
+    checkmarkId,
+    uphold
+  }: {
+    checkmarkId: string;
+    uphold: boolean;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  report: ({
+    address,
+    evidenceHash,
+    reason
   }: {
     address: string;
+    evidenceHash: string;
+    reason: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  resolveReport: ({
+    id,
+    status
+  }: {
+    id: number;
+    status: ReportStatus;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  fundReportPool: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  revokeIssuedBy: ({
+    assigner,
+    fromHeight,
+    limit,
+    toHeight
+  }: {
+    assigner: string;
+    fromHeight: number;
+    limit?: number;
+    toHeight: number;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  finalizeRevocation: ({
+    checkmarkId
+  }: {
+    checkmarkId: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  suspend: ({
+    address,
+    checkmarkId,
+    reason,
+    until
+  }: {
+    address?: string;
+    checkmarkId?: string;
+    reason: string;
+    until?: Expiration;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  unsuspend: ({
+    address,
+    checkmarkId
+  }: {
+    address?: string;
+    checkmarkId?: string;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   updateCheckmarkBan: ({
     banIds,
+    expiration,
+    note,
+    reason,
     unbanIds
   }: {
     banIds?: string[];
+    expiration?: Expiration;
+    note?: string;
+    reason?: BanReason;
     unbanIds?: string[];
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateAddressBan: ({
+    banAddresses,
+    expiration,
+    note,
+    reason,
+    unbanAddresses
+  }: {
+    banAddresses?: string[];
+    expiration?: Expiration;
+    note?: string;
+    reason?: BanReason;
+    unbanAddresses?: string[];
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   updateAssigner: ({
     assigner
   }: {
     assigner: string;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  executeTimelocked: ({
+    id
+  }: {
+    id: number;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  cancelTimelocked: ({
+    id
+  }: {
+    id: number;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  linkEvmAddress: ({
+    ethAddress,
+    signature
+  }: {
+    ethAddress: string;
+    signature: Binary;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  registerPubkey: ({
+    pubkey
+  }: {
+    pubkey: Binary;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  addSecondaryAddress: ({
+    address
+  }: {
+    address: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  acceptSecondaryAddress: ({
+    primary
+  }: {
+    primary: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  removeSecondaryAddress: ({
+    address
+  }: {
+    address: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  delegate: ({
+    to
+  }: {
+    to: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  undelegate: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  setGuardians: ({
+    guardians,
+    threshold
+  }: {
+    guardians: string[];
+    threshold: number;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  approveRecovery: ({
+    holder,
+    newAddress
+  }: {
+    holder: string;
+    newAddress: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  cancelRecovery: ({
+    holder
+  }: {
+    holder?: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  executeRecovery: ({
+    holder
+  }: {
+    holder: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateConfig: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateAllowedContracts: ({
+    add,
+    remove
+  }: {
+    add?: string[];
+    remove?: string[];
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  grantRole: ({
+    address,
+    role
+  }: {
+    address: string;
+    role: Role;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  revokeRole: ({
+    address,
+    role
+  }: {
+    address: string;
+    role: Role;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   updateOwnership: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
 }
 export class CwCheckmarkMessageComposer implements CwCheckmarkMessage {
@@ -52,11 +298,55 @@ export class CwCheckmarkMessageComposer implements CwCheckmarkMessage {
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.assign = this.assign.bind(this);
+    this.receive = this.receive.bind(this);
+    this.requestVerification = this.requestVerification.bind(this);
+    this.rejectVerification = this.rejectVerification.bind(this);
+    this.cancelVerification = this.cancelVerification.bind(this);
+    this.withdrawFees = this.withdrawFees.bind(this);
+    this.approveAssign = this.approveAssign.bind(this);
+    this.assignOrganization = this.assignOrganization.bind(this);
+    this.reassignCheckmark = this.reassignCheckmark.bind(this);
+    this.rotateCheckmark = this.rotateCheckmark.bind(this);
+    this.processEpoch = this.processEpoch.bind(this);
+    this.prunePendingAssignments = this.prunePendingAssignments.bind(this);
     this.delete = this.delete.bind(this);
+    this.acceptCheckmark = this.acceptCheckmark.bind(this);
+    this.rejectCheckmark = this.rejectCheckmark.bind(this);
+    this.optOut = this.optOut.bind(this);
+    this.optIn = this.optIn.bind(this);
+    this.cancelDelete = this.cancelDelete.bind(this);
+    this.finalizeDelete = this.finalizeDelete.bind(this);
     this.revokeCheckmark = this.revokeCheckmark.bind(this);
     this.revokeAddress = this.revokeAddress.bind(this);
+    this.appeal = this.appeal.bind(this);
+    this.resolveAppeal = this.resolveAppeal.bind(this);
+    this.report = this.report.bind(this);
+    this.resolveReport = this.resolveReport.bind(this);
+    this.fundReportPool = this.fundReportPool.bind(this);
+    this.revokeIssuedBy = this.revokeIssuedBy.bind(this);
+    this.finalizeRevocation = this.finalizeRevocation.bind(this);
+    this.suspend = this.suspend.bind(this);
+    this.unsuspend = this.unsuspend.bind(this);
     this.updateCheckmarkBan = this.updateCheckmarkBan.bind(this);
+    this.updateAddressBan = this.updateAddressBan.bind(this);
     this.updateAssigner = this.updateAssigner.bind(this);
+    this.executeTimelocked = this.executeTimelocked.bind(this);
+    this.cancelTimelocked = this.cancelTimelocked.bind(this);
+    this.linkEvmAddress = this.linkEvmAddress.bind(this);
+    this.registerPubkey = this.registerPubkey.bind(this);
+    this.addSecondaryAddress = this.addSecondaryAddress.bind(this);
+    this.acceptSecondaryAddress = this.acceptSecondaryAddress.bind(this);
+    this.removeSecondaryAddress = this.removeSecondaryAddress.bind(this);
+    this.delegate = this.delegate.bind(this);
+    this.undelegate = this.undelegate.bind(this);
+    this.setGuardians = this.setGuardians.bind(this);
+    this.approveRecovery = this.approveRecovery.bind(this);
+    this.cancelRecovery = this.cancelRecovery.bind(this);
+    this.executeRecovery = this.executeRecovery.bind(this);
+    this.updateConfig = this.updateConfig.bind(this);
+    this.updateAllowedContracts = this.updateAllowedContracts.bind(this);
+    this.grantRole = this.grantRole.bind(this);
+    this.revokeRole = this.revokeRole.bind(this);
     this.updateOwnership = this.updateOwnership.bind(this);
   }
 
@@ -82,22 +372,100 @@ export class CwCheckmarkMessageComposer implements CwCheckmarkMessage {
       })
     };
   };
-  delete = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+  receive = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
       value: MsgExecuteContract.fromPartial({
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          delete: {}
+          receive: {}
         })),
         funds
       })
     };
   };
-  revokeCheckmark = ({
+  requestVerification = ({
+    sessionCommitment
+  }: {
+    sessionCommitment: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          request_verification: {
+            session_commitment: sessionCommitment
+          }
+        })),
+        funds
+      })
+    };
+  };
+  rejectVerification = ({
+    address,
+    reasonCode,
+    refund
+  }: {
+    address: string;
+    reasonCode: number;
+    refund?: Uint128;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          reject_verification: {
+            address,
+            reason_code: reasonCode,
+            refund
+          }
+        })),
+        funds
+      })
+    };
+  };
+  cancelVerification = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          cancel_verification: {}
+        })),
+        funds
+      })
+    };
+  };
+  withdrawFees = ({
+    denom
+  }: {
+    denom: Denom;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          withdraw_fees: {
+            denom
+          }
+        })),
+        funds
+      })
+    };
+  };
+  approveAssign = ({
+    address,
     checkmarkId
   }: {
+    address: string;
     checkmarkId: string;
   }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
@@ -106,7 +474,8 @@ export class CwCheckmarkMessageComposer implements CwCheckmarkMessage {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          revoke_checkmark: {
+          approve_assign: {
+            address,
             checkmark_id: checkmarkId
           }
         })),
@@ -114,10 +483,12 @@ export class CwCheckmarkMessageComposer implements CwCheckmarkMessage {
       })
     };
   };
-  revokeAddress = ({
-    address
+  assignOrganization = ({
+    address,
+    checkmarkId
   }: {
     address: string;
+    checkmarkId: string;
   }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -125,20 +496,21 @@ export class CwCheckmarkMessageComposer implements CwCheckmarkMessage {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          revoke_address: {
-            address
+          assign_organization: {
+            address,
+            checkmark_id: checkmarkId
           }
         })),
         funds
       })
     };
   };
-  updateCheckmarkBan = ({
-    banIds,
-    unbanIds
+  reassignCheckmark = ({
+    address,
+    checkmarkId
   }: {
-    banIds?: string[];
-    unbanIds?: string[];
+    address: string;
+    checkmarkId: string;
   }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -146,19 +518,23 @@ export class CwCheckmarkMessageComposer implements CwCheckmarkMessage {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          update_checkmark_ban: {
-            ban_ids: banIds,
-            unban_ids: unbanIds
+          reassign_checkmark: {
+            address,
+            checkmark_id: checkmarkId
           }
         })),
         funds
       })
     };
   };
-  updateAssigner = ({
-    assigner
+  rotateCheckmark = ({
+    address,
+    newCheckmarkId,
+    reissue
   }: {
-    assigner: string;
+    address: string;
+    newCheckmarkId: string;
+    reissue?: boolean;
   }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -166,8 +542,797 @@ export class CwCheckmarkMessageComposer implements CwCheckmarkMessage {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          update_assigner: {
-            assigner
+          rotate_checkmark: {
+            address,
+            new_checkmark_id: newCheckmarkId,
+            reissue
+          }
+        })),
+        funds
+      })
+    };
+  };
+  processEpoch = ({
+    limit
+  }: {
+    limit?: number;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          process_epoch: {
+            limit
+          }
+        })),
+        funds
+      })
+    };
+  };
+  prunePendingAssignments = ({
+    limit
+  }: {
+    limit?: number;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          prune_pending_assignments: {
+            limit
+          }
+        })),
+        funds
+      })
+    };
+  };
+  delete = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          delete: {}
+        })),
+        funds
+      })
+    };
+  };
+  acceptCheckmark = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          accept_checkmark: {}
+        })),
+        funds
+      })
+    };
+  };
+  rejectCheckmark = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          reject_checkmark: {}
+        })),
+        funds
+      })
+    };
+  };
+  optOut = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          opt_out: {}
+        })),
+        funds
+      })
+    };
+  };
+  optIn = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          opt_in: {}
+        })),
+        funds
+      })
+    };
+  };
+  cancelDelete = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          cancel_delete: {}
+        })),
+        funds
+      })
+    };
+  };
+  finalizeDelete = ({
+    address
+  }: {
+    address: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          finalize_delete: {
+            address
+          }
+        })),
+        funds
+      })
+    };
+  };
+  revokeCheckmark = ({
+    checkmarkId,
+    reason
+  }: {
+    checkmarkId: string;
+    reason?: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          revoke_checkmark: {
+            checkmark_id: checkmarkId,
+            reason
+          }
+        })),
+        funds
+      })
+    };
+  };
+  revokeAddress = ({
+    address,
+    reason
+  }: {
+    address: string;
+    reason?: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          revoke_address: {
+            address,
+            reason
+          }
+        })),
+        funds
+      })
+    };
+  };
+  appeal = ({
+    evidenceHash
+  }: {
+    evidenceHash: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          appeal: {
+            evidence_hash: evidenceHash
+          }
+        })),
+        funds
+      })
+    };
+  };
+  resolveAppeal = ({
+    checkmarkId,
+    uphold
+  }: {
+    checkmarkId: string;
+    uphold: boolean;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          resolve_appeal: {
+            checkmark_id: checkmarkId,
+            uphold
+          }
+        })),
+        funds
+      })
+    };
+  };
+  report = ({
+    address,
+    evidenceHash,
+    reason
+  }: {
+    address: string;
+    evidenceHash: string;
+    reason: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          report: {
+            address,
+            evidence_hash: evidenceHash,
+            reason
+          }
+        })),
+        funds
+      })
+    };
+  };
+  resolveReport = ({
+    id,
+    status
+  }: {
+    id: number;
+    status: ReportStatus;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          resolve_report: {
+            id,
+            status
+          }
+        })),
+        funds
+      })
+    };
+  };
+  fundReportPool = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          fund_report_pool: {}
+        })),
+        funds
+      })
+    };
+  };
+  revokeIssuedBy = ({
+    assigner,
+    fromHeight,
+    limit,
+    toHeight
+  }: {
+    assigner: string;
+    fromHeight: number;
+    limit?: number;
+    toHeight: number;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          revoke_issued_by: {
+            assigner,
+            from_height: fromHeight,
+            limit,
+            to_height: toHeight
+          }
+        })),
+        funds
+      })
+    };
+  };
+  finalizeRevocation = ({
+    checkmarkId
+  }: {
+    checkmarkId: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          finalize_revocation: {
+            checkmark_id: checkmarkId
+          }
+        })),
+        funds
+      })
+    };
+  };
+  suspend = ({
+    address,
+    checkmarkId,
+    reason,
+    until
+  }: {
+    address?: string;
+    checkmarkId?: string;
+    reason: string;
+    until?: Expiration;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          suspend: {
+            address,
+            checkmark_id: checkmarkId,
+            reason,
+            until
+          }
+        })),
+        funds
+      })
+    };
+  };
+  unsuspend = ({
+    address,
+    checkmarkId
+  }: {
+    address?: string;
+    checkmarkId?: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          unsuspend: {
+            address,
+            checkmark_id: checkmarkId
+          }
+        })),
+        funds
+      })
+    };
+  };
+  updateCheckmarkBan = ({
+    banIds,
+    expiration,
+    note,
+    reason,
+    unbanIds
+  }: {
+    banIds?: string[];
+    expiration?: Expiration;
+    note?: string;
+    reason?: BanReason;
+    unbanIds?: string[];
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_checkmark_ban: {
+            ban_ids: banIds,
+            expiration,
+            note,
+            reason,
+            unban_ids: unbanIds
+          }
+        })),
+        funds
+      })
+    };
+  };
+  updateAddressBan = ({
+    banAddresses,
+    expiration,
+    note,
+    reason,
+    unbanAddresses
+  }: {
+    banAddresses?: string[];
+    expiration?: Expiration;
+    note?: string;
+    reason?: BanReason;
+    unbanAddresses?: string[];
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_address_ban: {
+            ban_addresses: banAddresses,
+            expiration,
+            note,
+            reason,
+            unban_addresses: unbanAddresses
+          }
+        })),
+        funds
+      })
+    };
+  };
+  updateAssigner = ({
+    assigner
+  }: {
+    assigner: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_assigner: {
+            assigner
+          }
+        })),
+        funds
+      })
+    };
+  };
+  executeTimelocked = ({
+    id
+  }: {
+    id: number;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          execute_timelocked: {
+            id
+          }
+        })),
+        funds
+      })
+    };
+  };
+  cancelTimelocked = ({
+    id
+  }: {
+    id: number;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          cancel_timelocked: {
+            id
+          }
+        })),
+        funds
+      })
+    };
+  };
+  linkEvmAddress = ({
+    ethAddress,
+    signature
+  }: {
+    ethAddress: string;
+    signature: Binary;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          link_evm_address: {
+            eth_address: ethAddress,
+            signature
+          }
+        })),
+        funds
+      })
+    };
+  };
+  registerPubkey = ({
+    pubkey
+  }: {
+    pubkey: Binary;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          register_pubkey: {
+            pubkey
+          }
+        })),
+        funds
+      })
+    };
+  };
+  addSecondaryAddress = ({
+    address
+  }: {
+    address: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          add_secondary_address: {
+            address
+          }
+        })),
+        funds
+      })
+    };
+  };
+  acceptSecondaryAddress = ({
+    primary
+  }: {
+    primary: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          accept_secondary_address: {
+            primary
+          }
+        })),
+        funds
+      })
+    };
+  };
+  removeSecondaryAddress = ({
+    address
+  }: {
+    address: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          remove_secondary_address: {
+            address
+          }
+        })),
+        funds
+      })
+    };
+  };
+  delegate = ({
+    to
+  }: {
+    to: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          delegate: {
+            to
+          }
+        })),
+        funds
+      })
+    };
+  };
+  undelegate = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          undelegate: {}
+        })),
+        funds
+      })
+    };
+  };
+  setGuardians = ({
+    guardians,
+    threshold
+  }: {
+    guardians: string[];
+    threshold: number;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          set_guardians: {
+            guardians,
+            threshold
+          }
+        })),
+        funds
+      })
+    };
+  };
+  approveRecovery = ({
+    holder,
+    newAddress
+  }: {
+    holder: string;
+    newAddress: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          approve_recovery: {
+            holder,
+            new_address: newAddress
+          }
+        })),
+        funds
+      })
+    };
+  };
+  cancelRecovery = ({
+    holder
+  }: {
+    holder?: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          cancel_recovery: {
+            holder
+          }
+        })),
+        funds
+      })
+    };
+  };
+  executeRecovery = ({
+    holder
+  }: {
+    holder: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          execute_recovery: {
+            holder
+          }
+        })),
+        funds
+      })
+    };
+  };
+  updateConfig = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_config: {}
+        })),
+        funds
+      })
+    };
+  };
+  updateAllowedContracts = ({
+    add,
+    remove
+  }: {
+    add?: string[];
+    remove?: string[];
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_allowed_contracts: {
+            add,
+            remove
+          }
+        })),
+        funds
+      })
+    };
+  };
+  grantRole = ({
+    address,
+    role
+  }: {
+    address: string;
+    role: Role;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          grant_role: {
+            address,
+            role
+          }
+        })),
+        funds
+      })
+    };
+  };
+  revokeRole = ({
+    address,
+    role
+  }: {
+    address: string;
+    role: Role;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          revoke_role: {
+            address,
+            role
           }
         })),
         funds
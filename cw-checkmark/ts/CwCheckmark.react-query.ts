@@ -7,7 +7,7 @@
 import { UseQueryOptions, useQuery, useMutation, UseMutationOptions } from "@tanstack/react-query";
 import { ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { StdFee, Coin } from "@cosmjs/amino";
-import { InstantiateMsg, ExecuteMsg, Action, Expiration, Timestamp, Uint64, QueryMsg, Addr, AssignerResponse, CheckmarkBannedResponse, CountResponse, GetAddressResponse, GetCheckmarkResponse, OwnershipForString } from "./CwCheckmark.types";
+import { InstantiateMsg, ExecuteMsg, Uint128, Binary, Denom, Addr, ReportStatus, Expiration, Timestamp, Uint64, BanReason, Duration, Decimal, Role, Action, Cw20ReceiveMsg, UpdateConfigMsg, FeeSplit, Fee, QueryMsg, SudoMsg, CountResponse, AddressBannedResponse, Ban, AssignerResponse, CheckmarkBannedResponse, CheckmarkIssuedResponse, Config, FeeBalancesResponse, GetAddressResponse, GetCheckmarkResponse, Suspension, GetDelegationResponse, GuardiansResponse, GuardianChange, HasRoleResponse, ListAllowedContractsResponse, ListBannedAddressesResponse, BannedAddressEntry, ListBannedCheckmarksResponse, BannedCheckmarkEntry, ListIssuedByResponse, IssuedCheckmark, ListOrganizationsResponse, OrganizationEntry, ListPendingAssignmentsResponse, PendingAssignmentEntry, PendingAssignment, ListPendingRecoveriesResponse, PendingRecovery, Recovery, RecoveryProposal, VerificationStatus, ListVerificationRequestsResponse, VerificationRequestEntry, VerificationRequest, ListReportsResponse, ReportEntry, Report, ListRoleMembersResponse, ListScheduledRevocationsResponse, ScheduledRevocationEntry, ScheduledRevocation, Appeal, TimelockedMsg, ListTimelockedActionsResponse, TimelockedActionEntry, TimelockedAction, MixingEpochResponse, OfferResponse, Offer, Issuance, OptedOutResponse, OwnershipForString, PausedResponse, PendingRecoveryResponse, ReassignCooldownResponse, ReportResponse, ReportPoolResponse, ResolveVoterResponse, ScheduledRevocationResponse, SecondaryAddressesResponse, TimelockedActionResponse, VerificationRequestResponse } from "./CwCheckmark.types";
 import { CwCheckmarkQueryClient, CwCheckmarkClient } from "./CwCheckmark.client";
 export const cwCheckmarkQueryKeys = {
   contract: ([{
@@ -24,18 +24,166 @@ export const cwCheckmarkQueryKeys = {
     method: "get_address",
     args
   }] as const),
+  getCheckmarkByEvmAddress: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "get_checkmark_by_evm_address",
+    args
+  }] as const),
+  getCheckmarkByCanonicalAddress: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "get_checkmark_by_canonical_address",
+    args
+  }] as const),
+  getCheckmarkByPubkey: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "get_checkmark_by_pubkey",
+    args
+  }] as const),
   count: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
     method: "count",
     args
   }] as const),
+  checkmarkIssued: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "checkmark_issued",
+    args
+  }] as const),
   checkmarkBanned: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
     method: "checkmark_banned",
     args
   }] as const),
+  listBannedCheckmarks: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "list_banned_checkmarks",
+    args
+  }] as const),
+  addressBanned: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "address_banned",
+    args
+  }] as const),
+  listPendingVerificationRequests: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "list_pending_verification_requests",
+    args
+  }] as const),
+  feeBalances: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "fee_balances",
+    args
+  }] as const),
+  verificationRequest: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "verification_request",
+    args
+  }] as const),
+  listAllowedContracts: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "list_allowed_contracts",
+    args
+  }] as const),
+  listOrganizations: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "list_organizations",
+    args
+  }] as const),
+  listBannedAddresses: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "list_banned_addresses",
+    args
+  }] as const),
   assigner: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
     method: "assigner",
     args
   }] as const),
+  paused: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "paused",
+    args
+  }] as const),
+  mixingEpoch: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "mixing_epoch",
+    args
+  }] as const),
+  secondaryAddresses: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "secondary_addresses",
+    args
+  }] as const),
+  getDelegation: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "get_delegation",
+    args
+  }] as const),
+  activeCount: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "active_count",
+    args
+  }] as const),
+  resolveVoter: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "resolve_voter",
+    args
+  }] as const),
+  guardians: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "guardians",
+    args
+  }] as const),
+  pendingRecovery: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "pending_recovery",
+    args
+  }] as const),
+  listPendingRecoveries: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "list_pending_recoveries",
+    args
+  }] as const),
+  scheduledRevocation: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "scheduled_revocation",
+    args
+  }] as const),
+  listScheduledRevocations: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "list_scheduled_revocations",
+    args
+  }] as const),
+  report: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "report",
+    args
+  }] as const),
+  listReportsByStatus: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "list_reports_by_status",
+    args
+  }] as const),
+  listReportsByTarget: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "list_reports_by_target",
+    args
+  }] as const),
+  reportPool: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "report_pool",
+    args
+  }] as const),
+  listIssuedBy: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "list_issued_by",
+    args
+  }] as const),
+  offer: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "offer",
+    args
+  }] as const),
+  optedOut: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "opted_out",
+    args
+  }] as const),
+  reassignCooldown: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "reassign_cooldown",
+    args
+  }] as const),
+  listRoleMembers: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "list_role_members",
+    args
+  }] as const),
+  hasRole: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "has_role",
+    args
+  }] as const),
+  listPendingAssignments: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "list_pending_assignments",
+    args
+  }] as const),
+  timelockedAction: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "timelocked_action",
+    args
+  }] as const),
+  listTimelockedActions: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "list_timelocked_actions",
+    args
+  }] as const),
+  config: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
+    method: "config",
+    args
+  }] as const),
   ownership: (contractAddress: string, args?: Record<string, unknown>) => ([{ ...cwCheckmarkQueryKeys.address(contractAddress)[0],
     method: "ownership",
     args
@@ -47,80 +195,1447 @@ export interface CwCheckmarkReactQuery<TResponse, TData = TResponse> {
     initialData?: undefined;
   };
 }
-export interface CwCheckmarkOwnershipQuery<TData> extends CwCheckmarkReactQuery<OwnershipForString, TData> {}
-export function useCwCheckmarkOwnershipQuery<TData = OwnershipForString>({
-  client,
-  options
-}: CwCheckmarkOwnershipQuery<TData>) {
-  return useQuery<OwnershipForString, Error, TData>(cwCheckmarkQueryKeys.ownership(client.contractAddress), () => client.ownership(), options);
+export interface CwCheckmarkOwnershipQuery<TData> extends CwCheckmarkReactQuery<OwnershipForString, TData> {}
+export function useCwCheckmarkOwnershipQuery<TData = OwnershipForString>({
+  client,
+  options
+}: CwCheckmarkOwnershipQuery<TData>) {
+  return useQuery<OwnershipForString, Error, TData>(cwCheckmarkQueryKeys.ownership(client.contractAddress), () => client.ownership(), options);
+}
+export interface CwCheckmarkConfigQuery<TData> extends CwCheckmarkReactQuery<Config, TData> {}
+export function useCwCheckmarkConfigQuery<TData = Config>({
+  client,
+  options
+}: CwCheckmarkConfigQuery<TData>) {
+  return useQuery<Config, Error, TData>(cwCheckmarkQueryKeys.config(client.contractAddress), () => client.config(), options);
+}
+export interface CwCheckmarkListTimelockedActionsQuery<TData> extends CwCheckmarkReactQuery<ListTimelockedActionsResponse, TData> {
+  args: {
+    limit?: number;
+    startAfter?: number;
+  };
+}
+export function useCwCheckmarkListTimelockedActionsQuery<TData = ListTimelockedActionsResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkListTimelockedActionsQuery<TData>) {
+  return useQuery<ListTimelockedActionsResponse, Error, TData>(cwCheckmarkQueryKeys.listTimelockedActions(client.contractAddress, args), () => client.listTimelockedActions({
+    limit: args.limit,
+    startAfter: args.startAfter
+  }), options);
+}
+export interface CwCheckmarkTimelockedActionQuery<TData> extends CwCheckmarkReactQuery<TimelockedActionResponse, TData> {
+  args: {
+    id: number;
+  };
+}
+export function useCwCheckmarkTimelockedActionQuery<TData = TimelockedActionResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkTimelockedActionQuery<TData>) {
+  return useQuery<TimelockedActionResponse, Error, TData>(cwCheckmarkQueryKeys.timelockedAction(client.contractAddress, args), () => client.timelockedAction({
+    id: args.id
+  }), options);
+}
+export interface CwCheckmarkListPendingAssignmentsQuery<TData> extends CwCheckmarkReactQuery<ListPendingAssignmentsResponse, TData> {
+  args: {
+    limit?: number;
+    startAfter?: string;
+  };
+}
+export function useCwCheckmarkListPendingAssignmentsQuery<TData = ListPendingAssignmentsResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkListPendingAssignmentsQuery<TData>) {
+  return useQuery<ListPendingAssignmentsResponse, Error, TData>(cwCheckmarkQueryKeys.listPendingAssignments(client.contractAddress, args), () => client.listPendingAssignments({
+    limit: args.limit,
+    startAfter: args.startAfter
+  }), options);
+}
+export interface CwCheckmarkHasRoleQuery<TData> extends CwCheckmarkReactQuery<HasRoleResponse, TData> {
+  args: {
+    address: string;
+    role: Role;
+  };
+}
+export function useCwCheckmarkHasRoleQuery<TData = HasRoleResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkHasRoleQuery<TData>) {
+  return useQuery<HasRoleResponse, Error, TData>(cwCheckmarkQueryKeys.hasRole(client.contractAddress, args), () => client.hasRole({
+    address: args.address,
+    role: args.role
+  }), options);
+}
+export interface CwCheckmarkListRoleMembersQuery<TData> extends CwCheckmarkReactQuery<ListRoleMembersResponse, TData> {
+  args: {
+    limit?: number;
+    role: Role;
+    startAfter?: string;
+  };
+}
+export function useCwCheckmarkListRoleMembersQuery<TData = ListRoleMembersResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkListRoleMembersQuery<TData>) {
+  return useQuery<ListRoleMembersResponse, Error, TData>(cwCheckmarkQueryKeys.listRoleMembers(client.contractAddress, args), () => client.listRoleMembers({
+    limit: args.limit,
+    role: args.role,
+    startAfter: args.startAfter
+  }), options);
+}
+export interface CwCheckmarkReassignCooldownQuery<TData> extends CwCheckmarkReactQuery<ReassignCooldownResponse, TData> {
+  args: {
+    address: string;
+  };
+}
+export function useCwCheckmarkReassignCooldownQuery<TData = ReassignCooldownResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkReassignCooldownQuery<TData>) {
+  return useQuery<ReassignCooldownResponse, Error, TData>(cwCheckmarkQueryKeys.reassignCooldown(client.contractAddress, args), () => client.reassignCooldown({
+    address: args.address
+  }), options);
+}
+export interface CwCheckmarkOptedOutQuery<TData> extends CwCheckmarkReactQuery<OptedOutResponse, TData> {
+  args: {
+    address: string;
+  };
+}
+export function useCwCheckmarkOptedOutQuery<TData = OptedOutResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkOptedOutQuery<TData>) {
+  return useQuery<OptedOutResponse, Error, TData>(cwCheckmarkQueryKeys.optedOut(client.contractAddress, args), () => client.optedOut({
+    address: args.address
+  }), options);
+}
+export interface CwCheckmarkOfferQuery<TData> extends CwCheckmarkReactQuery<OfferResponse, TData> {
+  args: {
+    address: string;
+  };
+}
+export function useCwCheckmarkOfferQuery<TData = OfferResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkOfferQuery<TData>) {
+  return useQuery<OfferResponse, Error, TData>(cwCheckmarkQueryKeys.offer(client.contractAddress, args), () => client.offer({
+    address: args.address
+  }), options);
+}
+export interface CwCheckmarkListIssuedByQuery<TData> extends CwCheckmarkReactQuery<ListIssuedByResponse, TData> {
+  args: {
+    assigner: string;
+    fromHeight: number;
+    limit?: number;
+    startAfter?: [number, string];
+    toHeight: number;
+  };
+}
+export function useCwCheckmarkListIssuedByQuery<TData = ListIssuedByResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkListIssuedByQuery<TData>) {
+  return useQuery<ListIssuedByResponse, Error, TData>(cwCheckmarkQueryKeys.listIssuedBy(client.contractAddress, args), () => client.listIssuedBy({
+    assigner: args.assigner,
+    fromHeight: args.fromHeight,
+    limit: args.limit,
+    startAfter: args.startAfter,
+    toHeight: args.toHeight
+  }), options);
+}
+export interface CwCheckmarkReportPoolQuery<TData> extends CwCheckmarkReactQuery<ReportPoolResponse, TData> {}
+export function useCwCheckmarkReportPoolQuery<TData = ReportPoolResponse>({
+  client,
+  options
+}: CwCheckmarkReportPoolQuery<TData>) {
+  return useQuery<ReportPoolResponse, Error, TData>(cwCheckmarkQueryKeys.reportPool(client.contractAddress), () => client.reportPool(), options);
+}
+export interface CwCheckmarkListReportsByTargetQuery<TData> extends CwCheckmarkReactQuery<ListReportsResponse, TData> {
+  args: {
+    address: string;
+    limit?: number;
+    startAfter?: number;
+  };
+}
+export function useCwCheckmarkListReportsByTargetQuery<TData = ListReportsResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkListReportsByTargetQuery<TData>) {
+  return useQuery<ListReportsResponse, Error, TData>(cwCheckmarkQueryKeys.listReportsByTarget(client.contractAddress, args), () => client.listReportsByTarget({
+    address: args.address,
+    limit: args.limit,
+    startAfter: args.startAfter
+  }), options);
+}
+export interface CwCheckmarkListReportsByStatusQuery<TData> extends CwCheckmarkReactQuery<ListReportsResponse, TData> {
+  args: {
+    limit?: number;
+    startAfter?: number;
+    status: ReportStatus;
+  };
+}
+export function useCwCheckmarkListReportsByStatusQuery<TData = ListReportsResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkListReportsByStatusQuery<TData>) {
+  return useQuery<ListReportsResponse, Error, TData>(cwCheckmarkQueryKeys.listReportsByStatus(client.contractAddress, args), () => client.listReportsByStatus({
+    limit: args.limit,
+    startAfter: args.startAfter,
+    status: args.status
+  }), options);
+}
+export interface CwCheckmarkReportQuery<TData> extends CwCheckmarkReactQuery<ReportResponse, TData> {
+  args: {
+    id: number;
+  };
+}
+export function useCwCheckmarkReportQuery<TData = ReportResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkReportQuery<TData>) {
+  return useQuery<ReportResponse, Error, TData>(cwCheckmarkQueryKeys.report(client.contractAddress, args), () => client.report({
+    id: args.id
+  }), options);
+}
+export interface CwCheckmarkListScheduledRevocationsQuery<TData> extends CwCheckmarkReactQuery<ListScheduledRevocationsResponse, TData> {
+  args: {
+    limit?: number;
+    startAfter?: string;
+  };
+}
+export function useCwCheckmarkListScheduledRevocationsQuery<TData = ListScheduledRevocationsResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkListScheduledRevocationsQuery<TData>) {
+  return useQuery<ListScheduledRevocationsResponse, Error, TData>(cwCheckmarkQueryKeys.listScheduledRevocations(client.contractAddress, args), () => client.listScheduledRevocations({
+    limit: args.limit,
+    startAfter: args.startAfter
+  }), options);
+}
+export interface CwCheckmarkScheduledRevocationQuery<TData> extends CwCheckmarkReactQuery<ScheduledRevocationResponse, TData> {
+  args: {
+    checkmarkId: string;
+  };
+}
+export function useCwCheckmarkScheduledRevocationQuery<TData = ScheduledRevocationResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkScheduledRevocationQuery<TData>) {
+  return useQuery<ScheduledRevocationResponse, Error, TData>(cwCheckmarkQueryKeys.scheduledRevocation(client.contractAddress, args), () => client.scheduledRevocation({
+    checkmarkId: args.checkmarkId
+  }), options);
+}
+export interface CwCheckmarkListPendingRecoveriesQuery<TData> extends CwCheckmarkReactQuery<ListPendingRecoveriesResponse, TData> {
+  args: {
+    limit?: number;
+    startAfter?: string;
+  };
+}
+export function useCwCheckmarkListPendingRecoveriesQuery<TData = ListPendingRecoveriesResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkListPendingRecoveriesQuery<TData>) {
+  return useQuery<ListPendingRecoveriesResponse, Error, TData>(cwCheckmarkQueryKeys.listPendingRecoveries(client.contractAddress, args), () => client.listPendingRecoveries({
+    limit: args.limit,
+    startAfter: args.startAfter
+  }), options);
+}
+export interface CwCheckmarkPendingRecoveryQuery<TData> extends CwCheckmarkReactQuery<PendingRecoveryResponse, TData> {
+  args: {
+    address: string;
+  };
+}
+export function useCwCheckmarkPendingRecoveryQuery<TData = PendingRecoveryResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkPendingRecoveryQuery<TData>) {
+  return useQuery<PendingRecoveryResponse, Error, TData>(cwCheckmarkQueryKeys.pendingRecovery(client.contractAddress, args), () => client.pendingRecovery({
+    address: args.address
+  }), options);
+}
+export interface CwCheckmarkGuardiansQuery<TData> extends CwCheckmarkReactQuery<GuardiansResponse, TData> {
+  args: {
+    address: string;
+  };
+}
+export function useCwCheckmarkGuardiansQuery<TData = GuardiansResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkGuardiansQuery<TData>) {
+  return useQuery<GuardiansResponse, Error, TData>(cwCheckmarkQueryKeys.guardians(client.contractAddress, args), () => client.guardians({
+    address: args.address
+  }), options);
+}
+export interface CwCheckmarkResolveVoterQuery<TData> extends CwCheckmarkReactQuery<ResolveVoterResponse, TData> {
+  args: {
+    address: string;
+  };
+}
+export function useCwCheckmarkResolveVoterQuery<TData = ResolveVoterResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkResolveVoterQuery<TData>) {
+  return useQuery<ResolveVoterResponse, Error, TData>(cwCheckmarkQueryKeys.resolveVoter(client.contractAddress, args), () => client.resolveVoter({
+    address: args.address
+  }), options);
+}
+export interface CwCheckmarkActiveCountQuery<TData> extends CwCheckmarkReactQuery<CountResponse, TData> {}
+export function useCwCheckmarkActiveCountQuery<TData = CountResponse>({
+  client,
+  options
+}: CwCheckmarkActiveCountQuery<TData>) {
+  return useQuery<CountResponse, Error, TData>(cwCheckmarkQueryKeys.activeCount(client.contractAddress), () => client.activeCount(), options);
+}
+export interface CwCheckmarkGetDelegationQuery<TData> extends CwCheckmarkReactQuery<GetDelegationResponse, TData> {
+  args: {
+    address: string;
+  };
+}
+export function useCwCheckmarkGetDelegationQuery<TData = GetDelegationResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkGetDelegationQuery<TData>) {
+  return useQuery<GetDelegationResponse, Error, TData>(cwCheckmarkQueryKeys.getDelegation(client.contractAddress, args), () => client.getDelegation({
+    address: args.address
+  }), options);
+}
+export interface CwCheckmarkSecondaryAddressesQuery<TData> extends CwCheckmarkReactQuery<SecondaryAddressesResponse, TData> {
+  args: {
+    address: string;
+  };
+}
+export function useCwCheckmarkSecondaryAddressesQuery<TData = SecondaryAddressesResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkSecondaryAddressesQuery<TData>) {
+  return useQuery<SecondaryAddressesResponse, Error, TData>(cwCheckmarkQueryKeys.secondaryAddresses(client.contractAddress, args), () => client.secondaryAddresses({
+    address: args.address
+  }), options);
+}
+export interface CwCheckmarkMixingEpochQuery<TData> extends CwCheckmarkReactQuery<MixingEpochResponse, TData> {}
+export function useCwCheckmarkMixingEpochQuery<TData = MixingEpochResponse>({
+  client,
+  options
+}: CwCheckmarkMixingEpochQuery<TData>) {
+  return useQuery<MixingEpochResponse, Error, TData>(cwCheckmarkQueryKeys.mixingEpoch(client.contractAddress), () => client.mixingEpoch(), options);
+}
+export interface CwCheckmarkPausedQuery<TData> extends CwCheckmarkReactQuery<PausedResponse, TData> {}
+export function useCwCheckmarkPausedQuery<TData = PausedResponse>({
+  client,
+  options
+}: CwCheckmarkPausedQuery<TData>) {
+  return useQuery<PausedResponse, Error, TData>(cwCheckmarkQueryKeys.paused(client.contractAddress), () => client.paused(), options);
+}
+export interface CwCheckmarkAssignerQuery<TData> extends CwCheckmarkReactQuery<AssignerResponse, TData> {}
+export function useCwCheckmarkAssignerQuery<TData = AssignerResponse>({
+  client,
+  options
+}: CwCheckmarkAssignerQuery<TData>) {
+  return useQuery<AssignerResponse, Error, TData>(cwCheckmarkQueryKeys.assigner(client.contractAddress), () => client.assigner(), options);
+}
+export interface CwCheckmarkListBannedAddressesQuery<TData> extends CwCheckmarkReactQuery<ListBannedAddressesResponse, TData> {
+  args: {
+    limit?: number;
+    startAfter?: string;
+  };
+}
+export function useCwCheckmarkListBannedAddressesQuery<TData = ListBannedAddressesResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkListBannedAddressesQuery<TData>) {
+  return useQuery<ListBannedAddressesResponse, Error, TData>(cwCheckmarkQueryKeys.listBannedAddresses(client.contractAddress, args), () => client.listBannedAddresses({
+    limit: args.limit,
+    startAfter: args.startAfter
+  }), options);
+}
+export interface CwCheckmarkListOrganizationsQuery<TData> extends CwCheckmarkReactQuery<ListOrganizationsResponse, TData> {
+  args: {
+    limit?: number;
+    startAfter?: string;
+  };
+}
+export function useCwCheckmarkListOrganizationsQuery<TData = ListOrganizationsResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkListOrganizationsQuery<TData>) {
+  return useQuery<ListOrganizationsResponse, Error, TData>(cwCheckmarkQueryKeys.listOrganizations(client.contractAddress, args), () => client.listOrganizations({
+    limit: args.limit,
+    startAfter: args.startAfter
+  }), options);
+}
+export interface CwCheckmarkListAllowedContractsQuery<TData> extends CwCheckmarkReactQuery<ListAllowedContractsResponse, TData> {
+  args: {
+    limit?: number;
+    startAfter?: string;
+  };
+}
+export function useCwCheckmarkListAllowedContractsQuery<TData = ListAllowedContractsResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkListAllowedContractsQuery<TData>) {
+  return useQuery<ListAllowedContractsResponse, Error, TData>(cwCheckmarkQueryKeys.listAllowedContracts(client.contractAddress, args), () => client.listAllowedContracts({
+    limit: args.limit,
+    startAfter: args.startAfter
+  }), options);
+}
+export interface CwCheckmarkVerificationRequestQuery<TData> extends CwCheckmarkReactQuery<VerificationRequestResponse, TData> {
+  args: {
+    address: string;
+  };
+}
+export function useCwCheckmarkVerificationRequestQuery<TData = VerificationRequestResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkVerificationRequestQuery<TData>) {
+  return useQuery<VerificationRequestResponse, Error, TData>(cwCheckmarkQueryKeys.verificationRequest(client.contractAddress, args), () => client.verificationRequest({
+    address: args.address
+  }), options);
+}
+export interface CwCheckmarkFeeBalancesQuery<TData> extends CwCheckmarkReactQuery<FeeBalancesResponse, TData> {}
+export function useCwCheckmarkFeeBalancesQuery<TData = FeeBalancesResponse>({
+  client,
+  options
+}: CwCheckmarkFeeBalancesQuery<TData>) {
+  return useQuery<FeeBalancesResponse, Error, TData>(cwCheckmarkQueryKeys.feeBalances(client.contractAddress), () => client.feeBalances(), options);
+}
+export interface CwCheckmarkListPendingVerificationRequestsQuery<TData> extends CwCheckmarkReactQuery<ListVerificationRequestsResponse, TData> {
+  args: {
+    limit?: number;
+    startAfter?: string;
+  };
+}
+export function useCwCheckmarkListPendingVerificationRequestsQuery<TData = ListVerificationRequestsResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkListPendingVerificationRequestsQuery<TData>) {
+  return useQuery<ListVerificationRequestsResponse, Error, TData>(cwCheckmarkQueryKeys.listPendingVerificationRequests(client.contractAddress, args), () => client.listPendingVerificationRequests({
+    limit: args.limit,
+    startAfter: args.startAfter
+  }), options);
+}
+export interface CwCheckmarkAddressBannedQuery<TData> extends CwCheckmarkReactQuery<AddressBannedResponse, TData> {
+  args: {
+    address: string;
+  };
+}
+export function useCwCheckmarkAddressBannedQuery<TData = AddressBannedResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkAddressBannedQuery<TData>) {
+  return useQuery<AddressBannedResponse, Error, TData>(cwCheckmarkQueryKeys.addressBanned(client.contractAddress, args), () => client.addressBanned({
+    address: args.address
+  }), options);
+}
+export interface CwCheckmarkListBannedCheckmarksQuery<TData> extends CwCheckmarkReactQuery<ListBannedCheckmarksResponse, TData> {
+  args: {
+    limit?: number;
+    startAfter?: string;
+  };
+}
+export function useCwCheckmarkListBannedCheckmarksQuery<TData = ListBannedCheckmarksResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkListBannedCheckmarksQuery<TData>) {
+  return useQuery<ListBannedCheckmarksResponse, Error, TData>(cwCheckmarkQueryKeys.listBannedCheckmarks(client.contractAddress, args), () => client.listBannedCheckmarks({
+    limit: args.limit,
+    startAfter: args.startAfter
+  }), options);
+}
+export interface CwCheckmarkCheckmarkBannedQuery<TData> extends CwCheckmarkReactQuery<CheckmarkBannedResponse, TData> {
+  args: {
+    checkmarkId: string;
+  };
+}
+export function useCwCheckmarkCheckmarkBannedQuery<TData = CheckmarkBannedResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkCheckmarkBannedQuery<TData>) {
+  return useQuery<CheckmarkBannedResponse, Error, TData>(cwCheckmarkQueryKeys.checkmarkBanned(client.contractAddress, args), () => client.checkmarkBanned({
+    checkmarkId: args.checkmarkId
+  }), options);
+}
+export interface CwCheckmarkCheckmarkIssuedQuery<TData> extends CwCheckmarkReactQuery<CheckmarkIssuedResponse, TData> {
+  args: {
+    checkmarkId: string;
+  };
+}
+export function useCwCheckmarkCheckmarkIssuedQuery<TData = CheckmarkIssuedResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkCheckmarkIssuedQuery<TData>) {
+  return useQuery<CheckmarkIssuedResponse, Error, TData>(cwCheckmarkQueryKeys.checkmarkIssued(client.contractAddress, args), () => client.checkmarkIssued({
+    checkmarkId: args.checkmarkId
+  }), options);
+}
+export interface CwCheckmarkCountQuery<TData> extends CwCheckmarkReactQuery<CountResponse, TData> {}
+export function useCwCheckmarkCountQuery<TData = CountResponse>({
+  client,
+  options
+}: CwCheckmarkCountQuery<TData>) {
+  return useQuery<CountResponse, Error, TData>(cwCheckmarkQueryKeys.count(client.contractAddress), () => client.count(), options);
+}
+export interface CwCheckmarkGetCheckmarkByPubkeyQuery<TData> extends CwCheckmarkReactQuery<GetCheckmarkResponse, TData> {
+  args: {
+    pubkey: Binary;
+  };
+}
+export function useCwCheckmarkGetCheckmarkByPubkeyQuery<TData = GetCheckmarkResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkGetCheckmarkByPubkeyQuery<TData>) {
+  return useQuery<GetCheckmarkResponse, Error, TData>(cwCheckmarkQueryKeys.getCheckmarkByPubkey(client.contractAddress, args), () => client.getCheckmarkByPubkey({
+    pubkey: args.pubkey
+  }), options);
+}
+export interface CwCheckmarkGetCheckmarkByCanonicalAddressQuery<TData> extends CwCheckmarkReactQuery<GetCheckmarkResponse, TData> {
+  args: {
+    canonicalAddress: Binary;
+  };
+}
+export function useCwCheckmarkGetCheckmarkByCanonicalAddressQuery<TData = GetCheckmarkResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkGetCheckmarkByCanonicalAddressQuery<TData>) {
+  return useQuery<GetCheckmarkResponse, Error, TData>(cwCheckmarkQueryKeys.getCheckmarkByCanonicalAddress(client.contractAddress, args), () => client.getCheckmarkByCanonicalAddress({
+    canonicalAddress: args.canonicalAddress
+  }), options);
+}
+export interface CwCheckmarkGetCheckmarkByEvmAddressQuery<TData> extends CwCheckmarkReactQuery<GetCheckmarkResponse, TData> {
+  args: {
+    ethAddress: string;
+  };
+}
+export function useCwCheckmarkGetCheckmarkByEvmAddressQuery<TData = GetCheckmarkResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkGetCheckmarkByEvmAddressQuery<TData>) {
+  return useQuery<GetCheckmarkResponse, Error, TData>(cwCheckmarkQueryKeys.getCheckmarkByEvmAddress(client.contractAddress, args), () => client.getCheckmarkByEvmAddress({
+    ethAddress: args.ethAddress
+  }), options);
+}
+export interface CwCheckmarkGetAddressQuery<TData> extends CwCheckmarkReactQuery<GetAddressResponse, TData> {
+  args: {
+    checkmarkId: string;
+  };
+}
+export function useCwCheckmarkGetAddressQuery<TData = GetAddressResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkGetAddressQuery<TData>) {
+  return useQuery<GetAddressResponse, Error, TData>(cwCheckmarkQueryKeys.getAddress(client.contractAddress, args), () => client.getAddress({
+    checkmarkId: args.checkmarkId
+  }), options);
+}
+export interface CwCheckmarkGetCheckmarkQuery<TData> extends CwCheckmarkReactQuery<GetCheckmarkResponse, TData> {
+  args: {
+    address: string;
+  };
+}
+export function useCwCheckmarkGetCheckmarkQuery<TData = GetCheckmarkResponse>({
+  client,
+  args,
+  options
+}: CwCheckmarkGetCheckmarkQuery<TData>) {
+  return useQuery<GetCheckmarkResponse, Error, TData>(cwCheckmarkQueryKeys.getCheckmark(client.contractAddress, args), () => client.getCheckmark({
+    address: args.address
+  }), options);
+}
+export interface CwCheckmarkUpdateOwnershipMutation {
+  client: CwCheckmarkClient;
+  msg: Action;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkUpdateOwnershipMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkUpdateOwnershipMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkUpdateOwnershipMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.updateOwnership(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkRevokeRoleMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    address: string;
+    role: Role;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkRevokeRoleMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkRevokeRoleMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkRevokeRoleMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.revokeRole(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkGrantRoleMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    address: string;
+    role: Role;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkGrantRoleMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkGrantRoleMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkGrantRoleMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.grantRole(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkUpdateAllowedContractsMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    add?: string[];
+    remove?: string[];
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkUpdateAllowedContractsMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkUpdateAllowedContractsMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkUpdateAllowedContractsMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.updateAllowedContracts(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkUpdateConfigMutation {
+  client: CwCheckmarkClient;
+  msg: UpdateConfigMsg;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkUpdateConfigMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkUpdateConfigMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkUpdateConfigMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.updateConfig(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkExecuteRecoveryMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    holder: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkExecuteRecoveryMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkExecuteRecoveryMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkExecuteRecoveryMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.executeRecovery(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkCancelRecoveryMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    holder?: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkCancelRecoveryMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkCancelRecoveryMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkCancelRecoveryMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.cancelRecovery(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkApproveRecoveryMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    holder: string;
+    newAddress: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkApproveRecoveryMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkApproveRecoveryMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkApproveRecoveryMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.approveRecovery(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkSetGuardiansMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    guardians: string[];
+    threshold: number;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkSetGuardiansMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkSetGuardiansMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkSetGuardiansMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.setGuardians(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkUndelegateMutation {
+  client: CwCheckmarkClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkUndelegateMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkUndelegateMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkUndelegateMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.undelegate(fee, memo, funds), options);
+}
+export interface CwCheckmarkDelegateMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    to: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkDelegateMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkDelegateMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkDelegateMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.delegate(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkRemoveSecondaryAddressMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    address: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkRemoveSecondaryAddressMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkRemoveSecondaryAddressMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkRemoveSecondaryAddressMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.removeSecondaryAddress(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkAcceptSecondaryAddressMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    primary: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkAcceptSecondaryAddressMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkAcceptSecondaryAddressMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkAcceptSecondaryAddressMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.acceptSecondaryAddress(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkAddSecondaryAddressMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    address: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkAddSecondaryAddressMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkAddSecondaryAddressMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkAddSecondaryAddressMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.addSecondaryAddress(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkRegisterPubkeyMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    pubkey: Binary;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkRegisterPubkeyMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkRegisterPubkeyMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkRegisterPubkeyMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.registerPubkey(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkLinkEvmAddressMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    ethAddress: string;
+    signature: Binary;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkLinkEvmAddressMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkLinkEvmAddressMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkLinkEvmAddressMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.linkEvmAddress(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkCancelTimelockedMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    id: number;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkCancelTimelockedMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkCancelTimelockedMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkCancelTimelockedMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.cancelTimelocked(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkExecuteTimelockedMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    id: number;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkExecuteTimelockedMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkExecuteTimelockedMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkExecuteTimelockedMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.executeTimelocked(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkUpdateAssignerMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    assigner: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkUpdateAssignerMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkUpdateAssignerMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkUpdateAssignerMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.updateAssigner(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkUpdateAddressBanMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    banAddresses?: string[];
+    expiration?: Expiration;
+    note?: string;
+    reason?: BanReason;
+    unbanAddresses?: string[];
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkUpdateAddressBanMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkUpdateAddressBanMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkUpdateAddressBanMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.updateAddressBan(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkUpdateCheckmarkBanMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    banIds?: string[];
+    expiration?: Expiration;
+    note?: string;
+    reason?: BanReason;
+    unbanIds?: string[];
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkUpdateCheckmarkBanMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkUpdateCheckmarkBanMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkUpdateCheckmarkBanMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.updateCheckmarkBan(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkUnsuspendMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    address?: string;
+    checkmarkId?: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkUnsuspendMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkUnsuspendMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkUnsuspendMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.unsuspend(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkSuspendMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    address?: string;
+    checkmarkId?: string;
+    reason: string;
+    until?: Expiration;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkSuspendMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkSuspendMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkSuspendMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.suspend(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkFinalizeRevocationMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    checkmarkId: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkFinalizeRevocationMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkFinalizeRevocationMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkFinalizeRevocationMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.finalizeRevocation(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkRevokeIssuedByMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    assigner: string;
+    fromHeight: number;
+    limit?: number;
+    toHeight: number;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkRevokeIssuedByMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkRevokeIssuedByMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkRevokeIssuedByMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.revokeIssuedBy(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkFundReportPoolMutation {
+  client: CwCheckmarkClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkFundReportPoolMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkFundReportPoolMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkFundReportPoolMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.fundReportPool(fee, memo, funds), options);
+}
+export interface CwCheckmarkResolveReportMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    id: number;
+    status: ReportStatus;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkResolveReportMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkResolveReportMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkResolveReportMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.resolveReport(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkReportMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    address: string;
+    evidenceHash: string;
+    reason: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkReportMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkReportMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkReportMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.report(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkResolveAppealMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    checkmarkId: string;
+    uphold: boolean;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkResolveAppealMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkResolveAppealMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkResolveAppealMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.resolveAppeal(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkAppealMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    evidenceHash: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkAppealMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkAppealMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkAppealMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.appeal(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkRevokeAddressMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    address: string;
+    reason?: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkRevokeAddressMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkRevokeAddressMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkRevokeAddressMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.revokeAddress(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkRevokeCheckmarkMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    checkmarkId: string;
+    reason?: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkRevokeCheckmarkMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkRevokeCheckmarkMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkRevokeCheckmarkMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.revokeCheckmark(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkFinalizeDeleteMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    address: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkFinalizeDeleteMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkFinalizeDeleteMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkFinalizeDeleteMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.finalizeDelete(msg, fee, memo, funds), options);
 }
-export interface CwCheckmarkAssignerQuery<TData> extends CwCheckmarkReactQuery<AssignerResponse, TData> {}
-export function useCwCheckmarkAssignerQuery<TData = AssignerResponse>({
-  client,
-  options
-}: CwCheckmarkAssignerQuery<TData>) {
-  return useQuery<AssignerResponse, Error, TData>(cwCheckmarkQueryKeys.assigner(client.contractAddress), () => client.assigner(), options);
+export interface CwCheckmarkCancelDeleteMutation {
+  client: CwCheckmarkClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
 }
-export interface CwCheckmarkCheckmarkBannedQuery<TData> extends CwCheckmarkReactQuery<CheckmarkBannedResponse, TData> {
-  args: {
-    checkmarkId: string;
+export function useCwCheckmarkCancelDeleteMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkCancelDeleteMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkCancelDeleteMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.cancelDelete(fee, memo, funds), options);
+}
+export interface CwCheckmarkOptInMutation {
+  client: CwCheckmarkClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
   };
 }
-export function useCwCheckmarkCheckmarkBannedQuery<TData = CheckmarkBannedResponse>({
-  client,
-  args,
-  options
-}: CwCheckmarkCheckmarkBannedQuery<TData>) {
-  return useQuery<CheckmarkBannedResponse, Error, TData>(cwCheckmarkQueryKeys.checkmarkBanned(client.contractAddress, args), () => client.checkmarkBanned({
-    checkmarkId: args.checkmarkId
-  }), options);
+export function useCwCheckmarkOptInMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkOptInMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkOptInMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.optIn(fee, memo, funds), options);
 }
-export interface CwCheckmarkCountQuery<TData> extends CwCheckmarkReactQuery<CountResponse, TData> {}
-export function useCwCheckmarkCountQuery<TData = CountResponse>({
-  client,
-  options
-}: CwCheckmarkCountQuery<TData>) {
-  return useQuery<CountResponse, Error, TData>(cwCheckmarkQueryKeys.count(client.contractAddress), () => client.count(), options);
+export interface CwCheckmarkOptOutMutation {
+  client: CwCheckmarkClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
 }
-export interface CwCheckmarkGetAddressQuery<TData> extends CwCheckmarkReactQuery<GetAddressResponse, TData> {
-  args: {
-    checkmarkId: string;
+export function useCwCheckmarkOptOutMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkOptOutMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkOptOutMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.optOut(fee, memo, funds), options);
+}
+export interface CwCheckmarkRejectCheckmarkMutation {
+  client: CwCheckmarkClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
   };
 }
-export function useCwCheckmarkGetAddressQuery<TData = GetAddressResponse>({
-  client,
-  args,
-  options
-}: CwCheckmarkGetAddressQuery<TData>) {
-  return useQuery<GetAddressResponse, Error, TData>(cwCheckmarkQueryKeys.getAddress(client.contractAddress, args), () => client.getAddress({
-    checkmarkId: args.checkmarkId
-  }), options);
+export function useCwCheckmarkRejectCheckmarkMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkRejectCheckmarkMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkRejectCheckmarkMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.rejectCheckmark(fee, memo, funds), options);
 }
-export interface CwCheckmarkGetCheckmarkQuery<TData> extends CwCheckmarkReactQuery<GetCheckmarkResponse, TData> {
-  args: {
-    address: string;
+export interface CwCheckmarkAcceptCheckmarkMutation {
+  client: CwCheckmarkClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
   };
 }
-export function useCwCheckmarkGetCheckmarkQuery<TData = GetCheckmarkResponse>({
-  client,
-  args,
-  options
-}: CwCheckmarkGetCheckmarkQuery<TData>) {
-  return useQuery<GetCheckmarkResponse, Error, TData>(cwCheckmarkQueryKeys.getCheckmark(client.contractAddress, args), () => client.getCheckmark({
-    address: args.address
-  }), options);
+export function useCwCheckmarkAcceptCheckmarkMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkAcceptCheckmarkMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkAcceptCheckmarkMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.acceptCheckmark(fee, memo, funds), options);
 }
-export interface CwCheckmarkUpdateOwnershipMutation {
+export interface CwCheckmarkDeleteMutation {
   client: CwCheckmarkClient;
-  msg: Action;
   args?: {
     fee?: number | StdFee | "auto";
     memo?: string;
     funds?: Coin[];
   };
 }
-export function useCwCheckmarkUpdateOwnershipMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkUpdateOwnershipMutation>, "mutationFn">) {
-  return useMutation<ExecuteResult, Error, CwCheckmarkUpdateOwnershipMutation>(({
+export function useCwCheckmarkDeleteMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkDeleteMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkDeleteMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.delete(fee, memo, funds), options);
+}
+export interface CwCheckmarkPrunePendingAssignmentsMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    limit?: number;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkPrunePendingAssignmentsMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkPrunePendingAssignmentsMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkPrunePendingAssignmentsMutation>(({
     client,
     msg,
     args: {
@@ -128,12 +1643,12 @@ export function useCwCheckmarkUpdateOwnershipMutation(options?: Omit<UseMutation
       memo,
       funds
     } = {}
-  }) => client.updateOwnership(msg, fee, memo, funds), options);
+  }) => client.prunePendingAssignments(msg, fee, memo, funds), options);
 }
-export interface CwCheckmarkUpdateAssignerMutation {
+export interface CwCheckmarkProcessEpochMutation {
   client: CwCheckmarkClient;
   msg: {
-    assigner: string;
+    limit?: number;
   };
   args?: {
     fee?: number | StdFee | "auto";
@@ -141,8 +1656,8 @@ export interface CwCheckmarkUpdateAssignerMutation {
     funds?: Coin[];
   };
 }
-export function useCwCheckmarkUpdateAssignerMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkUpdateAssignerMutation>, "mutationFn">) {
-  return useMutation<ExecuteResult, Error, CwCheckmarkUpdateAssignerMutation>(({
+export function useCwCheckmarkProcessEpochMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkProcessEpochMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkProcessEpochMutation>(({
     client,
     msg,
     args: {
@@ -150,13 +1665,14 @@ export function useCwCheckmarkUpdateAssignerMutation(options?: Omit<UseMutationO
       memo,
       funds
     } = {}
-  }) => client.updateAssigner(msg, fee, memo, funds), options);
+  }) => client.processEpoch(msg, fee, memo, funds), options);
 }
-export interface CwCheckmarkUpdateCheckmarkBanMutation {
+export interface CwCheckmarkRotateCheckmarkMutation {
   client: CwCheckmarkClient;
   msg: {
-    banIds?: string[];
-    unbanIds?: string[];
+    address: string;
+    newCheckmarkId: string;
+    reissue?: boolean;
   };
   args?: {
     fee?: number | StdFee | "auto";
@@ -164,8 +1680,8 @@ export interface CwCheckmarkUpdateCheckmarkBanMutation {
     funds?: Coin[];
   };
 }
-export function useCwCheckmarkUpdateCheckmarkBanMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkUpdateCheckmarkBanMutation>, "mutationFn">) {
-  return useMutation<ExecuteResult, Error, CwCheckmarkUpdateCheckmarkBanMutation>(({
+export function useCwCheckmarkRotateCheckmarkMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkRotateCheckmarkMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkRotateCheckmarkMutation>(({
     client,
     msg,
     args: {
@@ -173,12 +1689,13 @@ export function useCwCheckmarkUpdateCheckmarkBanMutation(options?: Omit<UseMutat
       memo,
       funds
     } = {}
-  }) => client.updateCheckmarkBan(msg, fee, memo, funds), options);
+  }) => client.rotateCheckmark(msg, fee, memo, funds), options);
 }
-export interface CwCheckmarkRevokeAddressMutation {
+export interface CwCheckmarkReassignCheckmarkMutation {
   client: CwCheckmarkClient;
   msg: {
     address: string;
+    checkmarkId: string;
   };
   args?: {
     fee?: number | StdFee | "auto";
@@ -186,8 +1703,8 @@ export interface CwCheckmarkRevokeAddressMutation {
     funds?: Coin[];
   };
 }
-export function useCwCheckmarkRevokeAddressMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkRevokeAddressMutation>, "mutationFn">) {
-  return useMutation<ExecuteResult, Error, CwCheckmarkRevokeAddressMutation>(({
+export function useCwCheckmarkReassignCheckmarkMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkReassignCheckmarkMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkReassignCheckmarkMutation>(({
     client,
     msg,
     args: {
@@ -195,11 +1712,12 @@ export function useCwCheckmarkRevokeAddressMutation(options?: Omit<UseMutationOp
       memo,
       funds
     } = {}
-  }) => client.revokeAddress(msg, fee, memo, funds), options);
+  }) => client.reassignCheckmark(msg, fee, memo, funds), options);
 }
-export interface CwCheckmarkRevokeCheckmarkMutation {
+export interface CwCheckmarkAssignOrganizationMutation {
   client: CwCheckmarkClient;
   msg: {
+    address: string;
     checkmarkId: string;
   };
   args?: {
@@ -208,8 +1726,8 @@ export interface CwCheckmarkRevokeCheckmarkMutation {
     funds?: Coin[];
   };
 }
-export function useCwCheckmarkRevokeCheckmarkMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkRevokeCheckmarkMutation>, "mutationFn">) {
-  return useMutation<ExecuteResult, Error, CwCheckmarkRevokeCheckmarkMutation>(({
+export function useCwCheckmarkAssignOrganizationMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkAssignOrganizationMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkAssignOrganizationMutation>(({
     client,
     msg,
     args: {
@@ -217,25 +1735,136 @@ export function useCwCheckmarkRevokeCheckmarkMutation(options?: Omit<UseMutation
       memo,
       funds
     } = {}
-  }) => client.revokeCheckmark(msg, fee, memo, funds), options);
+  }) => client.assignOrganization(msg, fee, memo, funds), options);
 }
-export interface CwCheckmarkDeleteMutation {
+export interface CwCheckmarkApproveAssignMutation {
   client: CwCheckmarkClient;
+  msg: {
+    address: string;
+    checkmarkId: string;
+  };
   args?: {
     fee?: number | StdFee | "auto";
     memo?: string;
     funds?: Coin[];
   };
 }
-export function useCwCheckmarkDeleteMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkDeleteMutation>, "mutationFn">) {
-  return useMutation<ExecuteResult, Error, CwCheckmarkDeleteMutation>(({
+export function useCwCheckmarkApproveAssignMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkApproveAssignMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkApproveAssignMutation>(({
     client,
+    msg,
     args: {
       fee,
       memo,
       funds
     } = {}
-  }) => client.delete(fee, memo, funds), options);
+  }) => client.approveAssign(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkWithdrawFeesMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    denom: Denom;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkWithdrawFeesMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkWithdrawFeesMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkWithdrawFeesMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.withdrawFees(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkCancelVerificationMutation {
+  client: CwCheckmarkClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkCancelVerificationMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkCancelVerificationMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkCancelVerificationMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.cancelVerification(fee, memo, funds), options);
+}
+export interface CwCheckmarkRejectVerificationMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    address: string;
+    reasonCode: number;
+    refund?: Uint128;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkRejectVerificationMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkRejectVerificationMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkRejectVerificationMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.rejectVerification(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkRequestVerificationMutation {
+  client: CwCheckmarkClient;
+  msg: {
+    sessionCommitment: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkRequestVerificationMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkRequestVerificationMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkRequestVerificationMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.requestVerification(msg, fee, memo, funds), options);
+}
+export interface CwCheckmarkReceiveMutation {
+  client: CwCheckmarkClient;
+  msg: Cw20ReceiveMsg;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useCwCheckmarkReceiveMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, CwCheckmarkReceiveMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, CwCheckmarkReceiveMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.receive(msg, fee, memo, funds), options);
 }
 export interface CwCheckmarkAssignMutation {
   client: CwCheckmarkClient;
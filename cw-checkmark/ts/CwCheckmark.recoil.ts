@@ -6,7 +6,7 @@
 
 import { selectorFamily } from "recoil";
 import { cosmWasmClient } from "./chain";
-import { InstantiateMsg, ExecuteMsg, Action, Expiration, Timestamp, Uint64, QueryMsg, Addr, AssignerResponse, CheckmarkBannedResponse, CountResponse, GetAddressResponse, GetCheckmarkResponse, OwnershipForString } from "./CwCheckmark.types";
+import { InstantiateMsg, ExecuteMsg, Uint128, Binary, Denom, Addr, ReportStatus, Expiration, Timestamp, Uint64, BanReason, Duration, Decimal, Role, Action, Cw20ReceiveMsg, UpdateConfigMsg, FeeSplit, Fee, Coin, QueryMsg, SudoMsg, CountResponse, AddressBannedResponse, Ban, AssignerResponse, CheckmarkBannedResponse, CheckmarkIssuedResponse, Config, FeeBalancesResponse, GetAddressResponse, GetCheckmarkResponse, Suspension, GetDelegationResponse, GuardiansResponse, GuardianChange, HasRoleResponse, ListAllowedContractsResponse, ListBannedAddressesResponse, BannedAddressEntry, ListBannedCheckmarksResponse, BannedCheckmarkEntry, ListIssuedByResponse, IssuedCheckmark, ListOrganizationsResponse, OrganizationEntry, ListPendingAssignmentsResponse, PendingAssignmentEntry, PendingAssignment, ListPendingRecoveriesResponse, PendingRecovery, Recovery, RecoveryProposal, VerificationStatus, ListVerificationRequestsResponse, VerificationRequestEntry, VerificationRequest, ListReportsResponse, ReportEntry, Report, ListRoleMembersResponse, ListScheduledRevocationsResponse, ScheduledRevocationEntry, ScheduledRevocation, Appeal, TimelockedMsg, ListTimelockedActionsResponse, TimelockedActionEntry, TimelockedAction, MixingEpochResponse, OfferResponse, Offer, Issuance, OptedOutResponse, OwnershipForString, PausedResponse, PendingRecoveryResponse, ReassignCooldownResponse, ReportResponse, ReportPoolResponse, ResolveVoterResponse, ScheduledRevocationResponse, SecondaryAddressesResponse, TimelockedActionResponse, VerificationRequestResponse } from "./CwCheckmark.types";
 import { CwCheckmarkQueryClient } from "./CwCheckmark.client";
 type QueryClientParams = {
   contractAddress: string;
@@ -50,6 +50,48 @@ export const getAddressSelector = selectorFamily<GetAddressResponse, QueryClient
     return await client.getAddress(...params);
   }
 });
+export const getCheckmarkByEvmAddressSelector = selectorFamily<GetCheckmarkResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["getCheckmarkByEvmAddress"]>;
+}>({
+  key: "cwCheckmarkGetCheckmarkByEvmAddress",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.getCheckmarkByEvmAddress(...params);
+  }
+});
+export const getCheckmarkByCanonicalAddressSelector = selectorFamily<GetCheckmarkResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["getCheckmarkByCanonicalAddress"]>;
+}>({
+  key: "cwCheckmarkGetCheckmarkByCanonicalAddress",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.getCheckmarkByCanonicalAddress(...params);
+  }
+});
+export const getCheckmarkByPubkeySelector = selectorFamily<GetCheckmarkResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["getCheckmarkByPubkey"]>;
+}>({
+  key: "cwCheckmarkGetCheckmarkByPubkey",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.getCheckmarkByPubkey(...params);
+  }
+});
 export const countSelector = selectorFamily<CountResponse, QueryClientParams & {
   params: Parameters<CwCheckmarkQueryClient["count"]>;
 }>({
@@ -64,6 +106,20 @@ export const countSelector = selectorFamily<CountResponse, QueryClientParams & {
     return await client.count(...params);
   }
 });
+export const checkmarkIssuedSelector = selectorFamily<CheckmarkIssuedResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["checkmarkIssued"]>;
+}>({
+  key: "cwCheckmarkCheckmarkIssued",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.checkmarkIssued(...params);
+  }
+});
 export const checkmarkBannedSelector = selectorFamily<CheckmarkBannedResponse, QueryClientParams & {
   params: Parameters<CwCheckmarkQueryClient["checkmarkBanned"]>;
 }>({
@@ -78,6 +134,118 @@ export const checkmarkBannedSelector = selectorFamily<CheckmarkBannedResponse, Q
     return await client.checkmarkBanned(...params);
   }
 });
+export const listBannedCheckmarksSelector = selectorFamily<ListBannedCheckmarksResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["listBannedCheckmarks"]>;
+}>({
+  key: "cwCheckmarkListBannedCheckmarks",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.listBannedCheckmarks(...params);
+  }
+});
+export const addressBannedSelector = selectorFamily<AddressBannedResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["addressBanned"]>;
+}>({
+  key: "cwCheckmarkAddressBanned",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.addressBanned(...params);
+  }
+});
+export const listPendingVerificationRequestsSelector = selectorFamily<ListVerificationRequestsResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["listPendingVerificationRequests"]>;
+}>({
+  key: "cwCheckmarkListPendingVerificationRequests",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.listPendingVerificationRequests(...params);
+  }
+});
+export const feeBalancesSelector = selectorFamily<FeeBalancesResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["feeBalances"]>;
+}>({
+  key: "cwCheckmarkFeeBalances",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.feeBalances(...params);
+  }
+});
+export const verificationRequestSelector = selectorFamily<VerificationRequestResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["verificationRequest"]>;
+}>({
+  key: "cwCheckmarkVerificationRequest",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.verificationRequest(...params);
+  }
+});
+export const listAllowedContractsSelector = selectorFamily<ListAllowedContractsResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["listAllowedContracts"]>;
+}>({
+  key: "cwCheckmarkListAllowedContracts",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.listAllowedContracts(...params);
+  }
+});
+export const listOrganizationsSelector = selectorFamily<ListOrganizationsResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["listOrganizations"]>;
+}>({
+  key: "cwCheckmarkListOrganizations",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.listOrganizations(...params);
+  }
+});
+export const listBannedAddressesSelector = selectorFamily<ListBannedAddressesResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["listBannedAddresses"]>;
+}>({
+  key: "cwCheckmarkListBannedAddresses",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.listBannedAddresses(...params);
+  }
+});
 export const assignerSelector = selectorFamily<AssignerResponse, QueryClientParams & {
   params: Parameters<CwCheckmarkQueryClient["assigner"]>;
 }>({
@@ -92,6 +260,356 @@ export const assignerSelector = selectorFamily<AssignerResponse, QueryClientPara
     return await client.assigner(...params);
   }
 });
+export const pausedSelector = selectorFamily<PausedResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["paused"]>;
+}>({
+  key: "cwCheckmarkPaused",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.paused(...params);
+  }
+});
+export const mixingEpochSelector = selectorFamily<MixingEpochResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["mixingEpoch"]>;
+}>({
+  key: "cwCheckmarkMixingEpoch",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.mixingEpoch(...params);
+  }
+});
+export const secondaryAddressesSelector = selectorFamily<SecondaryAddressesResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["secondaryAddresses"]>;
+}>({
+  key: "cwCheckmarkSecondaryAddresses",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.secondaryAddresses(...params);
+  }
+});
+export const getDelegationSelector = selectorFamily<GetDelegationResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["getDelegation"]>;
+}>({
+  key: "cwCheckmarkGetDelegation",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.getDelegation(...params);
+  }
+});
+export const activeCountSelector = selectorFamily<CountResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["activeCount"]>;
+}>({
+  key: "cwCheckmarkActiveCount",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.activeCount(...params);
+  }
+});
+export const resolveVoterSelector = selectorFamily<ResolveVoterResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["resolveVoter"]>;
+}>({
+  key: "cwCheckmarkResolveVoter",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.resolveVoter(...params);
+  }
+});
+export const guardiansSelector = selectorFamily<GuardiansResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["guardians"]>;
+}>({
+  key: "cwCheckmarkGuardians",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.guardians(...params);
+  }
+});
+export const pendingRecoverySelector = selectorFamily<PendingRecoveryResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["pendingRecovery"]>;
+}>({
+  key: "cwCheckmarkPendingRecovery",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.pendingRecovery(...params);
+  }
+});
+export const listPendingRecoveriesSelector = selectorFamily<ListPendingRecoveriesResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["listPendingRecoveries"]>;
+}>({
+  key: "cwCheckmarkListPendingRecoveries",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.listPendingRecoveries(...params);
+  }
+});
+export const scheduledRevocationSelector = selectorFamily<ScheduledRevocationResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["scheduledRevocation"]>;
+}>({
+  key: "cwCheckmarkScheduledRevocation",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.scheduledRevocation(...params);
+  }
+});
+export const listScheduledRevocationsSelector = selectorFamily<ListScheduledRevocationsResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["listScheduledRevocations"]>;
+}>({
+  key: "cwCheckmarkListScheduledRevocations",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.listScheduledRevocations(...params);
+  }
+});
+export const reportSelector = selectorFamily<ReportResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["report"]>;
+}>({
+  key: "cwCheckmarkReport",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.report(...params);
+  }
+});
+export const listReportsByStatusSelector = selectorFamily<ListReportsResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["listReportsByStatus"]>;
+}>({
+  key: "cwCheckmarkListReportsByStatus",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.listReportsByStatus(...params);
+  }
+});
+export const listReportsByTargetSelector = selectorFamily<ListReportsResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["listReportsByTarget"]>;
+}>({
+  key: "cwCheckmarkListReportsByTarget",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.listReportsByTarget(...params);
+  }
+});
+export const reportPoolSelector = selectorFamily<ReportPoolResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["reportPool"]>;
+}>({
+  key: "cwCheckmarkReportPool",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.reportPool(...params);
+  }
+});
+export const listIssuedBySelector = selectorFamily<ListIssuedByResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["listIssuedBy"]>;
+}>({
+  key: "cwCheckmarkListIssuedBy",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.listIssuedBy(...params);
+  }
+});
+export const offerSelector = selectorFamily<OfferResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["offer"]>;
+}>({
+  key: "cwCheckmarkOffer",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.offer(...params);
+  }
+});
+export const optedOutSelector = selectorFamily<OptedOutResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["optedOut"]>;
+}>({
+  key: "cwCheckmarkOptedOut",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.optedOut(...params);
+  }
+});
+export const reassignCooldownSelector = selectorFamily<ReassignCooldownResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["reassignCooldown"]>;
+}>({
+  key: "cwCheckmarkReassignCooldown",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.reassignCooldown(...params);
+  }
+});
+export const listRoleMembersSelector = selectorFamily<ListRoleMembersResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["listRoleMembers"]>;
+}>({
+  key: "cwCheckmarkListRoleMembers",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.listRoleMembers(...params);
+  }
+});
+export const hasRoleSelector = selectorFamily<HasRoleResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["hasRole"]>;
+}>({
+  key: "cwCheckmarkHasRole",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.hasRole(...params);
+  }
+});
+export const listPendingAssignmentsSelector = selectorFamily<ListPendingAssignmentsResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["listPendingAssignments"]>;
+}>({
+  key: "cwCheckmarkListPendingAssignments",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.listPendingAssignments(...params);
+  }
+});
+export const timelockedActionSelector = selectorFamily<TimelockedActionResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["timelockedAction"]>;
+}>({
+  key: "cwCheckmarkTimelockedAction",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.timelockedAction(...params);
+  }
+});
+export const listTimelockedActionsSelector = selectorFamily<ListTimelockedActionsResponse, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["listTimelockedActions"]>;
+}>({
+  key: "cwCheckmarkListTimelockedActions",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.listTimelockedActions(...params);
+  }
+});
+export const configSelector = selectorFamily<Config, QueryClientParams & {
+  params: Parameters<CwCheckmarkQueryClient["config"]>;
+}>({
+  key: "cwCheckmarkConfig",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    return await client.config(...params);
+  }
+});
 export const ownershipSelector = selectorFamily<OwnershipForString, QueryClientParams & {
   params: Parameters<CwCheckmarkQueryClient["ownership"]>;
 }>({
@@ -13,34 +13,226 @@ export type ExecuteMsg = {
     address: string;
     checkmark_id: string;
   };
+} | {
+  receive: Cw20ReceiveMsg;
+} | {
+  request_verification: {
+    session_commitment: string;
+  };
+} | {
+  reject_verification: {
+    address: string;
+    reason_code: number;
+    refund?: Uint128 | null;
+  };
+} | {
+  cancel_verification: {};
+} | {
+  withdraw_fees: {
+    denom: Denom;
+  };
+} | {
+  approve_assign: {
+    address: string;
+    checkmark_id: string;
+  };
+} | {
+  assign_organization: {
+    address: string;
+    checkmark_id: string;
+  };
+} | {
+  reassign_checkmark: {
+    address: string;
+    checkmark_id: string;
+  };
+} | {
+  rotate_checkmark: {
+    address: string;
+    new_checkmark_id: string;
+    reissue?: boolean | null;
+  };
+} | {
+  process_epoch: {
+    limit?: number | null;
+  };
+} | {
+  prune_pending_assignments: {
+    limit?: number | null;
+  };
 } | {
   delete: {};
+} | {
+  accept_checkmark: {};
+} | {
+  reject_checkmark: {};
+} | {
+  opt_out: {};
+} | {
+  opt_in: {};
+} | {
+  cancel_delete: {};
+} | {
+  finalize_delete: {
+    address: string;
+  };
 } | {
   revoke_checkmark: {
     checkmark_id: string;
+    reason?: string | null;
   };
 } | {
   revoke_address: {
     address: string;
+    reason?: string | null;
+  };
+} | {
+  appeal: {
+    evidence_hash: string;
+  };
+} | {
+  resolve_appeal: {
+    checkmark_id: string;
+    uphold: boolean;
+  };
+} | {
+  report: {
+    address: string;
+    evidence_hash: string;
+    reason: string;
+  };
+} | {
+  resolve_report: {
+    id: number;
+    status: ReportStatus;
+  };
+} | {
+  fund_report_pool: {};
+} | {
+  revoke_issued_by: {
+    assigner: string;
+    from_height: number;
+    limit?: number | null;
+    to_height: number;
+  };
+} | {
+  finalize_revocation: {
+    checkmark_id: string;
+  };
+} | {
+  suspend: {
+    address?: string | null;
+    checkmark_id?: string | null;
+    reason: string;
+    until?: Expiration | null;
+  };
+} | {
+  unsuspend: {
+    address?: string | null;
+    checkmark_id?: string | null;
   };
 } | {
   update_checkmark_ban: {
     ban_ids?: string[] | null;
+    expiration?: Expiration | null;
+    note?: string | null;
+    reason?: BanReason | null;
     unban_ids?: string[] | null;
   };
+} | {
+  update_address_ban: {
+    ban_addresses?: string[] | null;
+    expiration?: Expiration | null;
+    note?: string | null;
+    reason?: BanReason | null;
+    unban_addresses?: string[] | null;
+  };
 } | {
   update_assigner: {
     assigner: string;
   };
+} | {
+  execute_timelocked: {
+    id: number;
+  };
+} | {
+  cancel_timelocked: {
+    id: number;
+  };
+} | {
+  link_evm_address: {
+    eth_address: string;
+    signature: Binary;
+  };
+} | {
+  register_pubkey: {
+    pubkey: Binary;
+  };
+} | {
+  add_secondary_address: {
+    address: string;
+  };
+} | {
+  accept_secondary_address: {
+    primary: string;
+  };
+} | {
+  remove_secondary_address: {
+    address: string;
+  };
+} | {
+  delegate: {
+    to: string;
+  };
+} | {
+  undelegate: {};
+} | {
+  set_guardians: {
+    guardians: string[];
+    threshold: number;
+  };
+} | {
+  approve_recovery: {
+    holder: string;
+    new_address: string;
+  };
+} | {
+  cancel_recovery: {
+    holder?: string | null;
+  };
+} | {
+  execute_recovery: {
+    holder: string;
+  };
+} | {
+  update_config: UpdateConfigMsg;
+} | {
+  update_allowed_contracts: {
+    add?: string[] | null;
+    remove?: string[] | null;
+  };
+} | {
+  grant_role: {
+    address: string;
+    role: Role;
+  };
+} | {
+  revoke_role: {
+    address: string;
+    role: Role;
+  };
 } | {
   update_ownership: Action;
 };
-export type Action = {
-  transfer_ownership: {
-    expiry?: Expiration | null;
-    new_owner: string;
-  };
-} | "accept_ownership" | "renounce_ownership";
+export type Uint128 = string;
+export type Binary = string;
+export type Denom = {
+  native: string;
+} | {
+  cw20: Addr;
+};
+export type Addr = string;
+export type ReportStatus = "open" | "upheld" | "rejected" | "slashed";
 export type Expiration = {
   at_height: number;
 } | {
@@ -50,6 +242,60 @@ export type Expiration = {
 };
 export type Timestamp = Uint64;
 export type Uint64 = string;
+export type BanReason = "fraud" | "duplicate_identity" | "compromised_account" | "other";
+export type Duration = {
+  height: number;
+} | {
+  time: number;
+};
+export type Decimal = string;
+export type Role = "assigner" | "revoker" | "ban_manager" | "guardian" | "config_admin" | "moderator";
+export type Action = {
+  transfer_ownership: {
+    expiry?: Expiration | null;
+    new_owner: string;
+  };
+} | "accept_ownership" | "renounce_ownership";
+export interface Cw20ReceiveMsg {
+  amount: Uint128;
+  msg: Binary;
+  sender: string;
+}
+export interface UpdateConfigMsg {
+  assign_approval_timeout?: Duration | null;
+  assign_threshold?: number | null;
+  deletion_delay?: Duration | null;
+  fee_split?: FeeSplit | null;
+  fees?: Fee[] | null;
+  max_secondary_addresses?: number | null;
+  mixing_epoch?: Duration | null;
+  offer_timeout?: Duration | null;
+  prevent_id_reuse?: boolean | null;
+  reassign_cooldown?: Duration | null;
+  recovery_delay?: Duration | null;
+  reject_contracts?: boolean | null;
+  report_bond?: Coin | null;
+  report_reward?: Coin | null;
+  require_consent?: boolean | null;
+  revocation_challenge_period?: Duration | null;
+  timelock_delay?: Duration | null;
+}
+export interface FeeSplit {
+  community_pool: Addr;
+  operator: Addr;
+  operator_share: Decimal;
+  provider: Addr;
+  provider_share: Decimal;
+}
+export interface Fee {
+  amount: Uint128;
+  denom: Denom;
+}
+export interface Coin {
+  amount: Uint128;
+  denom: string;
+  [k: string]: unknown;
+}
 export type QueryMsg = {
   get_checkmark: {
     address: string;
@@ -58,35 +304,523 @@ export type QueryMsg = {
   get_address: {
     checkmark_id: string;
   };
+} | {
+  get_checkmark_by_evm_address: {
+    eth_address: string;
+  };
+} | {
+  get_checkmark_by_canonical_address: {
+    canonical_address: Binary;
+  };
+} | {
+  get_checkmark_by_pubkey: {
+    pubkey: Binary;
+  };
 } | {
   count: {};
+} | {
+  checkmark_issued: {
+    checkmark_id: string;
+  };
 } | {
   checkmark_banned: {
     checkmark_id: string;
   };
+} | {
+  list_banned_checkmarks: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  address_banned: {
+    address: string;
+  };
+} | {
+  list_pending_verification_requests: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  fee_balances: {};
+} | {
+  verification_request: {
+    address: string;
+  };
+} | {
+  list_allowed_contracts: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  list_organizations: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  list_banned_addresses: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
 } | {
   assigner: {};
+} | {
+  paused: {};
+} | {
+  mixing_epoch: {};
+} | {
+  secondary_addresses: {
+    address: string;
+  };
+} | {
+  get_delegation: {
+    address: string;
+  };
+} | {
+  active_count: {};
+} | {
+  resolve_voter: {
+    address: string;
+  };
+} | {
+  guardians: {
+    address: string;
+  };
+} | {
+  pending_recovery: {
+    address: string;
+  };
+} | {
+  list_pending_recoveries: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  scheduled_revocation: {
+    checkmark_id: string;
+  };
+} | {
+  list_scheduled_revocations: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  report: {
+    id: number;
+  };
+} | {
+  list_reports_by_status: {
+    limit?: number | null;
+    start_after?: number | null;
+    status: ReportStatus;
+  };
+} | {
+  list_reports_by_target: {
+    address: string;
+    limit?: number | null;
+    start_after?: number | null;
+  };
+} | {
+  report_pool: {};
+} | {
+  list_issued_by: {
+    assigner: string;
+    from_height: number;
+    limit?: number | null;
+    start_after?: [number, string] | null;
+    to_height: number;
+  };
+} | {
+  offer: {
+    address: string;
+  };
+} | {
+  opted_out: {
+    address: string;
+  };
+} | {
+  reassign_cooldown: {
+    address: string;
+  };
+} | {
+  list_role_members: {
+    limit?: number | null;
+    role: Role;
+    start_after?: string | null;
+  };
+} | {
+  has_role: {
+    address: string;
+    role: Role;
+  };
+} | {
+  list_pending_assignments: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  timelocked_action: {
+    id: number;
+  };
+} | {
+  list_timelocked_actions: {
+    limit?: number | null;
+    start_after?: number | null;
+  };
+} | {
+  config: {};
 } | {
   ownership: {};
 };
-export type Addr = string;
+export type SudoMsg = {
+  update_owner: {
+    owner?: string | null;
+  };
+} | {
+  update_assigner: {
+    assigner: string;
+  };
+} | {
+  set_paused: {
+    paused: boolean;
+  };
+} | {
+  revoke_checkmark: {
+    checkmark_id: string;
+  };
+} | {
+  revoke_address: {
+    address: string;
+  };
+} | {
+  update_checkmark_ban: {
+    ban_ids?: string[] | null;
+    expiration?: Expiration | null;
+    note?: string | null;
+    reason?: BanReason | null;
+    unban_ids?: string[] | null;
+  };
+} | {
+  update_address_ban: {
+    ban_addresses?: string[] | null;
+    expiration?: Expiration | null;
+    note?: string | null;
+    reason?: BanReason | null;
+    unban_addresses?: string[] | null;
+  };
+};
+export interface CountResponse {
+  count: number;
+}
+export interface AddressBannedResponse {
+  ban?: Ban | null;
+  banned: boolean;
+}
+export interface Ban {
+  banned_by?: Addr | null;
+  expiration?: Expiration | null;
+  note?: string | null;
+  reason: BanReason;
+}
 export interface AssignerResponse {
   assigner: Addr;
 }
 export interface CheckmarkBannedResponse {
+  ban?: Ban | null;
   banned: boolean;
 }
-export interface CountResponse {
-  count: number;
+export interface CheckmarkIssuedResponse {
+  issued: boolean;
+}
+export interface Config {
+  assign_approval_timeout?: Duration;
+  assign_threshold?: number;
+  deletion_delay?: Duration | null;
+  fee_split?: FeeSplit | null;
+  fees?: Fee[];
+  max_secondary_addresses?: number;
+  mixing_epoch?: Duration | null;
+  offer_timeout?: Duration;
+  prevent_id_reuse?: boolean;
+  reassign_cooldown?: Duration | null;
+  recovery_delay?: Duration;
+  reject_contracts?: boolean;
+  report_bond?: Coin | null;
+  report_reward?: Coin | null;
+  require_consent?: boolean;
+  revocation_challenge_period?: Duration | null;
+  timelock_delay?: Duration | null;
+}
+export interface FeeBalancesResponse {
+  balances: Fee[];
 }
 export interface GetAddressResponse {
   address?: Addr | null;
 }
 export interface GetCheckmarkResponse {
   checkmark_id?: string | null;
+  organization: boolean;
+  pending_deletion?: Expiration | null;
+  suspension?: Suspension | null;
+}
+export interface Suspension {
+  reason: string;
+  suspended_by: Addr;
+  until?: Expiration | null;
+}
+export interface GetDelegationResponse {
+  delegate?: Addr | null;
+}
+export interface GuardiansResponse {
+  guardians: Addr[];
+  pending_change?: GuardianChange | null;
+  threshold: number;
+}
+export interface GuardianChange {
+  effective_after: Expiration;
+  guardians: Addr[];
+  threshold: number;
+}
+export interface HasRoleResponse {
+  has_role: boolean;
+}
+export interface ListAllowedContractsResponse {
+  contracts: Addr[];
+}
+export interface ListBannedAddressesResponse {
+  bans: BannedAddressEntry[];
+}
+export interface BannedAddressEntry {
+  address: Addr;
+  ban: Ban;
+}
+export interface ListBannedCheckmarksResponse {
+  bans: BannedCheckmarkEntry[];
+}
+export interface BannedCheckmarkEntry {
+  ban: Ban;
+  checkmark_id: string;
+}
+export interface ListIssuedByResponse {
+  checkmarks: IssuedCheckmark[];
+}
+export interface IssuedCheckmark {
+  address: Addr;
+  checkmark_id: string;
+  height: number;
+}
+export interface ListOrganizationsResponse {
+  organizations: OrganizationEntry[];
+}
+export interface OrganizationEntry {
+  address: Addr;
+  checkmark_id: string;
+}
+export interface ListPendingAssignmentsResponse {
+  assignments: PendingAssignmentEntry[];
+}
+export interface PendingAssignmentEntry {
+  checkmark_id: string;
+  pending: PendingAssignment;
+}
+export interface PendingAssignment {
+  address: Addr;
+  approvals: Addr[];
+  expiration: Expiration;
+}
+export interface ListPendingRecoveriesResponse {
+  recoveries: PendingRecovery[];
+}
+export interface PendingRecovery {
+  holder: Addr;
+  recovery: Recovery;
+}
+export interface Recovery {
+  executable_after?: Expiration | null;
+  new_address?: Addr | null;
+  proposals: RecoveryProposal[];
+}
+export interface RecoveryProposal {
+  approvals: Addr[];
+  new_address: Addr;
+}
+export type VerificationStatus = "pending" | "assigning" | "fulfilled" | {
+  rejected: {
+    reason_code: number;
+    refunded: Uint128;
+  };
+} | "cancelled";
+export interface ListVerificationRequestsResponse {
+  requests: VerificationRequestEntry[];
+}
+export interface VerificationRequestEntry {
+  address: Addr;
+  request: VerificationRequest;
+}
+export interface VerificationRequest {
+  fee?: Fee | null;
+  session_commitment: string;
+  status: VerificationStatus;
+}
+export interface ListReportsResponse {
+  reports: ReportEntry[];
+}
+export interface ReportEntry {
+  id: number;
+  report: Report;
+}
+export interface Report {
+  bond?: Coin | null;
+  evidence_hash: string;
+  reason: string;
+  reporter: Addr;
+  status: ReportStatus;
+  target: Addr;
+}
+export interface ListRoleMembersResponse {
+  members: Addr[];
+}
+export interface ListScheduledRevocationsResponse {
+  revocations: ScheduledRevocationEntry[];
+}
+export interface ScheduledRevocationEntry {
+  checkmark_id: string;
+  revocation: ScheduledRevocation;
+}
+export interface ScheduledRevocation {
+  appeal?: Appeal | null;
+  finalize_after: Expiration;
+  proposer: Addr;
+  reason?: string | null;
+}
+export interface Appeal {
+  dismissed: boolean;
+  evidence_hash: string;
+}
+export type TimelockedMsg = {
+  revoke_checkmark: {
+    checkmark_id: string;
+    reason?: string | null;
+  };
+} | {
+  revoke_address: {
+    address: string;
+    reason?: string | null;
+  };
+} | {
+  update_checkmark_ban: {
+    ban_ids?: string[] | null;
+    expiration?: Expiration | null;
+    note?: string | null;
+    reason?: BanReason | null;
+    unban_ids?: string[] | null;
+  };
+} | {
+  update_address_ban: {
+    ban_addresses?: string[] | null;
+    expiration?: Expiration | null;
+    note?: string | null;
+    reason?: BanReason | null;
+    unban_addresses?: string[] | null;
+  };
+} | {
+  update_assigner: {
+    assigner: string;
+  };
+} | {
+  update_config: UpdateConfigMsg;
+} | {
+  update_allowed_contracts: {
+    add?: string[] | null;
+    remove?: string[] | null;
+  };
+} | {
+  grant_role: {
+    address: string;
+    role: Role;
+  };
+} | {
+  revoke_role: {
+    address: string;
+    role: Role;
+  };
+} | {
+  update_ownership: Action;
+} | {
+  revoke_issued_by: {
+    assigner: string;
+    from_height: number;
+    limit?: number | null;
+    to_height: number;
+  };
+};
+export interface ListTimelockedActionsResponse {
+  actions: TimelockedActionEntry[];
+}
+export interface TimelockedActionEntry {
+  action: TimelockedAction;
+  id: number;
+}
+export interface TimelockedAction {
+  execute_after: Expiration;
+  msg: TimelockedMsg;
+  proposer: Addr;
+}
+export interface MixingEpochResponse {
+  next_epoch?: Expiration | null;
+  processing: boolean;
+  queued: number;
+}
+export interface OfferResponse {
+  offer?: Offer | null;
+}
+export interface Offer {
+  checkmark_id: string;
+  expiration: Expiration;
+  issuance: Issuance;
+  payment?: Fee | null;
+  reassign_from?: Addr | null;
+}
+export interface Issuance {
+  assigner: Addr;
+  height: number;
+}
+export interface OptedOutResponse {
+  opted_out: boolean;
 }
 export interface OwnershipForString {
   owner?: string | null;
   pending_expiry?: Expiration | null;
   pending_owner?: string | null;
+}
+export interface PausedResponse {
+  paused: boolean;
+}
+export interface PendingRecoveryResponse {
+  recovery?: Recovery | null;
+}
+export interface ReassignCooldownResponse {
+  ends?: Expiration | null;
+  remaining?: Duration | null;
+}
+export interface ReportResponse {
+  report?: Report | null;
+}
+export interface ReportPoolResponse {
+  balances: Coin[];
+}
+export interface ResolveVoterResponse {
+  checkmark_id?: string | null;
+  principal?: Addr | null;
+}
+export interface ScheduledRevocationResponse {
+  revocation?: ScheduledRevocation | null;
+}
+export interface SecondaryAddressesResponse {
+  addresses: Addr[];
+}
+export interface TimelockedActionResponse {
+  action?: TimelockedAction | null;
+}
+export interface VerificationRequestResponse {
+  request?: VerificationRequest | null;
 }
\ No newline at end of file